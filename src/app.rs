@@ -1,12 +1,17 @@
 pub mod achievement;
 pub mod audio;
+pub mod gamepad;
 pub mod init;
 pub mod interaction;
 pub mod key_binding;
 pub mod leaderboard;
+pub mod locale;
 pub mod screenshot;
 pub mod settings;
+pub mod theme;
+pub mod touch;
 pub mod ui;
+pub mod unlock;
 
 pub const WINDOW_W: f32 = 1280.0;
 pub const WINDOW_H: f32 = 720.0;
@@ -1,32 +1,55 @@
-use crate::reactor::status;
+use crate::{app, reactor::status};
 use bevy::prelude::*;
 use bevy::utils::HashMap;
 use bevy_persistent::prelude::*;
 use chrono::Local;
 use serde::{Deserialize, Serialize};
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 pub mod achievement_xxx;
-pub mod alpha_xxx;
+pub mod asset_def;
 pub mod c_chain_xxx;
 pub mod h_chain_xxx;
-pub mod max_c_xxx;
-pub mod max_h;
-pub mod not_moving_xxx_s;
-pub mod score_xxx;
-pub mod time_xxx_s;
-
-pub const ACHIEVEMENTS: [&dyn AchievementDefBase; 9] = [
-    &max_h::AchievementDef,
-    &max_c_xxx::AchievementDef,
-    &not_moving_xxx_s::AchievementDef,
-    &alpha_xxx::AchievementDef,
-    &score_xxx::AchievementDef,
-    &time_xxx_s::AchievementDef,
-    &h_chain_xxx::AchievementDef,
-    &c_chain_xxx::AchievementDef,
-    &achievement_xxx::AchievementDef,
-];
+
+fn builtin_defs() -> [Arc<dyn AchievementDefBase>; 3] {
+    [
+        Arc::new(h_chain_xxx::AchievementDef),
+        Arc::new(c_chain_xxx::AchievementDef),
+        Arc::new(achievement_xxx::AchievementDef),
+    ]
+}
+
+// NOTE: the asset definitions come first, then the builtin ones
+#[derive(Resource)]
+pub struct AchievementDefs {
+    ach_defs: Vec<Arc<dyn AchievementDefBase>>,
+    def_map: HashMap<String, Arc<dyn AchievementDefBase>>,
+}
+
+impl AchievementDefs {
+    pub fn new(loaded_defs: Vec<Arc<dyn AchievementDefBase>>) -> Self {
+        let mut ach_defs = loaded_defs;
+        ach_defs.extend(builtin_defs());
+        let def_map = ach_defs
+            .iter()
+            .map(|ach_def| (String::from(ach_def.code()), ach_def.clone()))
+            .collect();
+        AchievementDefs { ach_defs, def_map }
+    }
+    pub fn iter(&self) -> impl Iterator<Item = &dyn AchievementDefBase> {
+        self.ach_defs.iter().map(|ach_def| ach_def.as_ref())
+    }
+    pub fn find(&self, code: &str) -> Option<&dyn AchievementDefBase> {
+        self.def_map.get(code).map(|ach_def| ach_def.as_ref())
+    }
+}
+
+impl Default for AchievementDefs {
+    fn default() -> Self {
+        AchievementDefs::new(vec![])
+    }
+}
 
 const MAX_RUNNING_ACH_COUNT: usize = 2;
 
@@ -35,6 +58,45 @@ pub struct AchievementRecord {
     pub code: String,
     pub is_done: bool,
     pub done_at: String,
+    pub tier_done_at: Vec<String>,
+}
+
+#[derive(Resource, Serialize, Deserialize, Default)]
+struct LegacyAchievementStore {
+    is_enabled: bool,
+    pinned_codes: Vec<String>,
+    record_map: HashMap<String, LegacyAchievementRecord>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+struct LegacyAchievementRecord {
+    code: String,
+    is_done: bool,
+    done_at: String,
+}
+
+impl From<&LegacyAchievementStore> for AchievementStore {
+    fn from(legacy_store: &LegacyAchievementStore) -> Self {
+        AchievementStore {
+            is_enabled: legacy_store.is_enabled,
+            pinned_codes: legacy_store.pinned_codes.clone(),
+            record_map: legacy_store
+                .record_map
+                .iter()
+                .map(|(code, record)| {
+                    (
+                        code.clone(),
+                        AchievementRecord {
+                            code: record.code.clone(),
+                            is_done: record.is_done,
+                            done_at: record.done_at.clone(),
+                            tier_done_at: vec![],
+                        },
+                    )
+                })
+                .collect(),
+        }
+    }
 }
 
 #[derive(Resource, Serialize, Deserialize)]
@@ -57,17 +119,34 @@ impl AchievementStore {
             true
         }
     }
-    pub fn mark_done(&mut self, code: &str) {
-        let mut record = self.fetch_record(code);
+    pub fn mark_tier_done(&mut self, ach_def: &dyn AchievementDefBase, tier: usize) {
+        let mut record = self.fetch_record(ach_def.code());
         if !record.is_done {
-            record.is_done = true;
-            record.done_at = Local::now().format("%Y-%m-%d_%H:%M:%S%.9f").to_string();
+            let done_at = Local::now().format("%Y-%m-%d_%H:%M:%S%.9f").to_string();
+            while record.tier_done_at.len() <= tier {
+                record.tier_done_at.push(done_at.clone());
+            }
+            if record.tier_done_at.len() >= ach_def.tier_count() {
+                record.is_done = true;
+                record.done_at = done_at;
+            }
             self.record_map.insert(record.code.clone(), record);
         }
     }
     pub fn is_done(&self, code: &str) -> bool {
         self.fetch_record(code).is_done
     }
+    pub fn done_tier_count(&self, ach_def: &dyn AchievementDefBase) -> usize {
+        let record = self.fetch_record(ach_def.code());
+        if record.is_done {
+            ach_def.tier_count()
+        } else {
+            record.tier_done_at.len()
+        }
+    }
+    pub fn next_tier(&self, ach_def: &dyn AchievementDefBase) -> usize {
+        self.done_tier_count(ach_def).min(ach_def.tier_count() - 1)
+    }
     pub fn is_pinned(&self, code: &str) -> bool {
         let record = self.fetch_record(code);
         if record.is_done {
@@ -80,11 +159,67 @@ impl AchievementStore {
         self.pinned_codes.clone()
     }
     pub fn fetch_record(&self, code: &str) -> AchievementRecord {
-        if let Some(record) = self.record_map.get(code) {
-            record.clone()
-        } else {
-            let ach_def = fetch_ach_def(code);
-            ach_def.build_empty_record()
+        match self.record_map.get(code) {
+            Some(record) => record.clone(),
+            None => AchievementRecord {
+                code: String::from(code),
+                is_done: false,
+                done_at: String::from(""),
+                tier_done_at: vec![],
+            },
+        }
+    }
+}
+
+#[derive(Resource, Serialize, Deserialize)]
+pub struct AchievementCounters {
+    counter_map: HashMap<String, u32>,
+}
+
+impl AchievementCounters {
+    pub fn fetch(&self, field: &str) -> u32 {
+        self.counter_map.get(field).copied().unwrap_or(0)
+    }
+    pub fn snapshot(&self) -> HashMap<String, u32> {
+        self.counter_map.clone()
+    }
+    pub fn accumulate(&mut self, status: &status::ReactorStatus) {
+        for field in status::LIFETIME_FIELDS {
+            self.counter_map
+                .insert(String::from(field), status.fetch_lifetime(field));
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct AchievementBest {
+    pub value: u32,
+    pub uid: String,
+}
+
+#[derive(Resource, Serialize, Deserialize)]
+pub struct AchievementProgress {
+    best_map: HashMap<String, AchievementBest>,
+}
+
+impl AchievementProgress {
+    pub fn fetch_best(&self, code: &str) -> Option<AchievementBest> {
+        self.best_map.get(code).cloned()
+    }
+    pub fn best_value(&self, code: &str) -> u32 {
+        self.best_map.get(code).map_or(0, |best| best.value)
+    }
+    pub fn merge(&mut self, status: &status::ReactorStatus) {
+        for (code, value) in status.ach_progress.iter() {
+            if *value > self.best_value(code) {
+                self.best_map.insert(
+                    code.clone(),
+                    AchievementBest {
+                        value: *value,
+                        uid: String::from(status.uid()),
+                    },
+                );
+            }
         }
     }
 }
@@ -96,7 +231,8 @@ pub enum AchievementUiStatus {
     Done,
 }
 
-#[derive(Clone, PartialEq, Debug)]
+#[derive(Clone, PartialEq, Debug, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum AchievementProgressUi {
     Bar,
     Dots,
@@ -104,20 +240,20 @@ pub enum AchievementProgressUi {
 
 #[derive(Resource, Default)]
 pub struct AchievementInfo {
-    done_codes: Vec<String>,
+    done_codes: Vec<(String, usize)>,
     running_codes: Vec<String>,
 }
 
 impl AchievementInfo {
-    pub fn reset(&mut self, store: &AchievementStore) {
-        self.update_running_codes(store);
+    pub fn reset(&mut self, store: &AchievementStore, ach_defs: &AchievementDefs) {
+        self.update_running_codes(store, ach_defs);
         self.done_codes = vec![];
     }
 
-    pub fn update_running_codes(&mut self, store: &AchievementStore) {
+    pub fn update_running_codes(&mut self, store: &AchievementStore, ach_defs: &AchievementDefs) {
         self.running_codes = vec![];
         for code in store.pinned_codes.iter() {
-            if store.is_done(code) {
+            if ach_defs.find(code).is_none() || store.is_done(code) {
                 continue;
             }
             self.running_codes.push(String::from(code));
@@ -125,7 +261,7 @@ impl AchievementInfo {
                 return;
             }
         }
-        for ach_def in ACHIEVEMENTS {
+        for ach_def in ach_defs.iter() {
             if store.is_done(ach_def.code()) || store.is_pinned(ach_def.code()) {
                 continue;
             }
@@ -144,8 +280,8 @@ impl AchievementInfo {
         self.running_codes.clone()
     }
 
-    pub fn push_to_done(&mut self, code: &str) -> Option<String> {
-        self.done_codes.insert(0, String::from(code));
+    pub fn push_to_done(&mut self, code: &str, tier: usize) -> Option<(String, usize)> {
+        self.done_codes.insert(0, (String::from(code), tier));
         if self.done_codes.len() == 1 {
             self.done_codes.pop()
         } else {
@@ -153,7 +289,7 @@ impl AchievementInfo {
         }
     }
 
-    pub fn next_done(&mut self) -> Option<String> {
+    pub fn next_done(&mut self) -> Option<(String, usize)> {
         self.done_codes.pop()
     }
 }
@@ -165,53 +301,119 @@ impl Plugin for AchievementPlugin {
         let config_dir = dirs::config_dir()
             .map(|native_config_dir| native_config_dir.join("chain-reaction"))
             .unwrap_or(Path::new("local").join("configuration"));
+        let store_path = config_dir.join("achievement.bin");
         app.insert_resource(
             Persistent::<AchievementStore>::builder()
                 .name("achievement")
                 .format(StorageFormat::Bincode)
-                .path(config_dir.join("achievement.bin"))
+                .path(store_path.clone())
                 .default(AchievementStore {
                     is_enabled: true,
                     pinned_codes: vec![],
                     record_map: HashMap::new(),
                 })
                 .build()
+                .unwrap_or_else(|_| migrate_legacy_store(store_path)),
+        );
+        app.insert_resource(
+            Persistent::<AchievementCounters>::builder()
+                .name("achievement counter")
+                .format(StorageFormat::Bincode)
+                .path(config_dir.join("achievement_counter.bin"))
+                .default(AchievementCounters {
+                    counter_map: HashMap::new(),
+                })
+                .build()
                 .expect("failed to initialize variables"),
         );
-        app.insert_resource(AchievementInfo::default());
+        app.insert_resource(
+            Persistent::<AchievementProgress>::builder()
+                .name("achievement progress")
+                .format(StorageFormat::Bincode)
+                .path(config_dir.join("achievement_progress.bin"))
+                .default(AchievementProgress {
+                    best_map: HashMap::new(),
+                })
+                .build()
+                .expect("failed to initialize variables"),
+        );
+        app.insert_resource(AchievementInfo::default())
+            .init_resource::<AchievementDefs>()
+            .init_asset::<asset_def::AchievementDefList>()
+            .init_asset_loader::<asset_def::AchievementDefLoader>()
+            .add_systems(Startup, asset_def::load_defs)
+            .add_systems(Update, asset_def::register_defs);
     }
 }
 
-pub trait AchievementDefBase {
+pub trait AchievementDefBase: Send + Sync {
     fn code(&self) -> &str;
-    fn name(&self) -> &str;
+    fn name(&self, locale: &app::locale::Locale) -> String;
     fn color(&self) -> Color;
     fn icon(&self) -> &str {
         self.code()
     }
-    fn description(&self) -> String;
-    fn check_done(&self, status: &ResMut<status::ReactorStatus>) -> (u32, u32, bool);
-    fn build_empty_record(&self) -> AchievementRecord {
-        AchievementRecord {
-            code: String::from(self.code()),
-            is_done: false,
-            done_at: String::from(""),
-        }
+    fn description(&self, locale: &app::locale::Locale, tier: usize) -> String;
+    fn target(&self, tier: usize) -> u32;
+    fn check_done(&self, status: &ResMut<status::ReactorStatus>, tier: usize) -> (u32, u32, bool);
+    fn tier_count(&self) -> usize {
+        1
+    }
+    fn tier_name(&self, _tier: usize) -> Option<&str> {
+        None
+    }
+    fn tier_color(&self, _tier: usize) -> Color {
+        self.color()
     }
     fn icon_path(&self) -> String {
         format!("images/achievement/{}.png", self.icon())
     }
+    fn lifetime_progress(
+        &self,
+        _counters: &AchievementCounters,
+        _tier: usize,
+    ) -> Option<(u32, u32)> {
+        None
+    }
     fn progress_ui(&self) -> AchievementProgressUi;
     fn format_value(&self, value: u32) -> String {
         format!("{}", value)
     }
 }
 
-pub fn fetch_ach_def(code: &str) -> &dyn AchievementDefBase {
-    for ach_def in ACHIEVEMENTS {
-        if ach_def.code() == code {
-            return ach_def;
-        }
+fn migrate_legacy_store(path: PathBuf) -> Persistent<AchievementStore> {
+    let legacy_store = Persistent::<LegacyAchievementStore>::builder()
+        .name("legacy achievement")
+        .format(StorageFormat::Bincode)
+        .path(path.clone())
+        .default(LegacyAchievementStore::default())
+        .build()
+        .expect("failed to initialize variables");
+    // NOTE: the migrated store is written over the legacy file before loading,
+    //       reverting in memory would re-read the default with a wrong layout
+    let mut store = Persistent::<AchievementStore>::builder()
+        .name("achievement")
+        .format(StorageFormat::Bincode)
+        .path(path)
+        .default(AchievementStore::from(legacy_store.get()))
+        .revertible(true)
+        .unloaded(true)
+        .build()
+        .expect("failed to initialize variables");
+    store
+        .revert_to_default()
+        .expect("failed to migrate achievement");
+    store.reload().expect("failed to migrate achievement");
+    store
+}
+
+pub fn format_tier_name(
+    ach_def: &dyn AchievementDefBase,
+    tier: usize,
+    locale: &app::locale::Locale,
+) -> String {
+    match ach_def.tier_name(tier) {
+        Some(tier_name) => format!("{} {}", ach_def.name(locale), locale.tr(tier_name)),
+        None => ach_def.name(locale),
     }
-    panic!("Invalid achievement code")
 }
@@ -1,4 +1,4 @@
-use crate::app::achievement::*;
+use crate::{app, app::achievement::*};
 
 pub struct AchievementDef;
 
@@ -8,16 +8,21 @@ impl AchievementDefBase for AchievementDef {
     fn code(&self) -> &str {
         "achievement_xxx"
     }
-    fn name(&self) -> &str {
-        "Ace"
+    fn name(&self, locale: &app::locale::Locale) -> String {
+        locale.tr("Ace")
     }
     fn color(&self) -> Color {
         Color::rgb(0.55, 0.45, 0.96)
     }
-    fn description(&self) -> String {
-        format!("Get {} marks in a game", TARGET_ACH_COUNT)
+    fn description(&self, locale: &app::locale::Locale, _tier: usize) -> String {
+        locale
+            .tr("Get {target} marks in a game")
+            .replace("{target}", &TARGET_ACH_COUNT.to_string())
     }
-    fn check_done(&self, status: &ResMut<status::ReactorStatus>) -> (u32, u32, bool) {
+    fn target(&self, _tier: usize) -> u32 {
+        TARGET_ACH_COUNT
+    }
+    fn check_done(&self, status: &ResMut<status::ReactorStatus>, _tier: usize) -> (u32, u32, bool) {
         let current = status.done_achievements.len() as u32;
         let is_done = current >= TARGET_ACH_COUNT;
         (current, TARGET_ACH_COUNT, is_done)
@@ -0,0 +1,301 @@
+use crate::{app, app::achievement::*, reactor};
+use bevy::asset::{io::Reader, AssetLoader, AsyncReadExt, LoadContext};
+use bevy::reflect::TypePath;
+use bevy::utils::BoxedFuture;
+use serde::Deserializer;
+use std::sync::Arc;
+
+pub const ASSET_PATH: &str = "achievements.ach.json";
+
+#[derive(Deserialize, Clone, PartialEq, Default, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum AchievementKind {
+    #[default]
+    Run,
+    Lifetime,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct AchievementCondition {
+    #[serde(default)]
+    pub kind: AchievementKind,
+    pub field: String,
+    #[serde(default, deserialize_with = "deserialize_target")]
+    pub target: u32,
+    #[serde(default = "default_scale")]
+    pub scale: u32,
+}
+
+fn default_scale() -> u32 {
+    1
+}
+
+// NOTE: a symbolic target follows the game constant instead of copying its value
+fn deserialize_target<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u32, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Target {
+        Value(u32),
+        Symbol(String),
+    }
+    match Target::deserialize(deserializer)? {
+        Target::Value(value) => Ok(value),
+        Target::Symbol(symbol) => match symbol.as_str() {
+            "hyper_max_level" => Ok(reactor::particle::hyper::MAX_LEVEL as u32),
+            _ => Err(serde::de::Error::custom(format!(
+                "Invalid target symbol {}",
+                symbol
+            ))),
+        },
+    }
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct AchievementTier {
+    pub name: String,
+    #[serde(deserialize_with = "deserialize_target")]
+    pub target: u32,
+    pub color: [f32; 3],
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct AchievementDef {
+    code: String,
+    name: String,
+    color: [f32; 3],
+    #[serde(default)]
+    icon: Option<String>,
+    description: String,
+    condition: AchievementCondition,
+    #[serde(default)]
+    tiers: Vec<AchievementTier>,
+    progress_ui: AchievementProgressUi,
+    #[serde(default)]
+    value_format: Option<String>,
+}
+
+impl AchievementDef {
+    fn validate(&self) -> Result<(), String> {
+        let fields: &[&str] = match self.condition.kind {
+            AchievementKind::Run => &status::FETCHABLE_FIELDS,
+            AchievementKind::Lifetime => &status::LIFETIME_FIELDS,
+        };
+        if !fields.contains(&self.condition.field.as_str()) {
+            return Err(format!(
+                "Invalid condition field {} in {}",
+                self.condition.field, self.code
+            ));
+        }
+        let targets: Vec<u32> = if self.tiers.is_empty() {
+            vec![self.condition.target]
+        } else {
+            self.tiers.iter().map(|ach_tier| ach_tier.target).collect()
+        };
+        if targets[0] == 0
+            || targets.windows(2).any(|pair| pair[0] >= pair[1])
+            || self.condition.scale == 0
+        {
+            return Err(format!("Invalid condition target in {}", self.code));
+        }
+        Ok(())
+    }
+    fn tier_target(&self, tier: usize) -> u32 {
+        match self.tiers.get(tier) {
+            Some(ach_tier) => ach_tier.target,
+            None => self.condition.target,
+        }
+    }
+}
+
+impl AchievementDefBase for AchievementDef {
+    fn code(&self) -> &str {
+        &self.code
+    }
+    fn name(&self, locale: &app::locale::Locale) -> String {
+        locale.tr(&self.name)
+    }
+    fn color(&self) -> Color {
+        Color::rgb(self.color[0], self.color[1], self.color[2])
+    }
+    fn icon(&self) -> &str {
+        self.icon.as_deref().unwrap_or(&self.code)
+    }
+    fn description(&self, locale: &app::locale::Locale, tier: usize) -> String {
+        locale
+            .tr(&self.description)
+            .replace("{target}", &self.tier_target(tier).to_string())
+    }
+    fn target(&self, tier: usize) -> u32 {
+        self.tier_target(tier) * self.condition.scale
+    }
+    fn check_done(&self, status: &ResMut<status::ReactorStatus>, tier: usize) -> (u32, u32, bool) {
+        let current = match self.condition.kind {
+            AchievementKind::Run => status.fetch(&self.condition.field),
+            AchievementKind::Lifetime => status.fetch_lifetime(&self.condition.field),
+        };
+        let total = self.target(tier);
+        let is_done = current >= total;
+        (current, total, is_done)
+    }
+    fn tier_count(&self) -> usize {
+        self.tiers.len().max(1)
+    }
+    fn tier_name(&self, tier: usize) -> Option<&str> {
+        self.tiers.get(tier).map(|ach_tier| ach_tier.name.as_str())
+    }
+    fn tier_color(&self, tier: usize) -> Color {
+        match self.tiers.get(tier) {
+            Some(ach_tier) => Color::rgb(ach_tier.color[0], ach_tier.color[1], ach_tier.color[2]),
+            None => self.color(),
+        }
+    }
+    fn lifetime_progress(&self, counters: &AchievementCounters, tier: usize) -> Option<(u32, u32)> {
+        match self.condition.kind {
+            AchievementKind::Run => None,
+            AchievementKind::Lifetime => {
+                Some((counters.fetch(&self.condition.field), self.target(tier)))
+            }
+        }
+    }
+    fn progress_ui(&self) -> AchievementProgressUi {
+        self.progress_ui.clone()
+    }
+    fn format_value(&self, value: u32) -> String {
+        match &self.value_format {
+            Some(field) => reactor::field::format_field_text(field, value),
+            None => format!("{}", value),
+        }
+    }
+}
+
+#[derive(Asset, TypePath, Deserialize, Debug)]
+pub struct AchievementDefList(Vec<AchievementDef>);
+
+#[derive(Resource)]
+pub struct AchievementDefHandle(Handle<AchievementDefList>);
+
+#[derive(Default)]
+pub struct AchievementDefLoader;
+
+impl AssetLoader for AchievementDefLoader {
+    type Asset = AchievementDefList;
+    type Settings = ();
+    type Error = Box<dyn std::error::Error + Send + Sync>;
+    fn load<'a>(
+        &'a self,
+        reader: &'a mut Reader,
+        _settings: &'a (),
+        _load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<Self::Asset, Self::Error>> {
+        Box::pin(async move {
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes).await?;
+            let list: AchievementDefList = serde_json::from_slice(&bytes)?;
+            for ach_def in list.0.iter() {
+                ach_def.validate()?;
+            }
+            Ok(list)
+        })
+    }
+    fn extensions(&self) -> &[&str] {
+        &["ach.json"]
+    }
+}
+
+pub fn load_defs(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(AchievementDefHandle(asset_server.load(ASSET_PATH)));
+}
+
+pub fn register_defs(
+    mut events: EventReader<AssetEvent<AchievementDefList>>,
+    def_lists: Res<Assets<AchievementDefList>>,
+    def_handle: Res<AchievementDefHandle>,
+    mut ach_defs: ResMut<AchievementDefs>,
+) {
+    for event in events.read() {
+        match event {
+            AssetEvent::LoadedWithDependencies { id } | AssetEvent::Modified { id } => {
+                if *id != def_handle.0.id() {
+                    continue;
+                }
+                if let Some(def_list) = def_lists.get(*id) {
+                    *ach_defs = AchievementDefs::new(
+                        def_list
+                            .0
+                            .iter()
+                            .map(|ach_def| Arc::new(ach_def.clone()) as Arc<dyn AchievementDefBase>)
+                            .collect(),
+                    );
+                }
+            }
+            _ => (),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_def(condition: &str, tiers: &str) -> AchievementDef {
+        serde_json::from_str(&format!(
+            r#"{{
+                "code": "test",
+                "name": "Test",
+                "color": [1.0, 1.0, 1.0],
+                "description": "Test {{target}}",
+                "condition": {},
+                "tiers": {},
+                "progress_ui": "bar"
+            }}"#,
+            condition, tiers
+        ))
+        .unwrap()
+    }
+
+    fn tiers(targets: &[u32]) -> String {
+        let tiers: Vec<String> = targets
+            .iter()
+            .map(|target| {
+                format!(
+                    r#"{{ "name": "T", "target": {}, "color": [0, 0, 0] }}"#,
+                    target
+                )
+            })
+            .collect();
+        format!("[{}]", tiers.join(","))
+    }
+
+    #[test]
+    fn accepts_increasing_tiers() {
+        let ach_def = parse_def(r#"{ "field": "score" }"#, &tiers(&[10, 20, 30]));
+        assert!(ach_def.validate().is_ok());
+        assert_eq!(ach_def.tier_count(), 3);
+        assert_eq!(ach_def.target(2), 30);
+    }
+
+    #[test]
+    fn rejects_unordered_or_zero_tiers() {
+        let flat = parse_def(r#"{ "field": "score" }"#, &tiers(&[10, 10]));
+        assert!(flat.validate().is_err());
+        let falling = parse_def(r#"{ "field": "score" }"#, &tiers(&[20, 10]));
+        assert!(falling.validate().is_err());
+        let zero = parse_def(r#"{ "field": "score" }"#, &tiers(&[0, 10]));
+        assert!(zero.validate().is_err());
+        let untargeted = parse_def(r#"{ "field": "score" }"#, "[]");
+        assert!(untargeted.validate().is_err());
+    }
+
+    #[test]
+    fn rejects_zero_scale_and_unknown_fields() {
+        let unscaled = parse_def(r#"{ "field": "score", "target": 5, "scale": 0 }"#, "[]");
+        assert!(unscaled.validate().is_err());
+        let unknown = parse_def(r#"{ "field": "unknown", "target": 5 }"#, "[]");
+        assert!(unknown.validate().is_err());
+        let run_only = parse_def(
+            r#"{ "kind": "lifetime", "field": "alpha_count", "target": 5 }"#,
+            "[]",
+        );
+        assert!(run_only.validate().is_err());
+    }
+}
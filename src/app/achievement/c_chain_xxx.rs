@@ -1,4 +1,4 @@
-use crate::app::achievement::*;
+use crate::{app, app::achievement::*};
 
 pub struct AchievementDef;
 
@@ -8,16 +8,21 @@ impl AchievementDefBase for AchievementDef {
     fn code(&self) -> &str {
         "c_chain_xxx"
     }
-    fn name(&self) -> &str {
-        "Fairy"
+    fn name(&self, locale: &app::locale::Locale) -> String {
+        locale.tr("Fairy")
     }
     fn color(&self) -> Color {
         Color::rgb(0.20, 0.82, 0.39)
     }
-    fn description(&self) -> String {
-        format!("C-Chain length >= {}", TARGET_CHAIN_LENGTH)
+    fn description(&self, locale: &app::locale::Locale, _tier: usize) -> String {
+        locale
+            .tr("C-Chain length >= {target}")
+            .replace("{target}", &TARGET_CHAIN_LENGTH.to_string())
     }
-    fn check_done(&self, status: &ResMut<status::ReactorStatus>) -> (u32, u32, bool) {
+    fn target(&self, _tier: usize) -> u32 {
+        TARGET_CHAIN_LENGTH
+    }
+    fn check_done(&self, status: &ResMut<status::ReactorStatus>, _tier: usize) -> (u32, u32, bool) {
         let current = match status.current_chain() {
             status::StatusChain::Control => status.fetch("chain_length"),
             _ => 0,
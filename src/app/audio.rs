@@ -1,10 +1,33 @@
 use crate::app::settings;
-use bevy::{audio::*, prelude::*};
+use bevy::{audio::*, prelude::*, utils::HashMap, window::WindowFocused};
 use bevy_persistent::prelude::*;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+const BGM_DUCKED_RATIO: f32 = 0.25;
+
+static VOICE_ORDER: AtomicU32 = AtomicU32::new(0);
 
 #[derive(Component)]
 pub struct AudioBgm;
 
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum AudioBus {
+    Bgm,
+    Game,
+    Ui,
+}
+
+impl AudioBus {
+    fn field(&self) -> &str {
+        match self {
+            AudioBus::Bgm => "bgm",
+            AudioBus::Game => "se",
+            AudioBus::Ui => "ui_se",
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
 pub enum AudioSe {
     Pop,
     PowerUp,
@@ -12,6 +35,63 @@ pub enum AudioSe {
     Hit,
     Tada,
     Focus,
+    Warning,
+}
+
+impl AudioSe {
+    pub fn bus(&self) -> AudioBus {
+        match self {
+            AudioSe::Focus => AudioBus::Ui,
+            _ => AudioBus::Game,
+        }
+    }
+    // NOTE: the oldest voice of the same sound is stolen once the limit is reached
+    fn max_voices(&self) -> usize {
+        match self {
+            AudioSe::Pop => 4,
+            AudioSe::Hit => 3,
+            AudioSe::PowerUp => 2,
+            AudioSe::Boom => 1,
+            AudioSe::Tada => 1,
+            AudioSe::Focus => 1,
+            AudioSe::Warning => 1,
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct AudioSeVoice {
+    se_type: AudioSe,
+    order: u32,
+}
+
+#[derive(Resource)]
+pub struct AudioMixer {
+    bgm_ducked: bool,
+    window_focused: bool,
+}
+
+impl Default for AudioMixer {
+    fn default() -> Self {
+        AudioMixer {
+            bgm_ducked: false,
+            window_focused: true,
+        }
+    }
+}
+
+impl AudioMixer {
+    fn gain(&self, settings: &settings::Settings, bus: AudioBus) -> f32 {
+        if settings.is_enabled("mute_unfocused") && !self.window_focused {
+            return 0.0;
+        }
+        let ducked_ratio = if bus == AudioBus::Bgm && self.bgm_ducked {
+            BGM_DUCKED_RATIO
+        } else {
+            1.0
+        };
+        bus_volume(settings, bus) * ducked_ratio
+    }
 }
 
 #[derive(Resource, Default)]
@@ -22,6 +102,7 @@ pub struct AudioSeAsset {
     hit: Option<Handle<AudioSource>>,
     tada: Option<Handle<AudioSource>>,
     focus: Option<Handle<AudioSource>>,
+    warning: Option<Handle<AudioSource>>,
 }
 
 impl AudioSeAsset {
@@ -33,6 +114,7 @@ impl AudioSeAsset {
             AudioSe::Hit => &self.hit,
             AudioSe::Tada => &self.tada,
             AudioSe::Focus => &self.focus,
+            AudioSe::Warning => &self.warning,
         }
     }
     pub fn set(&mut self, se_type: AudioSe, handle: Handle<AudioSource>) {
@@ -43,15 +125,20 @@ impl AudioSeAsset {
             AudioSe::Hit => self.hit = Some(handle),
             AudioSe::Tada => self.tada = Some(handle),
             AudioSe::Focus => self.focus = Some(handle),
+            AudioSe::Warning => self.warning = Some(handle),
         }
     }
 }
 
-pub struct AudioSeAssetPlugin;
+pub struct AudioMixerPlugin;
 
-impl Plugin for AudioSeAssetPlugin {
+impl Plugin for AudioMixerPlugin {
     fn build(&self, app: &mut App) {
-        app.insert_resource(AudioSeAsset::default());
+        app.insert_resource(AudioSeAsset::default())
+            .init_resource::<AudioMixer>()
+            .add_systems(PreUpdate, track_window_focus)
+            .add_systems(Update, apply_bus_volumes)
+            .add_systems(PostUpdate, limit_se_voices);
     }
 }
 
@@ -60,9 +147,10 @@ pub fn startup(
     asset_server: &Res<AssetServer>,
     audio_se_asset: &mut ResMut<AudioSeAsset>,
     settings: &Res<Persistent<settings::Settings>>,
+    bgm_path: &str,
 ) {
     init_se_asset(audio_se_asset, asset_server);
-    build_bgm(commands, asset_server, settings);
+    build_bgm(commands, asset_server, settings, bgm_path);
 }
 
 fn init_se_asset(audio_se_asset: &mut ResMut<AudioSeAsset>, asset_server: &Res<AssetServer>) {
@@ -72,19 +160,21 @@ fn init_se_asset(audio_se_asset: &mut ResMut<AudioSeAsset>, asset_server: &Res<A
     audio_se_asset.set(AudioSe::Hit, asset_server.load("audio/se/break_parts.ogg"));
     audio_se_asset.set(AudioSe::Tada, asset_server.load("audio/se/tada.ogg"));
     audio_se_asset.set(AudioSe::Focus, asset_server.load("audio/se/ui_focus.ogg"));
+    audio_se_asset.set(AudioSe::Warning, asset_server.load("audio/se/warning.wav"));
 }
 
 fn build_bgm(
     commands: &mut Commands,
     asset_server: &Res<AssetServer>,
     settings: &Res<Persistent<settings::Settings>>,
+    bgm_path: &str,
 ) {
     commands.spawn((
         AudioBundle {
-            source: asset_server.load("audio/bgm/main.ogg"),
+            source: asset_server.load(bgm_path.to_string()),
             settings: PlaybackSettings {
                 mode: bevy::audio::PlaybackMode::Loop,
-                volume: Volume::Absolute(VolumeLevel::new(to_volume(settings.get_value("bgm")))),
+                volume: Volume::Absolute(VolumeLevel::new(bus_volume(settings, AudioBus::Bgm))),
                 paused: !settings.is_enabled("bgm"),
                 ..default()
             },
@@ -93,43 +183,107 @@ fn build_bgm(
     ));
 }
 
-pub fn reduce_bgm_volume(
-    settings: Res<Persistent<settings::Settings>>,
-    audio_bgm_query: Query<&AudioSink, With<AudioBgm>>,
-) {
-    if let Ok(sink) = audio_bgm_query.get_single() {
-        sink.set_volume(to_volume(settings.get_value("bgm")) / 4.0);
-    }
+pub fn reduce_bgm_volume(mut mixer: ResMut<AudioMixer>) {
+    mixer.bgm_ducked = true;
 }
 
-pub fn roll_bgm_volume_back(
-    settings: Res<Persistent<settings::Settings>>,
-    audio_bgm_query: Query<&AudioSink, With<AudioBgm>>,
+pub fn roll_bgm_volume_back(mut mixer: ResMut<AudioMixer>) {
+    mixer.bgm_ducked = false;
+}
+
+pub fn switch_bgm(
+    commands: &mut Commands,
+    asset_server: &Res<AssetServer>,
+    settings: &Res<Persistent<settings::Settings>>,
+    audio_bgm_query: &Query<Entity, With<AudioBgm>>,
+    bgm_path: &str,
 ) {
-    if let Ok(sink) = audio_bgm_query.get_single() {
-        sink.set_volume(to_volume(settings.get_value("bgm")));
+    for entity in audio_bgm_query.iter() {
+        commands.entity(entity).despawn_recursive();
     }
+    build_bgm(commands, asset_server, settings, bgm_path);
 }
 
-pub fn to_volume(settings_value: u8) -> f32 {
+fn to_volume(settings_value: u8) -> f32 {
     settings_value as f32 * 0.02
 }
 
+pub fn bus_volume(settings: &settings::Settings, bus: AudioBus) -> f32 {
+    settings.get_value("master") as f32 * 0.01 * to_volume(settings.get_value(bus.field()))
+}
+
 pub fn play_se(
     se_type: AudioSe,
     commands: &mut Commands,
     audio_se_asset: &Res<AudioSeAsset>,
     settings: &Persistent<settings::Settings>,
 ) {
-    if settings.is_enabled("se") {
-        commands.spawn((AudioBundle {
-            source: audio_se_asset.get(se_type).as_ref().unwrap().clone(),
-            settings: PlaybackSettings {
-                mode: bevy::audio::PlaybackMode::Despawn,
-                volume: Volume::Absolute(VolumeLevel::new(to_volume(settings.get_value("se")))),
-                paused: false,
-                ..default()
+    play_se_with_speed(se_type, 1.0, commands, audio_se_asset, settings);
+}
+
+pub fn play_se_with_speed(
+    se_type: AudioSe,
+    speed: f32,
+    commands: &mut Commands,
+    audio_se_asset: &Res<AudioSeAsset>,
+    settings: &Persistent<settings::Settings>,
+) {
+    let bus = se_type.bus();
+    if settings.is_enabled(bus.field()) {
+        commands.spawn((
+            AudioBundle {
+                source: audio_se_asset.get(se_type).as_ref().unwrap().clone(),
+                settings: PlaybackSettings {
+                    mode: bevy::audio::PlaybackMode::Despawn,
+                    volume: Volume::Absolute(VolumeLevel::new(bus_volume(settings, bus))),
+                    paused: false,
+                    speed,
+                    ..default()
+                },
+            },
+            AudioSeVoice {
+                se_type,
+                order: VOICE_ORDER.fetch_add(1, Ordering::Relaxed),
             },
-        },));
+        ));
+    }
+}
+
+fn track_window_focus(mut events: EventReader<WindowFocused>, mut mixer: ResMut<AudioMixer>) {
+    if let Some(event) = events.read().last() {
+        mixer.window_focused = event.focused;
+    }
+}
+
+fn limit_se_voices(mut commands: Commands, voice_query: Query<(Entity, &AudioSeVoice)>) {
+    let mut voices: Vec<(Entity, &AudioSeVoice)> = voice_query.iter().collect();
+    voices.sort_by_key(|(_, voice)| std::cmp::Reverse(voice.order));
+    let mut counts: HashMap<AudioSe, usize> = HashMap::new();
+    for (entity, voice) in voices {
+        let count = counts.entry(voice.se_type).or_default();
+        *count += 1;
+        if *count > voice.se_type.max_voices() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+// NOTE: voices started since the last run are caught too, so a muted window stays silent
+fn apply_bus_volumes(
+    settings: Res<Persistent<settings::Settings>>,
+    mixer: Res<AudioMixer>,
+    audio_bgm_query: Query<&AudioSink, With<AudioBgm>>,
+    voice_query: Query<(&AudioSeVoice, Ref<AudioSink>)>,
+) {
+    let is_changed = settings.is_changed() || mixer.is_changed();
+    if is_changed {
+        if let Ok(sink) = audio_bgm_query.get_single() {
+            sink.set_volume(mixer.gain(&settings, AudioBus::Bgm));
+        }
+    }
+    for (voice, sink) in voice_query.iter() {
+        if is_changed || sink.is_added() {
+            sink.set_volume(mixer.gain(&settings, voice.se_type.bus()));
+        }
     }
 }
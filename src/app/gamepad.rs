@@ -0,0 +1,182 @@
+use crate::app;
+use bevy::input::gamepad::GamepadAxisType;
+use bevy::prelude::*;
+use bevy_persistent::prelude::*;
+use bevy_ui_navigation::systems::InputMapping;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, PartialEq, Default, Serialize, Deserialize, Debug)]
+pub enum ResponseCurve {
+    #[default]
+    Linear,
+    Quadratic,
+    Cubic,
+}
+
+impl ResponseCurve {
+    pub fn name(&self) -> &str {
+        match self {
+            ResponseCurve::Linear => "Linear",
+            ResponseCurve::Quadratic => "Quadratic",
+            ResponseCurve::Cubic => "Cubic",
+        }
+    }
+    pub fn next(&self) -> Self {
+        match self {
+            ResponseCurve::Linear => ResponseCurve::Quadratic,
+            ResponseCurve::Quadratic => ResponseCurve::Cubic,
+            ResponseCurve::Cubic => ResponseCurve::Linear,
+        }
+    }
+    fn apply(&self, value: f32) -> f32 {
+        match self {
+            ResponseCurve::Linear => value,
+            ResponseCurve::Quadratic => value.powi(2),
+            ResponseCurve::Cubic => value.powi(3),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Default, Serialize, Deserialize, Debug)]
+pub enum StickChoice {
+    #[default]
+    Both,
+    Left,
+    Right,
+}
+
+impl StickChoice {
+    pub fn name(&self) -> &str {
+        match self {
+            StickChoice::Both => "Both",
+            StickChoice::Left => "Left",
+            StickChoice::Right => "Right",
+        }
+    }
+    pub fn next(&self) -> Self {
+        match self {
+            StickChoice::Both => StickChoice::Left,
+            StickChoice::Left => StickChoice::Right,
+            StickChoice::Right => StickChoice::Both,
+        }
+    }
+    fn axes(&self) -> Vec<(GamepadAxisType, GamepadAxisType)> {
+        let left = (GamepadAxisType::LeftStickX, GamepadAxisType::LeftStickY);
+        let right = (GamepadAxisType::RightStickX, GamepadAxisType::RightStickY);
+        match self {
+            StickChoice::Both => vec![left, right],
+            StickChoice::Left => vec![left],
+            StickChoice::Right => vec![right],
+        }
+    }
+}
+
+#[derive(Clone, Copy, Serialize, Deserialize, Debug)]
+pub struct GamepadTuning {
+    pub inner_dead_zone: u8,
+    pub outer_dead_zone: u8,
+    pub curve: ResponseCurve,
+    pub stick: StickChoice,
+}
+
+impl Default for GamepadTuning {
+    fn default() -> Self {
+        Self {
+            inner_dead_zone: 25,
+            outer_dead_zone: 100,
+            curve: ResponseCurve::default(),
+            stick: StickChoice::default(),
+        }
+    }
+}
+
+impl GamepadTuning {
+    pub fn apply(&self, raw: Vec2) -> Vec2 {
+        let length = raw.length();
+        let inner = self.inner_dead_zone as f32 / 100.0;
+        let outer = self.outer_dead_zone as f32 / 100.0;
+        if length <= inner {
+            return Vec2::ZERO;
+        }
+        let ratio = if outer > inner {
+            ((length - inner) / (outer - inner)).min(1.0)
+        } else {
+            1.0
+        };
+        raw / length * self.curve.apply(ratio)
+    }
+}
+
+#[derive(Resource, PartialEq, Default)]
+pub struct ActiveGamepad {
+    pub gamepad: Option<Gamepad>,
+    pub name: String,
+}
+
+pub struct GamepadPlugin;
+
+impl Plugin for GamepadPlugin {
+    fn build(&self, app: &mut App) {
+        // NOTE: runs before the input systems drop a disconnected pad, so the
+        // frame handling the disconnection still sees it as the active one
+        app.init_resource::<ActiveGamepad>().add_systems(
+            PreUpdate,
+            update_active_gamepad.before(bevy::input::InputSystem),
+        );
+    }
+}
+
+fn update_active_gamepad(
+    gamepads: Res<Gamepads>,
+    mut active_gamepad: ResMut<ActiveGamepad>,
+    settings: Res<Persistent<app::settings::Settings>>,
+    mut input_mapping: ResMut<InputMapping>,
+) {
+    let preferred = gamepads
+        .iter()
+        .find(|gamepad| gamepads.name(*gamepad) == Some(settings.fetch_active_gamepad()));
+    // NOTE: falling back to another pad is temporary, the saved choice only
+    // changes when the player picks a pad in the settings page
+    let gamepad = preferred.or_else(|| gamepads.iter().next());
+    let name = gamepad
+        .and_then(|gamepad| gamepads.name(gamepad))
+        .map(String::from)
+        .unwrap_or_default();
+    if !active_gamepad.set_if_neq(ActiveGamepad { gamepad, name }) {
+        return;
+    }
+    input_mapping.gamepads = gamepad.into_iter().collect();
+}
+
+pub fn next_gamepad(gamepads: &Gamepads, active_gamepad: &ActiveGamepad) -> Option<String> {
+    let connected = gamepads.iter().collect::<Vec<_>>();
+    let index = connected
+        .iter()
+        .position(|gamepad| Some(*gamepad) == active_gamepad.gamepad)
+        .map_or(0, |index| (index + 1) % connected.len());
+    connected
+        .get(index)
+        .and_then(|gamepad| gamepads.name(*gamepad))
+        .map(String::from)
+}
+
+pub fn read_stick(
+    active_gamepad: &ActiveGamepad,
+    axes: &Axis<GamepadAxis>,
+    tuning: &GamepadTuning,
+) -> Vec2 {
+    let Some(gamepad) = active_gamepad.gamepad else {
+        return Vec2::ZERO;
+    };
+    let fetch_axis = |axis_type| {
+        axes.get(GamepadAxis::new(gamepad, axis_type))
+            .unwrap_or(0.0)
+    };
+    tuning
+        .stick
+        .axes()
+        .into_iter()
+        .map(|(axis_x, axis_y)| tuning.apply(Vec2::new(fetch_axis(axis_x), fetch_axis(axis_y))))
+        .max_by(|a, b| a.length().total_cmp(&b.length()))
+        .unwrap_or(Vec2::ZERO)
+}
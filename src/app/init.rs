@@ -1,7 +1,8 @@
 use crate::{app, reactor};
 use bevy::{
     prelude::*,
-    window::{Cursor, CursorIcon, PresentMode, WindowMode, WindowTheme},
+    render::camera::ScalingMode,
+    window::{Cursor, CursorIcon, PresentMode, WindowMode, WindowResizeConstraints, WindowTheme},
 };
 use bevy_persistent::prelude::*;
 
@@ -17,9 +18,10 @@ impl Plugin for InitPlugin {
                 fit_canvas_to_parent: true,
                 prevent_default_event_handling: false,
                 window_theme: Some(WindowTheme::Dark),
-                enabled_buttons: bevy::window::EnabledButtons {
-                    maximize: false,
-                    ..Default::default()
+                resize_constraints: WindowResizeConstraints {
+                    min_width: app::WINDOW_W / 2.0,
+                    min_height: app::WINDOW_H / 2.0,
+                    ..default()
                 },
                 cursor: Cursor {
                     icon: CursorIcon::Crosshair,
@@ -28,16 +30,21 @@ impl Plugin for InitPlugin {
                 ..default()
             }),
             ..default()
-        }));
+        }))
+        .add_systems(Update, update_ui_scale);
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn startup(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     mut audio_se_asset: ResMut<app::audio::AudioSeAsset>,
     settings: Res<Persistent<app::settings::Settings>>,
+    unlock_store: Res<Persistent<app::unlock::UnlockStore>>,
     mut window_query: Query<&mut Window>,
+    theme: Res<app::theme::Theme>,
+    locale: Res<app::locale::Locale>,
 ) {
     // window
     let mut window = window_query.single_mut();
@@ -48,11 +55,53 @@ pub fn startup(
     }
 
     // audio
-    app::audio::startup(&mut commands, &asset_server, &mut audio_se_asset, &settings);
+    app::audio::startup(
+        &mut commands,
+        &asset_server,
+        &mut audio_se_asset,
+        &settings,
+        unlock_store.bgm_path(),
+    );
 
     // camera
-    commands.spawn(Camera2dBundle::default());
+    let mut camera = Camera2dBundle::default();
+    camera.projection.scaling_mode = ScalingMode::AutoMin {
+        min_width: app::WINDOW_W,
+        min_height: app::WINDOW_H,
+    };
+    commands.spawn(camera);
 
     // reactor
-    reactor::startup(&mut commands, &asset_server);
+    reactor::startup(&mut commands, &asset_server, &theme, &locale);
+}
+
+fn update_ui_scale(
+    window_query: Query<&Window>,
+    settings: Res<Persistent<app::settings::Settings>>,
+    mut ui_scale: ResMut<UiScale>,
+    mut fit_query: Query<(&app::ui::FitToField, &mut Style)>,
+) {
+    let Ok(window) = window_query.get_single() else {
+        return;
+    };
+    let fit_ratio = (window.width() / app::WINDOW_W).min(window.height() / app::WINDOW_H);
+    let user_ratio = app::ui::to_ui_scale(settings.get_value("ui_scale"));
+    let scale = (fit_ratio * user_ratio) as f64;
+    if ui_scale.0 != scale {
+        ui_scale.0 = scale;
+    }
+    for (fit_to_field, mut style) in fit_query.iter_mut() {
+        if let Some(width) = fit_to_field.width {
+            let width = Val::Px(width / user_ratio);
+            if style.width != width {
+                style.width = width;
+            }
+        }
+        if let Some(height) = fit_to_field.height {
+            let height = Val::Px(height / user_ratio);
+            if style.height != height {
+                style.height = height;
+            }
+        }
+    }
 }
@@ -46,6 +46,7 @@ fn update_button_interaction(
     mut commands: Commands,
     audio_se_asset: Res<app::audio::AudioSeAsset>,
     settings: Res<Persistent<app::settings::Settings>>,
+    theme: Res<app::theme::Theme>,
 ) {
     for (focus, mut color) in focusables.iter_mut() {
         let new_color = if matches!(focus.state(), FocusState::Focused) {
@@ -55,9 +56,9 @@ fn update_button_interaction(
                 &audio_se_asset,
                 settings.as_ref(),
             );
-            app::ui::BTN_HOVERED_BG
+            theme.btn_hovered_bg
         } else {
-            app::ui::BTN_BG
+            theme.btn_bg
         };
         *color = new_color.into();
     }
@@ -70,6 +71,7 @@ fn update_switch_interaction(
     mut commands: Commands,
     audio_se_asset: Res<app::audio::AudioSeAsset>,
     settings: Res<Persistent<app::settings::Settings>>,
+    theme: Res<app::theme::Theme>,
 ) {
     for (focus, mut color) in focusables.iter_mut() {
         let new_color = if matches!(focus.state(), FocusState::Focused) {
@@ -79,9 +81,9 @@ fn update_switch_interaction(
                 &audio_se_asset,
                 settings.as_ref(),
             );
-            app::ui::SECONDARY_COLOR
+            theme.secondary
         } else {
-            app::ui::BG_COLOR
+            theme.bg
         };
         *color = new_color.into();
     }
@@ -94,6 +96,7 @@ fn update_slider_interaction(
     mut commands: Commands,
     audio_se_asset: Res<app::audio::AudioSeAsset>,
     settings: Res<Persistent<app::settings::Settings>>,
+    theme: Res<app::theme::Theme>,
 ) {
     for (focus, mut color) in focusables.iter_mut() {
         let new_color = if matches!(focus.state(), FocusState::Focused) {
@@ -103,9 +106,9 @@ fn update_slider_interaction(
                 &audio_se_asset,
                 settings.as_ref(),
             );
-            app::ui::SECONDARY_COLOR
+            theme.secondary
         } else {
-            app::ui::BG_COLOR
+            theme.bg
         };
         *color = new_color.into();
     }
@@ -118,6 +121,7 @@ fn update_link_interaction(
     mut commands: Commands,
     audio_se_asset: Res<app::audio::AudioSeAsset>,
     settings: Res<Persistent<app::settings::Settings>>,
+    theme: Res<app::theme::Theme>,
 ) {
     for (focus, mut color) in focusables.iter_mut() {
         let new_color = if matches!(focus.state(), FocusState::Focused) {
@@ -127,9 +131,9 @@ fn update_link_interaction(
                 &audio_se_asset,
                 settings.as_ref(),
             );
-            app::ui::FG_COLOR
+            theme.fg
         } else {
-            app::ui::BG_COLOR
+            theme.bg
         };
         *color = new_color.into();
     }
@@ -137,12 +141,15 @@ fn update_link_interaction(
 
 type FocusablePanel = (Changed<Focusable>, With<IaPanel>);
 
-fn update_panel_interaction(mut focusables: Query<(&Focusable, &mut BorderColor), FocusablePanel>) {
+fn update_panel_interaction(
+    mut focusables: Query<(&Focusable, &mut BorderColor), FocusablePanel>,
+    theme: Res<app::theme::Theme>,
+) {
     for (focus, mut color) in focusables.iter_mut() {
         let new_color = if matches!(focus.state(), FocusState::Focused) {
-            app::ui::FG_COLOR
+            theme.fg
         } else {
-            app::ui::MUTE_COLOR
+            theme.mute
         };
         *color = new_color.into();
     }
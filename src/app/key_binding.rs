@@ -1,9 +1,13 @@
+use crate::app;
 use bevy::prelude::*;
+use bevy::utils::HashMap;
+use bevy_persistent::prelude::*;
 use bevy_ui_navigation::{
     events::Direction,
     prelude::{NavRequest, NavRequestSystem},
     systems::InputMapping,
 };
+use serde::{Deserialize, Serialize};
 
 #[derive(PartialEq, Default)]
 pub enum KeyBindingMode {
@@ -11,6 +15,7 @@ pub enum KeyBindingMode {
     Navgation,
     Gaming,
     Keyboard,
+    Rebinding,
 }
 
 #[derive(Resource, Default)]
@@ -18,6 +23,265 @@ pub struct KeyBindingConfig {
     pub mode: KeyBindingMode,
 }
 
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Debug)]
+pub enum GameAction {
+    MoveUp,
+    MoveDown,
+    MoveLeft,
+    MoveRight,
+    Slow,
+    Dash,
+    Bomb,
+    Pause,
+    Confirm,
+    Cancel,
+}
+
+pub const GAME_ACTIONS: [GameAction; 10] = [
+    GameAction::MoveUp,
+    GameAction::MoveDown,
+    GameAction::MoveLeft,
+    GameAction::MoveRight,
+    GameAction::Slow,
+    GameAction::Dash,
+    GameAction::Bomb,
+    GameAction::Pause,
+    GameAction::Confirm,
+    GameAction::Cancel,
+];
+
+impl GameAction {
+    pub fn name(&self) -> &str {
+        match self {
+            GameAction::MoveUp => "Up",
+            GameAction::MoveDown => "Down",
+            GameAction::MoveLeft => "Left",
+            GameAction::MoveRight => "Right",
+            GameAction::Slow => "Slow",
+            GameAction::Dash => "Dash",
+            GameAction::Bomb => "Bomb",
+            GameAction::Pause => "Pause",
+            GameAction::Confirm => "Confirm",
+            GameAction::Cancel => "Cancel",
+        }
+    }
+    // NOTE: menu and gameplay actions are never read together, so they may share inputs
+    fn shares_mode_with(&self, other: &GameAction) -> bool {
+        let is_menu =
+            |action: &GameAction| matches!(action, GameAction::Confirm | GameAction::Cancel);
+        let is_gaming = |action: &GameAction| {
+            matches!(
+                action,
+                GameAction::Slow | GameAction::Dash | GameAction::Bomb | GameAction::Pause
+            )
+        };
+        !(is_menu(self) && is_gaming(other) || is_gaming(self) && is_menu(other))
+    }
+    fn default_keys(&self) -> Vec<KeyCode> {
+        match self {
+            GameAction::MoveUp => vec![KeyCode::W, KeyCode::Up, KeyCode::K],
+            GameAction::MoveDown => vec![KeyCode::S, KeyCode::Down, KeyCode::J],
+            GameAction::MoveLeft => vec![KeyCode::A, KeyCode::Left, KeyCode::H],
+            GameAction::MoveRight => vec![KeyCode::D, KeyCode::Right, KeyCode::L],
+            GameAction::Slow => vec![KeyCode::ShiftLeft, KeyCode::ShiftRight],
+            GameAction::Dash => vec![KeyCode::E, KeyCode::X],
+            GameAction::Bomb => vec![KeyCode::Q, KeyCode::C],
+            GameAction::Pause => vec![
+                KeyCode::Space,
+                KeyCode::Back,
+                KeyCode::Delete,
+                KeyCode::Escape,
+            ],
+            GameAction::Confirm => vec![KeyCode::Space],
+            GameAction::Cancel => vec![KeyCode::Delete],
+        }
+    }
+    fn default_buttons(&self) -> Vec<GamepadButtonType> {
+        match self {
+            GameAction::MoveUp => vec![GamepadButtonType::DPadUp],
+            GameAction::MoveDown => vec![GamepadButtonType::DPadDown],
+            GameAction::MoveLeft => vec![GamepadButtonType::DPadLeft],
+            GameAction::MoveRight => vec![GamepadButtonType::DPadRight],
+            GameAction::Slow => vec![
+                GamepadButtonType::LeftTrigger,
+                GamepadButtonType::RightTrigger,
+            ],
+            GameAction::Dash => vec![GamepadButtonType::West, GamepadButtonType::RightTrigger2],
+            GameAction::Bomb => vec![GamepadButtonType::North, GamepadButtonType::LeftTrigger2],
+            GameAction::Pause => vec![GamepadButtonType::South, GamepadButtonType::Start],
+            GameAction::Confirm => vec![GamepadButtonType::South],
+            GameAction::Cancel => vec![GamepadButtonType::East],
+        }
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ActionBinding {
+    keys: Vec<KeyCode>,
+    buttons: Vec<GamepadButtonType>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct InputMap {
+    binding_map: HashMap<GameAction, ActionBinding>,
+}
+
+impl Default for InputMap {
+    fn default() -> Self {
+        let mut binding_map = HashMap::new();
+        for action in GAME_ACTIONS {
+            binding_map.insert(
+                action,
+                ActionBinding {
+                    keys: action.default_keys(),
+                    buttons: action.default_buttons(),
+                },
+            );
+        }
+        Self { binding_map }
+    }
+}
+
+impl InputMap {
+    pub fn keys(&self, action: GameAction) -> Vec<KeyCode> {
+        match self.binding_map.get(&action) {
+            Some(binding) => binding.keys.clone(),
+            None => action.default_keys(),
+        }
+    }
+    pub fn buttons(&self, action: GameAction) -> Vec<GamepadButtonType> {
+        match self.binding_map.get(&action) {
+            Some(binding) => binding.buttons.clone(),
+            None => action.default_buttons(),
+        }
+    }
+    pub fn bind_key(&mut self, action: GameAction, key: KeyCode) {
+        let mut keys = self.keys(action);
+        replace_primary(&mut keys, key);
+        let buttons = self.buttons(action);
+        self.binding_map
+            .insert(action, ActionBinding { keys, buttons });
+    }
+    pub fn bind_button(&mut self, action: GameAction, button: GamepadButtonType) {
+        let mut buttons = self.buttons(action);
+        replace_primary(&mut buttons, button);
+        let keys = self.keys(action);
+        self.binding_map
+            .insert(action, ActionBinding { keys, buttons });
+    }
+    pub fn key_conflict(&self, action: GameAction, key: KeyCode) -> Option<GameAction> {
+        GAME_ACTIONS.into_iter().find(|other| {
+            *other != action && action.shares_mode_with(other) && self.keys(*other).contains(&key)
+        })
+    }
+    pub fn button_conflict(
+        &self,
+        action: GameAction,
+        button: GamepadButtonType,
+    ) -> Option<GameAction> {
+        GAME_ACTIONS.into_iter().find(|other| {
+            *other != action
+                && action.shares_mode_with(other)
+                && self.buttons(*other).contains(&button)
+        })
+    }
+    pub fn is_pressed(
+        &self,
+        action: GameAction,
+        keyboard_input: &Input<KeyCode>,
+        gamepad_input: &Input<GamepadButton>,
+        active_gamepad: &app::gamepad::ActiveGamepad,
+    ) -> bool {
+        let buttons = self.buttons(action);
+        keyboard_input.any_pressed(self.keys(action))
+            || gamepad_input.get_pressed().any(|button| {
+                Some(button.gamepad) == active_gamepad.gamepad
+                    && buttons.contains(&button.button_type)
+            })
+    }
+    pub fn is_just_pressed(
+        &self,
+        action: GameAction,
+        keyboard_input: &Input<KeyCode>,
+        gamepad_input: &Input<GamepadButton>,
+        active_gamepad: &app::gamepad::ActiveGamepad,
+    ) -> bool {
+        let buttons = self.buttons(action);
+        keyboard_input.any_just_pressed(self.keys(action))
+            || gamepad_input.get_just_pressed().any(|button| {
+                Some(button.gamepad) == active_gamepad.gamepad
+                    && buttons.contains(&button.button_type)
+            })
+    }
+    pub fn move_delta(
+        &self,
+        keyboard_input: &Input<KeyCode>,
+        gamepad_input: &Input<GamepadButton>,
+        active_gamepad: &app::gamepad::ActiveGamepad,
+    ) -> Vec2 {
+        let mut delta: Vec2 = Vec2::default();
+        if self.is_pressed(
+            GameAction::MoveUp,
+            keyboard_input,
+            gamepad_input,
+            active_gamepad,
+        ) {
+            delta.y = -1.0;
+        }
+        if self.is_pressed(
+            GameAction::MoveDown,
+            keyboard_input,
+            gamepad_input,
+            active_gamepad,
+        ) {
+            delta.y = 1.0;
+        }
+        if self.is_pressed(
+            GameAction::MoveLeft,
+            keyboard_input,
+            gamepad_input,
+            active_gamepad,
+        ) {
+            delta.x = -1.0;
+        }
+        if self.is_pressed(
+            GameAction::MoveRight,
+            keyboard_input,
+            gamepad_input,
+            active_gamepad,
+        ) {
+            delta.x = 1.0;
+        }
+        delta
+    }
+    pub fn keys_text(&self, action: GameAction) -> String {
+        self.keys(action)
+            .iter()
+            .map(|key| format!("{:?}", key))
+            .collect::<Vec<_>>()
+            .join(" / ")
+    }
+    pub fn buttons_text(&self, action: GameAction) -> String {
+        self.buttons(action)
+            .iter()
+            .map(|button| format!("{:?}", button))
+            .collect::<Vec<_>>()
+            .join(" / ")
+    }
+}
+
+// NOTE: only the first input is rebound, the alternates are kept
+fn replace_primary<T: PartialEq>(inputs: &mut Vec<T>, input: T) {
+    if inputs.first() == Some(&input) {
+        return;
+    }
+    inputs.retain(|item| *item != input);
+    match inputs.first_mut() {
+        Some(primary) => *primary = input,
+        None => inputs.push(input),
+    }
+}
+
 pub struct KeyBindingPlugin;
 
 impl Plugin for KeyBindingPlugin {
@@ -30,41 +294,62 @@ impl Plugin for KeyBindingPlugin {
     }
 }
 
+// NOTE: gamepad buttons are routed through the input map, the sticks stay on the default mapping
+const UNMAPPED_BUTTON: GamepadButtonType = GamepadButtonType::Other(u8::MAX);
+
 fn setup_input_mapping(mut input_mapping: ResMut<InputMapping>) {
     input_mapping.keyboard_navigation = false;
     input_mapping.key_action = KeyCode::Return;
     input_mapping.focus_follows_mouse = true;
+    input_mapping.action_button = UNMAPPED_BUTTON;
+    input_mapping.cancel_button = UNMAPPED_BUTTON;
+    input_mapping.up_button = UNMAPPED_BUTTON;
+    input_mapping.down_button = UNMAPPED_BUTTON;
+    input_mapping.left_button = UNMAPPED_BUTTON;
+    input_mapping.right_button = UNMAPPED_BUTTON;
 }
 
-fn handle_key_binding(
+pub fn handle_key_binding(
     mut requests: EventWriter<NavRequest>,
-    input: Res<Input<KeyCode>>,
+    keyboard_input: Res<Input<KeyCode>>,
+    gamepad_input: Res<Input<GamepadButton>>,
+    active_gamepad: Res<app::gamepad::ActiveGamepad>,
     config: Res<KeyBindingConfig>,
+    settings: Res<Persistent<app::settings::Settings>>,
 ) {
-    move_by_arrow(&mut requests, &input);
+    let input_map = settings.fetch_input_map();
+    let is_just_pressed = |action: GameAction| {
+        input_map.is_just_pressed(action, &keyboard_input, &gamepad_input, &active_gamepad)
+    };
     match config.mode {
         KeyBindingMode::Navgation => {
-            if input.any_just_pressed([KeyCode::Space]) {
+            if is_just_pressed(GameAction::Confirm) {
                 requests.send(NavRequest::Action);
             }
-            if input.any_just_pressed([KeyCode::Delete]) {
+            if is_just_pressed(GameAction::Cancel) {
                 requests.send(NavRequest::Cancel);
             }
-            move_by_wsad(&mut requests, &input);
-            move_by_kjhl(&mut requests, &input);
+            if is_just_pressed(GameAction::MoveUp) {
+                requests.send(NavRequest::Move(Direction::North));
+            }
+            if is_just_pressed(GameAction::MoveDown) {
+                requests.send(NavRequest::Move(Direction::South));
+            }
+            if is_just_pressed(GameAction::MoveLeft) {
+                requests.send(NavRequest::Move(Direction::West));
+            }
+            if is_just_pressed(GameAction::MoveRight) {
+                requests.send(NavRequest::Move(Direction::East));
+            }
         }
         KeyBindingMode::Gaming => {
-            if input.any_just_pressed([
-                KeyCode::Space,
-                KeyCode::Back,
-                KeyCode::Delete,
-                KeyCode::Escape,
-            ]) {
+            if is_just_pressed(GameAction::Pause) {
                 requests.send(NavRequest::Action);
             }
         }
         // NOTE: use default key binding only
-        KeyBindingMode::Keyboard => (),
+        KeyBindingMode::Keyboard => move_by_arrow(&mut requests, &keyboard_input),
+        KeyBindingMode::Rebinding => (),
     }
 }
 
@@ -83,32 +368,30 @@ fn move_by_arrow(requests: &mut EventWriter<NavRequest>, input: &Res<Input<KeyCo
     }
 }
 
-fn move_by_wsad(requests: &mut EventWriter<NavRequest>, input: &Res<Input<KeyCode>>) {
-    if input.just_pressed(KeyCode::W) {
-        requests.send(NavRequest::Move(Direction::North));
-    }
-    if input.just_pressed(KeyCode::S) {
-        requests.send(NavRequest::Move(Direction::South));
-    }
-    if input.just_pressed(KeyCode::A) {
-        requests.send(NavRequest::Move(Direction::West));
-    }
-    if input.just_pressed(KeyCode::D) {
-        requests.send(NavRequest::Move(Direction::East));
-    }
-}
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-fn move_by_kjhl(requests: &mut EventWriter<NavRequest>, input: &Res<Input<KeyCode>>) {
-    if input.just_pressed(KeyCode::K) {
-        requests.send(NavRequest::Move(Direction::North));
-    }
-    if input.just_pressed(KeyCode::J) {
-        requests.send(NavRequest::Move(Direction::South));
+    #[test]
+    fn replaces_only_the_primary_input() {
+        let mut inputs = vec![KeyCode::W, KeyCode::Up];
+        replace_primary(&mut inputs, KeyCode::I);
+        assert_eq!(inputs, vec![KeyCode::I, KeyCode::Up]);
     }
-    if input.just_pressed(KeyCode::H) {
-        requests.send(NavRequest::Move(Direction::West));
+
+    #[test]
+    fn promotes_an_alternate_without_duplicating_it() {
+        let mut inputs = vec![KeyCode::W, KeyCode::Up];
+        replace_primary(&mut inputs, KeyCode::Up);
+        assert_eq!(inputs, vec![KeyCode::Up]);
+        replace_primary(&mut inputs, KeyCode::Up);
+        assert_eq!(inputs, vec![KeyCode::Up]);
     }
-    if input.just_pressed(KeyCode::L) {
-        requests.send(NavRequest::Move(Direction::East));
+
+    #[test]
+    fn binds_an_empty_action() {
+        let mut inputs: Vec<KeyCode> = Vec::new();
+        replace_primary(&mut inputs, KeyCode::Space);
+        assert_eq!(inputs, vec![KeyCode::Space]);
     }
 }
@@ -1,19 +1,20 @@
 use bevy::prelude::*;
 use bevy_persistent::prelude::*;
 use serde::{Deserialize, Serialize};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 #[cfg(not(target_arch = "wasm32"))]
 use crate::app;
 
 pub const MAX_PLAYER_NAME_LENGTH: usize = 12;
 pub const MAX_RECORDS_PER_LIST: usize = 9;
-pub const LEADERBOARD_LISTS: [&str; 5] = [
+pub const LEADERBOARD_LISTS: [&str; 6] = [
     "score",
     "time",
     "max_alpha_count",
     "max_control_chain",
     "max_hyper_chain",
+    "max_graze_streak",
 ];
 
 #[derive(Resource, Serialize, Deserialize, Clone, Debug, Default)]
@@ -32,10 +33,62 @@ pub struct LeaderboardRecord {
     pub max_hyper_level: u32,
     pub total_stopping_time: u32,
     pub max_stopping_time: u32,
+    pub max_graze_streak: u32,
+    pub bombs_used: u32,
     pub started_at: String,
     pub ended_at: String,
 }
 
+#[derive(Resource, Serialize, Deserialize, Default)]
+struct LegacyLeaderboard {
+    records: Vec<LegacyLeaderboardRecord>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+struct LegacyLeaderboardRecord {
+    player_name: String,
+    time: u32,
+    score: u32,
+    max_alpha_count: u32,
+    max_control_chain: u32,
+    max_hyper_chain: u32,
+    total_control_count: u32,
+    total_hyper_count: u32,
+    max_control_count: u32,
+    max_full_level_control_count: u32,
+    max_control_level: u32,
+    max_hyper_level: u32,
+    total_stopping_time: u32,
+    max_stopping_time: u32,
+    started_at: String,
+    ended_at: String,
+}
+
+impl From<&LegacyLeaderboardRecord> for LeaderboardRecord {
+    fn from(record: &LegacyLeaderboardRecord) -> Self {
+        LeaderboardRecord {
+            player_name: record.player_name.clone(),
+            time: record.time,
+            score: record.score,
+            max_alpha_count: record.max_alpha_count,
+            max_control_chain: record.max_control_chain,
+            max_hyper_chain: record.max_hyper_chain,
+            total_control_count: record.total_control_count,
+            total_hyper_count: record.total_hyper_count,
+            max_control_count: record.max_control_count,
+            max_full_level_control_count: record.max_full_level_control_count,
+            max_control_level: record.max_control_level,
+            max_hyper_level: record.max_hyper_level,
+            total_stopping_time: record.total_stopping_time,
+            max_stopping_time: record.max_stopping_time,
+            started_at: record.started_at.clone(),
+            ended_at: record.ended_at.clone(),
+            // NOTE: stats recorded after the baseline layout start from zero
+            ..default()
+        }
+    }
+}
+
 impl LeaderboardRecord {
     pub fn uid(&self) -> &str {
         &self.started_at
@@ -55,6 +108,9 @@ impl LeaderboardRecord {
             "max_control_level" => self.max_control_level,
             "max_hyper_level" => self.max_hyper_level,
             "max_stopping_time" => self.max_stopping_time,
+            "max_graze_streak" => self.max_graze_streak,
+            "bombs_used" => self.bombs_used,
+            "bomb_free_score" => self.score,
             _ => panic!("Invalid field"),
         }
     }
@@ -78,9 +134,10 @@ impl Leaderboard {
                 Reverse(record.max_alpha_count),
                 Reverse(record.max_control_chain),
                 Reverse(record.max_hyper_chain),
+                Reverse(record.max_graze_streak),
             )
         });
-        if self.records.len() > MAX_RECORDS_PER_LIST * 5 {
+        if self.records.len() > MAX_RECORDS_PER_LIST * LEADERBOARD_LISTS.len() {
             self.records.pop();
         }
     }
@@ -88,12 +145,17 @@ impl Leaderboard {
     pub fn fetch_records(&self, field: &str) -> Vec<LeaderboardRecord> {
         use std::cmp::Reverse;
         let mut records = self.records.clone();
+        // NOTE: bomb-free runs are a filtered score list rather than a stat of their own
+        if field == "bomb_free_score" {
+            records.retain(|record| record.bombs_used == 0);
+        }
         records.sort_by_key(|record| match field {
             "time" => (Reverse(record.time), Reverse(record.score)),
-            "score" => (Reverse(record.score), Reverse(record.time)),
+            "score" | "bomb_free_score" => (Reverse(record.score), Reverse(record.time)),
             "max_alpha_count" => (Reverse(record.max_alpha_count), Reverse(record.score)),
             "max_control_chain" => (Reverse(record.max_control_chain), Reverse(record.score)),
             "max_hyper_chain" => (Reverse(record.max_hyper_chain), Reverse(record.score)),
+            "max_graze_streak" => (Reverse(record.max_graze_streak), Reverse(record.score)),
             _ => panic!("Invalid record field"),
         });
         records.into_iter().take(MAX_RECORDS_PER_LIST).collect()
@@ -178,16 +240,107 @@ impl Plugin for LeaderboardPlugin {
         let config_dir = dirs::config_dir()
             .map(|native_config_dir| native_config_dir.join("chain-reaction"))
             .unwrap_or(Path::new("local").join("configuration"));
+        let leaderboard_path = config_dir.join("leaderboard.bin");
 
         app.insert_resource(
             Persistent::<Leaderboard>::builder()
                 .name("leaderboard")
                 .format(StorageFormat::Bincode)
-                .path(config_dir.join("leaderboard.bin"))
+                .path(leaderboard_path.clone())
                 .default(Leaderboard { records: vec![] })
                 .build()
-                .expect("failed to initialize variables"),
+                .unwrap_or_else(|_| migrate_legacy_leaderboard(leaderboard_path)),
         );
         app.insert_resource(LeaderboardRecord::default());
     }
 }
+
+fn migrate_legacy_leaderboard(path: PathBuf) -> Persistent<Leaderboard> {
+    let legacy_leaderboard = Persistent::<LegacyLeaderboard>::builder()
+        .name("legacy leaderboard")
+        .format(StorageFormat::Bincode)
+        .path(path.clone())
+        .default(LegacyLeaderboard::default())
+        .build()
+        .expect("failed to initialize variables");
+    // NOTE: the migrated records are written over the legacy file before loading,
+    //       reverting in memory would re-read the default with a wrong layout
+    let mut leaderboard = Persistent::<Leaderboard>::builder()
+        .name("leaderboard")
+        .format(StorageFormat::Bincode)
+        .path(path)
+        .default(Leaderboard {
+            records: legacy_leaderboard
+                .records
+                .iter()
+                .map(LeaderboardRecord::from)
+                .collect(),
+        })
+        .revertible(true)
+        .unloaded(true)
+        .build()
+        .expect("failed to initialize variables");
+    leaderboard
+        .revert_to_default()
+        .expect("failed to migrate leaderboard");
+    leaderboard.reload().expect("failed to migrate leaderboard");
+    leaderboard
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn migrates_baseline_records() {
+        let path = std::env::temp_dir().join(format!(
+            "chain-reaction-leaderboard-{}.bin",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_file(&path);
+        Persistent::<LegacyLeaderboard>::builder()
+            .name("legacy leaderboard")
+            .format(StorageFormat::Bincode)
+            .path(path.clone())
+            .default(LegacyLeaderboard {
+                records: vec![LegacyLeaderboardRecord {
+                    player_name: String::from("legacy"),
+                    time: 1200,
+                    score: 3400,
+                    max_alpha_count: 5,
+                    max_control_chain: 6,
+                    max_hyper_chain: 7,
+                    total_control_count: 8,
+                    total_hyper_count: 9,
+                    max_control_count: 10,
+                    max_full_level_control_count: 11,
+                    max_control_level: 12,
+                    max_hyper_level: 13,
+                    total_stopping_time: 14,
+                    max_stopping_time: 15,
+                    started_at: String::from("started"),
+                    ended_at: String::from("ended"),
+                }],
+            })
+            .build()
+            .unwrap();
+        assert!(Persistent::<Leaderboard>::builder()
+            .name("leaderboard")
+            .format(StorageFormat::Bincode)
+            .path(path.clone())
+            .default(Leaderboard { records: vec![] })
+            .build()
+            .is_err());
+
+        let leaderboard = migrate_legacy_leaderboard(path.clone());
+        let records = leaderboard.fetch_records("score");
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].player_name, "legacy");
+        assert_eq!(records[0].score, 3400);
+        assert_eq!(records[0].max_stopping_time, 15);
+        assert_eq!(records[0].max_graze_streak, 0);
+        assert_eq!(records[0].bombs_used, 0);
+        assert_eq!(leaderboard.fetch_records("bomb_free_score").len(), 1);
+    }
+}
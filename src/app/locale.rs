@@ -0,0 +1,218 @@
+use crate::app;
+use bevy::asset::{io::Reader, AssetLoader, AsyncReadExt, LoadContext};
+use bevy::prelude::*;
+use bevy::reflect::TypePath;
+use bevy::utils::{BoxedFuture, HashMap};
+use bevy_persistent::prelude::*;
+use serde::Deserialize;
+
+pub const DEFAULT_LANGUAGE: &str = "en";
+pub const LANGUAGES: [&str; 3] = ["en", "fr", "ja"];
+// NOTE: japanese stays out of the cycle until a CJK font is bundled with the game
+const SELECTABLE_LANGUAGES: [&str; 2] = ["en", "fr"];
+
+// NOTE: strings are keyed by their english text, so english needs no entries
+#[derive(Asset, TypePath, Deserialize, Clone, Debug)]
+pub struct StringTable {
+    code: String,
+    name: String,
+    #[serde(default)]
+    font: Option<String>,
+    #[serde(default)]
+    strings: HashMap<String, String>,
+}
+
+#[derive(Resource, Default)]
+pub struct Locale {
+    language: String,
+    tables: Vec<StringTable>,
+    loaded_fonts: Vec<String>,
+}
+
+impl Locale {
+    fn new(language: &str) -> Self {
+        Self {
+            language: String::from(language),
+            ..default()
+        }
+    }
+    fn active_table(&self) -> Option<&StringTable> {
+        self.tables.iter().find(|table| table.code == self.language)
+    }
+    pub fn tr(&self, text: &str) -> String {
+        self.active_table()
+            .and_then(|table| table.strings.get(text).cloned())
+            .unwrap_or_else(|| String::from(text))
+    }
+    pub fn fetch_font(&self, font: &str) -> String {
+        if font == app::ui::FONT_DIGIT {
+            return String::from(font);
+        }
+        self.active_table()
+            .and_then(|table| table.font.clone())
+            .filter(|locale_font| self.loaded_fonts.contains(locale_font))
+            .unwrap_or_else(|| String::from(font))
+    }
+    pub fn language_name(&self, code: &str) -> String {
+        if code == DEFAULT_LANGUAGE {
+            return String::from("English");
+        }
+        self.tables
+            .iter()
+            .find(|table| table.code == code)
+            .map(|table| table.name.clone())
+            .unwrap_or_else(|| code.to_uppercase())
+    }
+}
+
+// NOTE: keeps the english source so the text can follow a language switch
+#[derive(Component)]
+pub struct LocalizedText(pub String);
+
+#[derive(Resource)]
+struct StringTableHandles(Vec<Handle<StringTable>>);
+
+#[derive(Resource, Default)]
+struct LocaleFontHandles(Vec<(String, Handle<Font>)>);
+
+#[derive(Default)]
+pub struct StringTableLoader;
+
+impl AssetLoader for StringTableLoader {
+    type Asset = StringTable;
+    type Settings = ();
+    type Error = Box<dyn std::error::Error + Send + Sync>;
+    fn load<'a>(
+        &'a self,
+        reader: &'a mut Reader,
+        _settings: &'a (),
+        _load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<Self::Asset, Self::Error>> {
+        Box::pin(async move {
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes).await?;
+            let table: StringTable = serde_json::from_slice(&bytes)?;
+            if !LANGUAGES.contains(&table.code.as_str()) {
+                return Err(format!("Invalid language code {}", table.code).into());
+            }
+            Ok(table)
+        })
+    }
+    fn extensions(&self) -> &[&str] {
+        &["lang.json"]
+    }
+}
+
+pub struct LocalePlugin;
+
+impl Plugin for LocalePlugin {
+    fn build(&self, app: &mut App) {
+        let language = app
+            .world
+            .resource::<Persistent<app::settings::Settings>>()
+            .fetch_language()
+            .to_string();
+        app.insert_resource(Locale::new(selectable_language(&language)))
+            .init_asset::<StringTable>()
+            .init_asset_loader::<StringTableLoader>()
+            .init_resource::<LocaleFontHandles>()
+            .add_systems(Startup, load_tables)
+            .add_systems(PreUpdate, (register_tables, register_fonts, apply_language))
+            .add_systems(Update, refresh_localized_texts);
+    }
+}
+
+fn load_tables(mut commands: Commands, asset_server: Res<AssetServer>) {
+    let handles = LANGUAGES
+        .iter()
+        .filter(|code| **code != DEFAULT_LANGUAGE)
+        .map(|code| asset_server.load(format!("locales/{}.lang.json", code)))
+        .collect();
+    commands.insert_resource(StringTableHandles(handles));
+}
+
+fn register_tables(
+    mut events: EventReader<AssetEvent<StringTable>>,
+    tables: Res<Assets<StringTable>>,
+    table_handles: Res<StringTableHandles>,
+    mut font_handles: ResMut<LocaleFontHandles>,
+    mut locale: ResMut<Locale>,
+    asset_server: Res<AssetServer>,
+) {
+    for event in events.read() {
+        match event {
+            AssetEvent::LoadedWithDependencies { id } | AssetEvent::Modified { id } => {
+                if !table_handles.0.iter().any(|handle| handle.id() == *id) {
+                    continue;
+                }
+                let Some(table) = tables.get(*id) else {
+                    continue;
+                };
+                if let Some(font) = &table.font {
+                    font_handles
+                        .0
+                        .push((font.clone(), asset_server.load(font.clone())));
+                }
+                locale.tables.retain(|loaded| loaded.code != table.code);
+                locale.tables.push(table.clone());
+            }
+            _ => (),
+        }
+    }
+}
+
+// NOTE: a locale font is only used once it has actually loaded,
+// otherwise text keeps falling back to the latin fonts
+fn register_fonts(
+    mut events: EventReader<AssetEvent<Font>>,
+    font_handles: Res<LocaleFontHandles>,
+    mut locale: ResMut<Locale>,
+) {
+    for event in events.read() {
+        if let AssetEvent::LoadedWithDependencies { id } = event {
+            for (path, handle) in font_handles.0.iter() {
+                if handle.id() == *id {
+                    locale.loaded_fonts.push(path.clone());
+                }
+            }
+        }
+    }
+}
+
+fn apply_language(settings: Res<Persistent<app::settings::Settings>>, mut locale: ResMut<Locale>) {
+    let language = selectable_language(settings.fetch_language());
+    if settings.is_changed() && locale.language != language {
+        locale.language = String::from(language);
+    }
+}
+
+fn refresh_localized_texts(
+    mut text_query: Query<(&LocalizedText, &mut Text)>,
+    locale: Res<Locale>,
+    asset_server: Res<AssetServer>,
+) {
+    if !locale.is_changed() {
+        return;
+    }
+    let font = asset_server.load(locale.fetch_font(app::ui::FONT));
+    for (localized_text, mut text) in text_query.iter_mut() {
+        text.sections[0].value = locale.tr(&localized_text.0);
+        text.sections[0].style.font = font.clone();
+    }
+}
+
+pub fn next_language(code: &str) -> &'static str {
+    let index = SELECTABLE_LANGUAGES
+        .iter()
+        .position(|language| *language == code)
+        .map_or(0, |index| (index + 1) % SELECTABLE_LANGUAGES.len());
+    SELECTABLE_LANGUAGES[index]
+}
+
+fn selectable_language(code: &str) -> &str {
+    if SELECTABLE_LANGUAGES.contains(&code) {
+        code
+    } else {
+        DEFAULT_LANGUAGE
+    }
+}
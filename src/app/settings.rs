@@ -1,4 +1,9 @@
+use crate::{
+    app::{gamepad, key_binding, locale, theme},
+    reactor::tail,
+};
 use bevy::prelude::*;
+use bevy::utils::HashMap;
 use bevy_persistent::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::path::Path;
@@ -8,12 +13,53 @@ pub struct Settings {
     first_run: bool,
     bgm_enabled: bool,
     se_enabled: bool,
+    #[serde(default = "default_enabled")]
+    ui_se_enabled: bool,
     fullscreen_enabled: bool,
+    #[serde(default)]
+    follow_cursor_enabled: bool,
+    #[serde(default)]
+    cursor_grab_enabled: bool,
+    #[serde(default = "default_enabled")]
+    auto_pause_enabled: bool,
+    #[serde(default)]
+    reduced_motion_enabled: bool,
+    #[serde(default = "default_enabled")]
+    mute_unfocused_enabled: bool,
+    #[serde(default = "default_enabled")]
+    camera_shake_enabled: bool,
+    #[serde(default = "default_enabled")]
+    hit_stop_enabled: bool,
+    #[serde(default = "default_enabled")]
+    slow_motion_enabled: bool,
+    #[serde(default)]
+    danger_indicator_enabled: bool,
+    #[serde(default = "default_master_volume")]
+    master_volume: u8,
     bgm_volume: u8,
     se_volume: u8,
+    #[serde(default = "default_volume")]
+    ui_se_volume: u8,
     sensitivity: u8,
     sensitivity_modified: u8,
+    #[serde(default = "default_ui_scale")]
+    ui_scale: u8,
+    #[serde(default)]
+    theme: theme::ThemePreset,
+    #[serde(default)]
+    trail_quality: tail::TrailQuality,
+    #[serde(default = "default_language")]
+    language: String,
     last_player: String,
+    #[serde(default)]
+    input_map: key_binding::InputMap,
+    #[serde(default)]
+    active_gamepad: String,
+    // NOTE: tunings are keyed by the device name, so they are per model:
+    // two identical pads share one tuning. Gamepad ids are reassigned on
+    // every reconnect, so they can't tell the pads apart between sessions.
+    #[serde(default)]
+    gamepad_tunings: HashMap<String, gamepad::GamepadTuning>,
 }
 
 impl Settings {
@@ -22,7 +68,17 @@ impl Settings {
             "first" => self.first_run,
             "bgm" => self.bgm_enabled,
             "se" => self.se_enabled,
+            "ui_se" => self.ui_se_enabled,
             "fullscreen" => self.fullscreen_enabled,
+            "follow_cursor" => self.follow_cursor_enabled,
+            "cursor_grab" => self.cursor_grab_enabled,
+            "auto_pause" => self.auto_pause_enabled,
+            "reduced_motion" => self.reduced_motion_enabled,
+            "mute_unfocused" => self.mute_unfocused_enabled,
+            "camera_shake" => self.camera_shake_enabled,
+            "hit_stop" => self.hit_stop_enabled,
+            "slow_motion" => self.slow_motion_enabled,
+            "danger_indicator" => self.danger_indicator_enabled,
             _ => false,
         }
     }
@@ -31,46 +87,83 @@ impl Settings {
             "first" => self.first_run = !self.first_run,
             "bgm" => self.bgm_enabled = !self.bgm_enabled,
             "se" => self.se_enabled = !self.se_enabled,
+            "ui_se" => self.ui_se_enabled = !self.ui_se_enabled,
             "fullscreen" => self.fullscreen_enabled = !self.fullscreen_enabled,
+            "follow_cursor" => self.follow_cursor_enabled = !self.follow_cursor_enabled,
+            "cursor_grab" => self.cursor_grab_enabled = !self.cursor_grab_enabled,
+            "auto_pause" => self.auto_pause_enabled = !self.auto_pause_enabled,
+            "reduced_motion" => self.reduced_motion_enabled = !self.reduced_motion_enabled,
+            "mute_unfocused" => self.mute_unfocused_enabled = !self.mute_unfocused_enabled,
+            "camera_shake" => self.camera_shake_enabled = !self.camera_shake_enabled,
+            "hit_stop" => self.hit_stop_enabled = !self.hit_stop_enabled,
+            "slow_motion" => self.slow_motion_enabled = !self.slow_motion_enabled,
+            "danger_indicator" => self.danger_indicator_enabled = !self.danger_indicator_enabled,
             _ => println!("Invalid field"),
         }
     }
     pub fn set_value(&mut self, field: &str, value: i8) {
         if Settings::is_value_vaild(value) {
             match field {
+                "master" => self.master_volume = value as u8,
                 "bgm" => self.bgm_volume = value as u8,
                 "se" => self.se_volume = value as u8,
+                "ui_se" => self.ui_se_volume = value as u8,
                 "sensitivity" => self.sensitivity = value as u8,
                 "sensitivity_modified" => self.sensitivity_modified = value as u8,
+                "ui_scale" => self.ui_scale = value as u8,
+                "gamepad_inner" => self.update_gamepad_tuning(|tuning| {
+                    tuning.inner_dead_zone = value as u8;
+                }),
+                "gamepad_outer" => self.update_gamepad_tuning(|tuning| {
+                    tuning.outer_dead_zone = value as u8;
+                }),
                 _ => println!("Invalid field"),
             }
         }
     }
     pub fn get_value(&self, field: &str) -> u8 {
         match field {
+            "master" => self.master_volume,
             "bgm" => self.bgm_volume,
             "se" => self.se_volume,
+            "ui_se" => self.ui_se_volume,
             "sensitivity" => self.sensitivity,
             "sensitivity_modified" => self.sensitivity_modified,
+            "ui_scale" => self.ui_scale,
+            "gamepad_inner" => self.fetch_gamepad_tuning().inner_dead_zone,
+            "gamepad_outer" => self.fetch_gamepad_tuning().outer_dead_zone,
             _ => 0,
         }
     }
+    pub fn cycle(&mut self, field: &str) {
+        match field {
+            "gamepad_curve" => self.update_gamepad_tuning(|tuning| {
+                tuning.curve = tuning.curve.next();
+            }),
+            "gamepad_stick" => self.update_gamepad_tuning(|tuning| {
+                tuning.stick = tuning.stick.next();
+            }),
+            "theme" => self.theme = self.theme.next(),
+            "trail_quality" => self.trail_quality = self.trail_quality.next(),
+            "language" => self.language = String::from(locale::next_language(&self.language)),
+            _ => println!("Invalid field"),
+        }
+    }
+    pub fn get_text(&self, field: &str, locale: &locale::Locale) -> String {
+        match field {
+            "gamepad_curve" => String::from(self.fetch_gamepad_tuning().curve.name()),
+            "gamepad_stick" => String::from(self.fetch_gamepad_tuning().stick.name()),
+            "theme" => String::from(self.theme.name()),
+            "trail_quality" => String::from(self.trail_quality.name()),
+            "language" => locale.language_name(&self.language),
+            _ => String::from(""),
+        }
+    }
     pub fn current_value(&self, field: &str) -> u8 {
         match field {
-            "bgm" => {
-                if self.bgm_enabled {
-                    self.bgm_volume
-                } else {
-                    0
-                }
-            }
-            "se" => {
-                if self.se_enabled {
-                    self.se_volume
-                } else {
-                    0
-                }
-            }
+            "bgm" if self.bgm_enabled => self.bgm_volume,
+            "se" if self.se_enabled => self.se_volume,
+            "ui_se" if self.ui_se_enabled => self.ui_se_volume,
             "sensitivity" => self.sensitivity,
             "sensitivity_modified" => self.sensitivity_modified,
             _ => 0,
@@ -82,11 +175,70 @@ impl Settings {
     pub fn update_last_player(&mut self, name: &str) {
         self.last_player = String::from(name);
     }
+    pub fn fetch_theme(&self) -> theme::ThemePreset {
+        self.theme
+    }
+    pub fn fetch_trail_quality(&self) -> tail::TrailQuality {
+        self.trail_quality
+    }
+    pub fn fetch_language(&self) -> &str {
+        &self.language
+    }
+    pub fn fetch_input_map(&self) -> &key_binding::InputMap {
+        &self.input_map
+    }
+    pub fn bind_key(&mut self, action: key_binding::GameAction, key: KeyCode) {
+        self.input_map.bind_key(action, key);
+    }
+    pub fn bind_button(&mut self, action: key_binding::GameAction, button: GamepadButtonType) {
+        self.input_map.bind_button(action, button);
+    }
+    pub fn reset_input_map(&mut self) {
+        self.input_map = key_binding::InputMap::default();
+    }
+    pub fn fetch_active_gamepad(&self) -> &str {
+        &self.active_gamepad
+    }
+    pub fn select_gamepad(&mut self, name: &str) {
+        self.active_gamepad = String::from(name);
+    }
+    pub fn fetch_gamepad_tuning(&self) -> gamepad::GamepadTuning {
+        self.gamepad_tunings
+            .get(&self.active_gamepad)
+            .copied()
+            .unwrap_or_default()
+    }
+    fn update_gamepad_tuning(&mut self, f: impl FnOnce(&mut gamepad::GamepadTuning)) {
+        let mut tuning = self.fetch_gamepad_tuning();
+        f(&mut tuning);
+        self.gamepad_tunings
+            .insert(self.active_gamepad.clone(), tuning);
+    }
     fn is_value_vaild(value: i8) -> bool {
         (0..=100).contains(&value)
     }
 }
 
+fn default_enabled() -> bool {
+    true
+}
+
+fn default_volume() -> u8 {
+    50
+}
+
+fn default_master_volume() -> u8 {
+    100
+}
+
+fn default_ui_scale() -> u8 {
+    50
+}
+
+fn default_language() -> String {
+    String::from(locale::DEFAULT_LANGUAGE)
+}
+
 pub struct SettingsPlugin;
 
 impl Plugin for SettingsPlugin {
@@ -106,10 +258,29 @@ impl Plugin for SettingsPlugin {
                     bgm_volume: 50,
                     se_enabled: true,
                     se_volume: 50,
+                    ui_se_enabled: true,
+                    ui_se_volume: default_volume(),
+                    master_volume: default_master_volume(),
+                    mute_unfocused_enabled: true,
                     fullscreen_enabled: false,
+                    follow_cursor_enabled: false,
+                    cursor_grab_enabled: false,
+                    auto_pause_enabled: true,
+                    reduced_motion_enabled: false,
+                    camera_shake_enabled: true,
+                    hit_stop_enabled: true,
+                    slow_motion_enabled: true,
+                    danger_indicator_enabled: false,
                     sensitivity: 50,
                     sensitivity_modified: 10,
+                    ui_scale: default_ui_scale(),
+                    theme: theme::ThemePreset::default(),
+                    trail_quality: tail::TrailQuality::default(),
+                    language: default_language(),
                     last_player: String::from(""),
+                    input_map: key_binding::InputMap::default(),
+                    active_gamepad: String::from(""),
+                    gamepad_tunings: HashMap::new(),
                 })
                 .revertible(true)
                 .revert_to_default_on_deserialization_errors(true)
@@ -0,0 +1,224 @@
+use crate::app;
+use bevy::prelude::*;
+use bevy_persistent::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, PartialEq, Default, Serialize, Deserialize, Debug)]
+pub enum ThemePreset {
+    #[default]
+    Default,
+    Deuteranopia,
+    Protanopia,
+    Tritanopia,
+    HighContrast,
+}
+
+impl ThemePreset {
+    pub fn name(&self) -> &str {
+        match self {
+            ThemePreset::Default => "Default",
+            ThemePreset::Deuteranopia => "Deuteranopia",
+            ThemePreset::Protanopia => "Protanopia",
+            ThemePreset::Tritanopia => "Tritanopia",
+            ThemePreset::HighContrast => "High Contrast",
+        }
+    }
+    pub fn next(&self) -> Self {
+        match self {
+            ThemePreset::Default => ThemePreset::Deuteranopia,
+            ThemePreset::Deuteranopia => ThemePreset::Protanopia,
+            ThemePreset::Protanopia => ThemePreset::Tritanopia,
+            ThemePreset::Tritanopia => ThemePreset::HighContrast,
+            ThemePreset::HighContrast => ThemePreset::Default,
+        }
+    }
+}
+
+#[derive(Resource, Clone, Copy, PartialEq, Debug)]
+pub struct Theme {
+    pub preset: ThemePreset,
+    pub bg: Color,
+    pub fg: Color,
+    pub secondary: Color,
+    pub mute: Color,
+    pub ghost: Color,
+    pub cover: Color,
+    pub btn_bg: Color,
+    pub btn_hovered_bg: Color,
+    pub btn_pressed_bg: Color,
+    pub u: Color,
+    pub alpha: Color,
+    pub control: Color,
+    pub hyper: Color,
+    pub trigger: Color,
+    pub well: Color,
+    pub hunter: Color,
+}
+
+const DEFAULT_THEME: Theme = Theme {
+    preset: ThemePreset::Default,
+    bg: Color::BLACK,
+    fg: Color::rgb(0.9, 0.9, 0.9),
+    secondary: Color::rgb(0.5, 0.5, 0.5),
+    mute: Color::rgb(0.2, 0.2, 0.2),
+    ghost: Color::rgba(0.9, 0.9, 0.9, 0.5),
+    cover: Color::rgba(0.0, 0.0, 0.0, 0.9),
+    btn_bg: Color::rgb(0.15, 0.15, 0.15),
+    btn_hovered_bg: Color::rgb(0.25, 0.25, 0.25),
+    btn_pressed_bg: Color::rgb(0.45, 0.45, 0.45),
+    u: Color::rgb(1.0, 0.84, 0.2),
+    alpha: Color::SILVER,
+    control: Color::LIME_GREEN,
+    hyper: Color::rgb(0.1, 0.56, 1.0),
+    trigger: Color::rgb(1.0, 0.39, 0.29),
+    well: Color::rgb(0.62, 0.35, 0.95),
+    hunter: Color::rgb(0.95, 0.2, 0.55),
+};
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum ThemeRole {
+    Fg,
+    Bg,
+    Secondary,
+    Mute,
+    Alpha,
+    Control,
+    Hyper,
+    Trigger,
+    Well,
+    Hunter,
+    U,
+}
+
+pub const THEME_ROLES: [ThemeRole; 11] = [
+    ThemeRole::Fg,
+    ThemeRole::Bg,
+    ThemeRole::Secondary,
+    ThemeRole::Mute,
+    ThemeRole::Alpha,
+    ThemeRole::Control,
+    ThemeRole::Hyper,
+    ThemeRole::Trigger,
+    ThemeRole::Well,
+    ThemeRole::Hunter,
+    ThemeRole::U,
+];
+
+#[derive(Component)]
+pub struct ThemeSwatch(pub ThemeRole);
+
+// NOTE: the colour vision presets pick their particle colours from the Okabe-Ito
+// set, which stays distinguishable under the common forms of colour vision
+// deficiency. The default theme keeps its own hues.
+impl Theme {
+    pub const fn from_preset(preset: ThemePreset) -> Self {
+        match preset {
+            ThemePreset::Default => DEFAULT_THEME,
+            ThemePreset::Deuteranopia => Theme {
+                preset,
+                u: Color::rgb(0.94, 0.89, 0.26),
+                control: Color::rgb(0.34, 0.71, 0.91),
+                hyper: Color::rgb(0.0, 0.45, 0.7),
+                trigger: Color::rgb(0.84, 0.37, 0.0),
+                well: Color::rgb(0.8, 0.47, 0.65),
+                hunter: Color::rgb(0.9, 0.62, 0.0),
+                ..DEFAULT_THEME
+            },
+            ThemePreset::Protanopia => Theme {
+                preset,
+                u: Color::rgb(0.94, 0.89, 0.26),
+                control: Color::rgb(0.34, 0.71, 0.91),
+                hyper: Color::rgb(0.8, 0.47, 0.65),
+                trigger: Color::rgb(0.9, 0.62, 0.0),
+                well: Color::rgb(0.0, 0.62, 0.45),
+                hunter: Color::rgb(0.84, 0.37, 0.0),
+                ..DEFAULT_THEME
+            },
+            ThemePreset::Tritanopia => Theme {
+                preset,
+                u: Color::rgb(0.8, 0.47, 0.65),
+                control: Color::rgb(0.0, 0.62, 0.45),
+                hyper: Color::rgb(0.94, 0.89, 0.26),
+                trigger: Color::rgb(0.84, 0.37, 0.0),
+                well: Color::rgb(0.0, 0.45, 0.7),
+                hunter: Color::rgb(0.9, 0.62, 0.0),
+                ..DEFAULT_THEME
+            },
+            ThemePreset::HighContrast => Theme {
+                preset,
+                bg: Color::BLACK,
+                fg: Color::WHITE,
+                secondary: Color::rgb(0.8, 0.8, 0.8),
+                mute: Color::rgb(0.5, 0.5, 0.5),
+                ghost: Color::rgba(1.0, 1.0, 1.0, 0.7),
+                cover: Color::rgba(0.0, 0.0, 0.0, 0.95),
+                btn_bg: Color::rgb(0.25, 0.25, 0.25),
+                btn_hovered_bg: Color::rgb(0.45, 0.45, 0.45),
+                btn_pressed_bg: Color::rgb(0.7, 0.7, 0.7),
+                u: Color::rgb(1.0, 1.0, 0.0),
+                alpha: Color::WHITE,
+                control: Color::rgb(0.0, 1.0, 0.0),
+                hyper: Color::rgb(0.0, 0.6, 1.0),
+                trigger: Color::rgb(1.0, 0.2, 0.0),
+                well: Color::rgb(1.0, 0.0, 1.0),
+                hunter: Color::rgb(0.0, 1.0, 1.0),
+            },
+        }
+    }
+    pub fn color(&self, role: ThemeRole) -> Color {
+        match role {
+            ThemeRole::Fg => self.fg,
+            ThemeRole::Bg => self.bg,
+            ThemeRole::Secondary => self.secondary,
+            ThemeRole::Mute => self.mute,
+            ThemeRole::Alpha => self.alpha,
+            ThemeRole::Control => self.control,
+            ThemeRole::Hyper => self.hyper,
+            ThemeRole::Trigger => self.trigger,
+            ThemeRole::Well => self.well,
+            ThemeRole::Hunter => self.hunter,
+            ThemeRole::U => self.u,
+        }
+    }
+}
+
+pub struct ThemePlugin;
+
+impl Plugin for ThemePlugin {
+    fn build(&self, app: &mut App) {
+        let preset = app
+            .world
+            .resource::<Persistent<app::settings::Settings>>()
+            .fetch_theme();
+        let theme = Theme::from_preset(preset);
+        app.insert_resource(theme)
+            .insert_resource(ClearColor(theme.bg))
+            .add_systems(PreUpdate, apply_theme)
+            .add_systems(Update, refresh_theme_swatches);
+    }
+}
+
+fn apply_theme(
+    settings: Res<Persistent<app::settings::Settings>>,
+    mut theme: ResMut<Theme>,
+    mut clear_color: ResMut<ClearColor>,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+    if theme.set_if_neq(Theme::from_preset(settings.fetch_theme())) {
+        clear_color.0 = theme.bg;
+    }
+}
+
+fn refresh_theme_swatches(
+    theme: Res<Theme>,
+    mut swatch_query: Query<(&ThemeSwatch, &mut BackgroundColor)>,
+) {
+    if !theme.is_changed() {
+        return;
+    }
+    for (swatch, mut background_color) in swatch_query.iter_mut() {
+        background_color.0 = theme.color(swatch.0);
+    }
+}
@@ -0,0 +1,195 @@
+use bevy::input::keyboard::KeyboardInput;
+use bevy::input::mouse::{MouseButtonInput, MouseMotion, MouseWheel};
+use bevy::input::touch::{TouchInput, TouchPhase};
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+
+const SLOW_TOUCH_COUNT: usize = 2;
+const TOUCH_BTN_RATIO: f32 = 2.0;
+
+// NOTE: keeps the padding a button was built with, so it can be scaled back and forth
+#[derive(Component)]
+pub struct TouchSized(pub UiRect);
+
+#[derive(Resource, Default)]
+pub struct TouchControl {
+    positions: HashMap<u64, Vec2>,
+    primary_id: Option<u64>,
+    drag_delta: Vec2,
+    is_touched: bool,
+}
+
+impl TouchControl {
+    pub fn drag_delta(&self) -> Vec2 {
+        self.drag_delta
+    }
+    pub fn is_slow(&self) -> bool {
+        self.positions.len() >= SLOW_TOUCH_COUNT
+    }
+    pub fn is_touched(&self) -> bool {
+        self.is_touched
+    }
+    fn handle_event(&mut self, event: &TouchInput) {
+        match event.phase {
+            TouchPhase::Started => {
+                self.positions.insert(event.id, event.position);
+                if self.primary_id.is_none() {
+                    self.primary_id = Some(event.id);
+                }
+                self.is_touched = true;
+            }
+            TouchPhase::Moved => {
+                if let Some(last_pos) = self.positions.get_mut(&event.id) {
+                    if self.primary_id == Some(event.id) {
+                        self.drag_delta += event.position - *last_pos;
+                    }
+                    *last_pos = event.position;
+                }
+            }
+            TouchPhase::Ended | TouchPhase::Canceled => {
+                self.positions.remove(&event.id);
+                if self.primary_id == Some(event.id) {
+                    self.primary_id = self.positions.keys().next().copied();
+                }
+            }
+        }
+    }
+}
+
+pub struct TouchPlugin;
+
+impl Plugin for TouchPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TouchControl>()
+            .add_systems(PreUpdate, track_touches)
+            .add_systems(PostUpdate, resize_touch_sized);
+    }
+}
+
+// NOTE: any mouse or keyboard input switches the UI back from the touch sizing
+fn track_touches(
+    mut events: EventReader<TouchInput>,
+    mut keyboard_events: EventReader<KeyboardInput>,
+    mut mouse_button_events: EventReader<MouseButtonInput>,
+    mut mouse_motion_events: EventReader<MouseMotion>,
+    mut mouse_wheel_events: EventReader<MouseWheel>,
+    mut touch_control: ResMut<TouchControl>,
+) {
+    touch_control.drag_delta = Vec2::ZERO;
+    let other_input_counts = [
+        keyboard_events.read().count(),
+        mouse_button_events.read().count(),
+        mouse_motion_events.read().count(),
+        mouse_wheel_events.read().count(),
+    ];
+    if other_input_counts.iter().any(|count| *count > 0) {
+        touch_control.is_touched = false;
+    }
+    for event in events.read() {
+        touch_control.handle_event(event);
+    }
+}
+
+fn scale_val(val: Val, ratio: f32) -> Val {
+    match val {
+        Val::Px(px) => Val::Px(px * ratio),
+        _ => val,
+    }
+}
+
+fn resize_touch_sized(
+    touch_control: Res<TouchControl>,
+    mut was_touched: Local<bool>,
+    mut sized_query: Query<(Ref<TouchSized>, &mut Style)>,
+) {
+    let is_switched = *was_touched != touch_control.is_touched();
+    *was_touched = touch_control.is_touched();
+    let ratio = if touch_control.is_touched() {
+        TOUCH_BTN_RATIO
+    } else {
+        1.0
+    };
+    for (sized, mut style) in sized_query.iter_mut() {
+        if !is_switched && !sized.is_added() {
+            continue;
+        }
+        style.padding = UiRect {
+            left: scale_val(sized.0.left, ratio),
+            right: scale_val(sized.0.right, ratio),
+            top: scale_val(sized.0.top, ratio),
+            bottom: scale_val(sized.0.bottom, ratio),
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::input::InputPlugin;
+
+    fn send_touch(app: &mut App, id: u64, phase: TouchPhase, position: Vec2) {
+        app.world.send_event(TouchInput {
+            phase,
+            position,
+            force: None,
+            id,
+        });
+    }
+
+    #[test]
+    fn tracks_primary_drag_and_slow_touch() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, InputPlugin, TouchPlugin));
+
+        send_touch(&mut app, 1, TouchPhase::Started, Vec2::new(10.0, 10.0));
+        app.update();
+        assert!(app.world.resource::<TouchControl>().is_touched());
+        assert!(!app.world.resource::<TouchControl>().is_slow());
+
+        send_touch(&mut app, 1, TouchPhase::Moved, Vec2::new(15.0, 7.0));
+        app.update();
+        assert_eq!(
+            app.world.resource::<TouchControl>().drag_delta(),
+            Vec2::new(5.0, -3.0)
+        );
+
+        send_touch(&mut app, 2, TouchPhase::Started, Vec2::new(50.0, 50.0));
+        send_touch(&mut app, 2, TouchPhase::Moved, Vec2::new(80.0, 80.0));
+        app.update();
+        assert!(app.world.resource::<TouchControl>().is_slow());
+        assert_eq!(
+            app.world.resource::<TouchControl>().drag_delta(),
+            Vec2::ZERO
+        );
+
+        send_touch(&mut app, 1, TouchPhase::Ended, Vec2::new(15.0, 7.0));
+        app.update();
+        assert!(!app.world.resource::<TouchControl>().is_slow());
+
+        send_touch(&mut app, 2, TouchPhase::Moved, Vec2::new(82.0, 84.0));
+        app.update();
+        assert_eq!(
+            app.world.resource::<TouchControl>().drag_delta(),
+            Vec2::new(2.0, 4.0)
+        );
+    }
+
+    #[test]
+    fn keyboard_input_ends_touch_mode() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, InputPlugin, TouchPlugin));
+
+        send_touch(&mut app, 1, TouchPhase::Started, Vec2::new(10.0, 10.0));
+        app.update();
+        assert!(app.world.resource::<TouchControl>().is_touched());
+
+        app.world.send_event(KeyboardInput {
+            scan_code: 0,
+            key_code: Some(KeyCode::Space),
+            state: bevy::input::ButtonState::Pressed,
+            window: Entity::PLACEHOLDER,
+        });
+        app.update();
+        assert!(!app.world.resource::<TouchControl>().is_touched());
+    }
+}
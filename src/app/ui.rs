@@ -11,19 +11,21 @@ pub const BTN_PADDING: f32 = 5.0;
 pub const MENU_ENTRY_PADDING: f32 = 16.0;
 pub const PAGE_PADDING: f32 = 6.0;
 
-pub const BG_COLOR: Color = Color::BLACK;
-pub const FG_COLOR: Color = Color::rgb(0.9, 0.9, 0.9);
-pub const SECONDARY_COLOR: Color = Color::rgb(0.5, 0.5, 0.5);
-pub const MUTE_COLOR: Color = Color::rgb(0.2, 0.2, 0.2);
-pub const COVER_COLOR: Color = Color::rgba(0.0, 0.0, 0.0, 0.9);
-pub const BTN_BG: Color = Color::rgb(0.15, 0.15, 0.15);
-pub const BTN_HOVERED_BG: Color = Color::rgb(0.25, 0.25, 0.25);
-pub const BTN_PRESSED_BG: Color = Color::rgb(0.45, 0.45, 0.45);
-
 pub const FONT: &str = "fonts/SYNNova-Regular.otf";
 pub const FONT_DIGIT: &str = "fonts/telegrama_raw.otf";
 pub const FONT_HW: &str = "fonts/VAG-HandWritten.otf";
 
+// NOTE: keeps nodes aligned with the playfield whatever the user ui scale is
+#[derive(Component)]
+pub struct FitToField {
+    pub width: Option<f32>,
+    pub height: Option<f32>,
+}
+
+pub fn to_ui_scale(value: u8) -> f32 {
+    0.5 + value as f32 / 100.0
+}
+
 pub fn px_p(size: f32) -> Val {
     Val::Px(SPACE_SIZE * size)
 }
@@ -34,6 +36,8 @@ pub fn build_icon_btn(
     bundle: impl Bundle,
     style: Style,
     icon: &str,
+    theme: &app::theme::Theme,
+    locale: &app::locale::Locale,
 ) -> Entity {
     let icon_style = Style {
         width: Val::Auto,
@@ -41,7 +45,16 @@ pub fn build_icon_btn(
         padding: UiRect::all(px_p(BTN_PADDING * 0.6)),
         ..style
     };
-    build_btn(parent, asset_server, bundle, icon_style, None, Some(icon))
+    build_btn(
+        parent,
+        asset_server,
+        bundle,
+        icon_style,
+        None,
+        Some(icon),
+        theme,
+        locale,
+    )
 }
 
 pub fn despawn_ui<T: Component>(to_despawn: Query<Entity, With<T>>, mut commands: Commands) {
@@ -50,6 +63,7 @@ pub fn despawn_ui<T: Component>(to_despawn: Query<Entity, With<T>>, mut commands
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn build_btn(
     parent: &mut ChildBuilder,
     asset_server: &Res<AssetServer>,
@@ -57,7 +71,10 @@ pub fn build_btn(
     style: Style,
     text: Option<&str>,
     icon: Option<&str>,
+    theme: &app::theme::Theme,
+    locale: &app::locale::Locale,
 ) -> Entity {
+    let padding = style.padding;
     parent
         .spawn((
             ButtonBundle {
@@ -67,9 +84,10 @@ pub fn build_btn(
                     align_items: AlignItems::Center,
                     ..style
                 },
-                background_color: BTN_BG.into(),
+                background_color: theme.btn_bg.into(),
                 ..default()
             },
+            app::touch::TouchSized(padding),
             bundle,
         ))
         .with_children(|parent| {
@@ -93,13 +111,16 @@ pub fn build_btn(
                 });
             }
             if let Some(text) = text {
-                parent.spawn(TextBundle::from_section(
-                    text,
-                    TextStyle {
-                        font: asset_server.load(FONT),
-                        font_size: BTN_FS,
-                        color: FG_COLOR,
-                    },
+                parent.spawn((
+                    TextBundle::from_section(
+                        locale.tr(text),
+                        TextStyle {
+                            font: asset_server.load(locale.fetch_font(FONT)),
+                            font_size: BTN_FS,
+                            color: theme.fg,
+                        },
+                    ),
+                    app::locale::LocalizedText(String::from(text)),
                 ));
             }
         })
@@ -115,6 +136,8 @@ pub fn build_menu_entry(
     bundle: impl Bundle,
     text: &str,
     icon: &str,
+    theme: &app::theme::Theme,
+    locale: &app::locale::Locale,
 ) -> Entity {
     parent
         .spawn((
@@ -126,9 +149,10 @@ pub fn build_menu_entry(
                     padding: UiRect::all(px_p(4.0)),
                     ..default()
                 },
-                background_color: BG_COLOR.into(),
+                background_color: theme.bg.into(),
                 ..default()
             },
+            app::touch::TouchSized(UiRect::all(px_p(4.0))),
             bundle,
         ))
         .with_children(|parent| {
@@ -144,24 +168,26 @@ pub fn build_menu_entry(
                 image: UiImage::new(icon),
                 ..default()
             });
-            parent.spawn(
+            parent.spawn((
                 TextBundle::from_section(
-                    text,
+                    locale.tr(text),
                     TextStyle {
-                        font: asset_server.load(FONT),
+                        font: asset_server.load(locale.fetch_font(FONT)),
                         font_size: FONT_SIZE * MENU_ENTRY_RATIO,
-                        color: FG_COLOR,
+                        color: theme.fg,
                     },
                 )
                 .with_style(Style {
                     margin: UiRect::right(px_p(2.0)),
                     ..default()
                 }),
-            );
+                app::locale::LocalizedText(String::from(text)),
+            ));
         })
         .id()
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn build_link(
     parent: &mut ChildBuilder,
     asset_server: &Res<AssetServer>,
@@ -170,6 +196,8 @@ pub fn build_link(
     icon: Option<&str>,
     font: &str,
     enable_interaction: bool,
+    theme: &app::theme::Theme,
+    locale: &app::locale::Locale,
 ) -> Entity {
     let mut entity = parent.spawn((
         NodeBundle {
@@ -180,8 +208,8 @@ pub fn build_link(
                 border: UiRect::bottom(px_p(1.0)),
                 ..default()
             },
-            background_color: BG_COLOR.into(),
-            border_color: BG_COLOR.into(),
+            background_color: theme.bg.into(),
+            border_color: theme.bg.into(),
             ..default()
         },
         bundle,
@@ -212,9 +240,9 @@ pub fn build_link(
             TextBundle::from_section(
                 text,
                 TextStyle {
-                    font: asset_server.load(font),
+                    font: asset_server.load(locale.fetch_font(font)),
                     font_size: FONT_SIZE,
-                    color: FG_COLOR,
+                    color: theme.fg,
                 },
             )
             .with_style(Style {
@@ -229,6 +257,46 @@ pub fn build_link(
     entity.id()
 }
 
+pub fn build_theme_swatches(parent: &mut ChildBuilder, theme: &app::theme::Theme) {
+    parent
+        .spawn(NodeBundle {
+            style: Style {
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|parent| {
+            for role in app::theme::THEME_ROLES {
+                parent
+                    .spawn(NodeBundle {
+                        style: Style {
+                            margin: UiRect::all(px_p(2.0)),
+                            padding: UiRect::all(px_p(2.0)),
+                            border: UiRect::all(px_p(0.5)),
+                            ..default()
+                        },
+                        border_color: theme.fg.into(),
+                        ..default()
+                    })
+                    .with_children(|parent| {
+                        parent.spawn((
+                            NodeBundle {
+                                style: Style {
+                                    width: Val::Px(ICON_SIZE * 1.5),
+                                    height: Val::Px(ICON_SIZE * 1.5),
+                                    ..default()
+                                },
+                                background_color: theme.color(role).into(),
+                                ..default()
+                            },
+                            app::theme::ThemeSwatch(role),
+                        ));
+                    });
+            }
+        });
+}
+
 #[derive(Component)]
 pub struct SwitchButton;
 
@@ -0,0 +1,265 @@
+use crate::{
+    app::{achievement, locale},
+    reactor::particle::uou,
+};
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+use bevy_persistent::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+pub const UNLOCK_SLOTS: [&str; 3] = ["u_color", "u_shape", "bgm"];
+
+pub static UNLOCKS: [UnlockDef; 9] = [
+    UnlockDef {
+        code: "u_color_theme",
+        name: "Theme Default",
+        value: UnlockValue::UouColor(None),
+        requirement: None,
+    },
+    UnlockDef {
+        code: "u_color_azure",
+        name: "Azure",
+        value: UnlockValue::UouColor(Some(Color::rgb(0.22, 0.6, 0.97))),
+        requirement: Some(("max_h", 0)),
+    },
+    UnlockDef {
+        code: "u_color_jade",
+        name: "Jade",
+        value: UnlockValue::UouColor(Some(Color::rgb(0.3, 0.8, 0.5))),
+        requirement: Some(("max_c_xxx", 0)),
+    },
+    UnlockDef {
+        code: "u_color_crimson",
+        name: "Crimson",
+        value: UnlockValue::UouColor(Some(Color::rgb(0.9, 0.2, 0.25))),
+        requirement: Some(("not_moving_xxx_s", 0)),
+    },
+    UnlockDef {
+        code: "u_shape_circle",
+        name: "Circle",
+        value: UnlockValue::UouShape(uou::UouShape::Circle),
+        requirement: None,
+    },
+    UnlockDef {
+        code: "u_shape_square",
+        name: "Square",
+        value: UnlockValue::UouShape(uou::UouShape::Square),
+        requirement: Some(("score_xxx", 0)),
+    },
+    UnlockDef {
+        code: "u_shape_diamond",
+        name: "Diamond",
+        value: UnlockValue::UouShape(uou::UouShape::Diamond),
+        requirement: Some(("alpha_xxx", 1)),
+    },
+    UnlockDef {
+        code: "bgm_main",
+        name: "Main Theme",
+        value: UnlockValue::Bgm("audio/bgm/main.ogg"),
+        requirement: None,
+    },
+    UnlockDef {
+        code: "bgm_rush",
+        name: "Rush",
+        value: UnlockValue::Bgm("audio/bgm/rush.wav"),
+        requirement: Some(("time_xxx_s", 0)),
+    },
+];
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum UnlockValue {
+    // NOTE: None follows the U colour of the current theme
+    UouColor(Option<Color>),
+    UouShape(uou::UouShape),
+    Bgm(&'static str),
+}
+
+#[derive(Debug)]
+pub struct UnlockDef {
+    pub code: &'static str,
+    pub name: &'static str,
+    pub value: UnlockValue,
+    requirement: Option<(&'static str, usize)>,
+}
+
+impl UnlockDef {
+    pub fn slot(&self) -> &str {
+        match self.value {
+            UnlockValue::UouColor(_) => "u_color",
+            UnlockValue::UouShape(_) => "u_shape",
+            UnlockValue::Bgm(_) => "bgm",
+        }
+    }
+    pub fn requirement_text(
+        &self,
+        ach_defs: &achievement::AchievementDefs,
+        locale: &locale::Locale,
+    ) -> String {
+        match self.requirement {
+            Some((ach_code, tier)) => match ach_defs.find(ach_code) {
+                Some(ach_def) => ach_def.description(locale, tier),
+                None => String::from("???"),
+            },
+            None => String::from(""),
+        }
+    }
+    fn is_reached(
+        &self,
+        ach_store: &achievement::AchievementStore,
+        ach_defs: &achievement::AchievementDefs,
+    ) -> bool {
+        match self.requirement {
+            Some((ach_code, tier)) => match ach_defs.find(ach_code) {
+                Some(ach_def) => ach_store.done_tier_count(ach_def) > tier,
+                None => false,
+            },
+            None => true,
+        }
+    }
+}
+
+#[derive(Resource, Serialize, Deserialize)]
+pub struct UnlockStore {
+    unlocked_codes: Vec<String>,
+    pending_codes: Vec<String>,
+    selected_map: HashMap<String, String>,
+}
+
+impl UnlockStore {
+    pub fn is_unlocked(&self, code: &str) -> bool {
+        let unlock_def = fetch_unlock_def(code);
+        unlock_def.requirement.is_none() || self.unlocked_codes.contains(&String::from(code))
+    }
+    pub fn is_selected(&self, code: &str) -> bool {
+        let unlock_def = fetch_unlock_def(code);
+        self.fetch_selected(unlock_def.slot()).code == code
+    }
+    pub fn select(&mut self, code: &str) -> bool {
+        if !self.is_unlocked(code) {
+            return false;
+        }
+        let unlock_def = fetch_unlock_def(code);
+        self.selected_map
+            .insert(String::from(unlock_def.slot()), String::from(code));
+        true
+    }
+    pub fn fetch_selected(&self, slot: &str) -> &'static UnlockDef {
+        if let Some(code) = self.selected_map.get(slot) {
+            if let Some(unlock_def) = find_unlock_def(code) {
+                if self.is_unlocked(code) {
+                    return unlock_def;
+                }
+            }
+        }
+        UNLOCKS
+            .iter()
+            .find(|unlock_def| unlock_def.slot() == slot && unlock_def.requirement.is_none())
+            .expect("Invalid unlock slot")
+    }
+    pub fn uou_skin(&self) -> uou::UouSkin {
+        let mut skin = uou::UouSkin::default();
+        if let UnlockValue::UouColor(Some(color)) = self.fetch_selected("u_color").value {
+            skin.color = Some(color);
+        }
+        if let UnlockValue::UouShape(shape) = self.fetch_selected("u_shape").value {
+            skin.shape = shape;
+        }
+        skin
+    }
+    pub fn bgm_path(&self) -> &'static str {
+        match self.fetch_selected("bgm").value {
+            UnlockValue::Bgm(path) => path,
+            _ => "audio/bgm/main.ogg",
+        }
+    }
+    pub fn pending_codes(&self) -> Vec<String> {
+        self.pending_codes.clone()
+    }
+    pub fn is_pending(&self, code: &str) -> bool {
+        self.pending_codes.contains(&String::from(code))
+    }
+    pub fn clear_pending(&mut self) {
+        self.pending_codes = vec![];
+    }
+    fn unlock_reached(
+        &mut self,
+        ach_store: &achievement::AchievementStore,
+        ach_defs: &achievement::AchievementDefs,
+    ) {
+        for unlock_def in UNLOCKS.iter() {
+            if self.is_unlocked(unlock_def.code) || !unlock_def.is_reached(ach_store, ach_defs) {
+                continue;
+            }
+            self.unlocked_codes.push(String::from(unlock_def.code));
+            self.pending_codes.push(String::from(unlock_def.code));
+        }
+    }
+}
+
+pub struct UnlockPlugin;
+
+impl Plugin for UnlockPlugin {
+    fn build(&self, app: &mut App) {
+        let config_dir = dirs::config_dir()
+            .map(|native_config_dir| native_config_dir.join("chain-reaction"))
+            .unwrap_or(Path::new("local").join("configuration"));
+        app.insert_resource(
+            Persistent::<UnlockStore>::builder()
+                .name("unlock")
+                .format(StorageFormat::Bincode)
+                .path(config_dir.join("unlock.bin"))
+                .default(UnlockStore {
+                    unlocked_codes: vec![],
+                    pending_codes: vec![],
+                    selected_map: HashMap::new(),
+                })
+                .build()
+                .expect("failed to initialize variables"),
+        );
+    }
+}
+
+pub fn check_unlocks(
+    ach_store: Res<Persistent<achievement::AchievementStore>>,
+    ach_defs: Res<achievement::AchievementDefs>,
+    mut unlock_store: ResMut<Persistent<UnlockStore>>,
+) {
+    let is_reached = UNLOCKS.iter().any(|unlock_def| {
+        !unlock_store.is_unlocked(unlock_def.code) && unlock_def.is_reached(&ach_store, &ach_defs)
+    });
+    if !is_reached {
+        return;
+    }
+    unlock_store
+        .update(|unlock_store| {
+            unlock_store.unlock_reached(&ach_store, &ach_defs);
+        })
+        .expect("failed to update unlocks");
+}
+
+pub fn fetch_slot_name(slot: &str) -> &str {
+    match slot {
+        "u_color" => "U Color",
+        "u_shape" => "U Shape",
+        "bgm" => "BGM",
+        _ => panic!("Invalid unlock slot"),
+    }
+}
+
+pub fn fetch_slot_icon(slot: &str) -> &str {
+    match slot {
+        "u_color" => "palette-fill",
+        "u_shape" => "shapes-fill",
+        "bgm" => "music-notes-fill",
+        _ => panic!("Invalid unlock slot"),
+    }
+}
+
+pub fn find_unlock_def(code: &str) -> Option<&'static UnlockDef> {
+    UNLOCKS.iter().find(|unlock_def| unlock_def.code == code)
+}
+
+pub fn fetch_unlock_def(code: &str) -> &'static UnlockDef {
+    find_unlock_def(code).expect("Invalid unlock code")
+}
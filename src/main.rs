@@ -8,7 +8,6 @@ use chain_reaction::{app, page, reactor};
 
 fn main() {
     App::new()
-        .insert_resource(ClearColor(app::ui::BG_COLOR))
         .insert_resource(AssetMetaCheck::Never)
         .add_systems(Startup, app::init::startup)
         .add_plugins((
@@ -16,11 +15,18 @@ fn main() {
             ShapePlugin,
             DefaultNavigationPlugins,
             TweeningPlugin,
-            app::settings::SettingsPlugin,
+            (
+                app::settings::SettingsPlugin,
+                app::theme::ThemePlugin,
+                app::locale::LocalePlugin,
+            ),
             app::leaderboard::LeaderboardPlugin,
             app::achievement::AchievementPlugin,
-            app::audio::AudioSeAssetPlugin,
+            app::unlock::UnlockPlugin,
+            app::audio::AudioMixerPlugin,
             app::key_binding::KeyBindingPlugin,
+            app::gamepad::GamepadPlugin,
+            app::touch::TouchPlugin,
             reactor::ReactorPlugin,
             page::PagePlugin,
             app::interaction::InteractionPlugin,
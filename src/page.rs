@@ -10,6 +10,7 @@ pub mod help;
 pub mod leaderboard;
 pub mod menu;
 pub mod settings;
+pub mod unlock;
 
 #[derive(Clone, Copy, Default, Eq, PartialEq, Debug, Hash, States)]
 pub enum PageState {
@@ -20,6 +21,7 @@ pub enum PageState {
     About,
     Leaderboard,
     Achievement,
+    Unlock,
     Help,
     Dev,
     Auto,
@@ -33,12 +35,13 @@ pub trait PageDefBase {
     fn build(&self, app: &mut App);
 }
 
-pub const PAGES: [&dyn PageDefBase; 9] = [
+pub const PAGES: [&dyn PageDefBase; 10] = [
     &menu::PageDef,
     &game::PageDef,
     &settings::PageDef,
     &leaderboard::PageDef,
     &achievement::PageDef,
+    &unlock::PageDef,
     &help::PageDef,
     &about::PageDef,
     &dev::PageDef,
@@ -73,7 +76,7 @@ pub fn fetch_page_icon_path(code: &str) -> String {
 const PAGE_TITLE_RATIO: f32 = 1.2;
 const SEP_W: f32 = 500.0;
 
-pub fn build_page_layout() -> NodeBundle {
+pub fn build_page_layout(theme: &app::theme::Theme) -> NodeBundle {
     NodeBundle {
         style: Style {
             width: Val::Percent(100.0),
@@ -81,7 +84,7 @@ pub fn build_page_layout() -> NodeBundle {
             padding: UiRect::all(app::ui::px_p(app::ui::PAGE_PADDING)),
             ..default()
         },
-        background_color: app::ui::COVER_COLOR.into(),
+        background_color: theme.cover.into(),
         ..default()
     }
 }
@@ -116,6 +119,8 @@ pub fn build_page_title(
     asset_server: &Res<AssetServer>,
     text: &str,
     icon: &str,
+    theme: &app::theme::Theme,
+    locale: &app::locale::Locale,
 ) -> Entity {
     parent
         .spawn((NodeBundle {
@@ -129,8 +134,8 @@ pub fn build_page_title(
                 border: UiRect::all(app::ui::px_p(0.5)),
                 ..default()
             },
-            background_color: app::ui::BG_COLOR.into(),
-            border_color: app::ui::FG_COLOR.into(),
+            background_color: theme.bg.into(),
+            border_color: theme.fg.into(),
             ..default()
         },))
         .with_children(|parent| {
@@ -146,20 +151,21 @@ pub fn build_page_title(
                 image: UiImage::new(icon),
                 ..default()
             });
-            parent.spawn(
+            parent.spawn((
                 TextBundle::from_section(
-                    text,
+                    locale.tr(text),
                     TextStyle {
-                        font: asset_server.load(app::ui::FONT),
+                        font: asset_server.load(locale.fetch_font(app::ui::FONT)),
                         font_size: app::ui::FONT_SIZE * PAGE_TITLE_RATIO,
-                        color: app::ui::FG_COLOR,
+                        color: theme.fg,
                     },
                 )
                 .with_style(Style {
                     margin: UiRect::right(app::ui::px_p(2.0)),
                     ..default()
                 }),
-            );
+                app::locale::LocalizedText(String::from(text)),
+            ));
         })
         .id()
 }
@@ -169,6 +175,8 @@ pub fn build_sep_title(
     asset_server: &Res<AssetServer>,
     text: &str,
     icon: &str,
+    theme: &app::theme::Theme,
+    locale: &app::locale::Locale,
 ) -> Entity {
     parent
         .spawn((NodeBundle {
@@ -189,7 +197,7 @@ pub fn build_sep_title(
                     margin: UiRect::top(app::ui::px_p(3.0)),
                     ..default()
                 },
-                background_color: app::ui::SECONDARY_COLOR.into(),
+                background_color: theme.secondary.into(),
                 ..default()
             },));
             parent
@@ -200,7 +208,7 @@ pub fn build_sep_title(
                         padding: UiRect::all(app::ui::px_p(3.0)),
                         ..default()
                     },
-                    background_color: app::ui::BG_COLOR.into(),
+                    background_color: theme.bg.into(),
                     ..default()
                 },))
                 .with_children(|parent| {
@@ -216,20 +224,21 @@ pub fn build_sep_title(
                         image: UiImage::new(icon),
                         ..default()
                     });
-                    parent.spawn(
+                    parent.spawn((
                         TextBundle::from_section(
-                            text,
+                            locale.tr(text),
                             TextStyle {
-                                font: asset_server.load(app::ui::FONT),
+                                font: asset_server.load(locale.fetch_font(app::ui::FONT)),
                                 font_size: app::ui::FONT_SIZE * PAGE_TITLE_RATIO,
-                                color: app::ui::SECONDARY_COLOR,
+                                color: theme.secondary,
                             },
                         )
                         .with_style(Style {
                             margin: UiRect::right(app::ui::px_p(2.0)),
                             ..default()
                         }),
-                    );
+                        app::locale::LocalizedText(String::from(text)),
+                    ));
                 });
         })
         .id()
@@ -42,9 +42,14 @@ enum ButtonAction {
     MoveToPage(PageState),
 }
 
-fn page_enter(mut commands: Commands, asset_server: Res<AssetServer>) {
+fn page_enter(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    theme: Res<app::theme::Theme>,
+    locale: Res<app::locale::Locale>,
+) {
     commands
-        .spawn((build_page_layout(), OnPage))
+        .spawn((build_page_layout(&theme), OnPage))
         .with_children(|parent| {
             parent
                 .spawn(NodeBundle {
@@ -59,7 +64,7 @@ fn page_enter(mut commands: Commands, asset_server: Res<AssetServer>) {
                 })
                 .with_children(|parent| {
                     build_game_title(parent, &asset_server);
-                    build_page_title(parent, &asset_server, PAGE_NAME, PAGE_ICON);
+                    build_page_title(parent, &asset_server, PAGE_NAME, PAGE_ICON, &theme, &locale);
                     parent
                         .spawn(NodeBundle {
                             style: Style {
@@ -95,13 +100,17 @@ fn page_enter(mut commands: Commands, asset_server: Res<AssetServer>) {
                                         env!("CARGO_PKG_VERSION"),
                                         None,
                                         "default",
-                                        false
+                                        false,
+                                        &theme,
+                                        &locale
                                     );
                                     build_sep_title(
                                         parent,
                                         &asset_server,
                                         "Link",
                                         "link-bold",
+                                        &theme,
+                                        &locale,
                                     );
                                     app::ui::build_link(
                                         parent,
@@ -110,7 +119,9 @@ fn page_enter(mut commands: Commands, asset_server: Res<AssetServer>) {
                                         "itch.io",
                                         Some("house-line-light"),
                                         "default",
-                                        true
+                                        true,
+                                        &theme,
+                                        &locale
                                     );
                                     app::ui::build_link(
                                         parent,
@@ -119,13 +130,17 @@ fn page_enter(mut commands: Commands, asset_server: Res<AssetServer>) {
                                         "github.com",
                                         Some("github-logo-light"),
                                         "default",
-                                        true
+                                        true,
+                                        &theme,
+                                        &locale
                                     );
                                     build_sep_title(
                                         parent,
                                         &asset_server,
                                         "Design",
                                         "pencil-line-fill",
+                                        &theme,
+                                        &locale,
                                     );
                                     app::ui::build_link(
                                         parent,
@@ -134,13 +149,17 @@ fn page_enter(mut commands: Commands, asset_server: Res<AssetServer>) {
                                         "Kait Wang",
                                         None,
                                         "default",
-                                        false
+                                        false,
+                                        &theme,
+                                        &locale
                                     );
                                     build_sep_title(
                                         parent,
                                         &asset_server,
                                         "Programming",
                                         "code-bold",
+                                        &theme,
+                                        &locale,
                                     );
                                     app::ui::build_link(
                                         parent,
@@ -149,13 +168,17 @@ fn page_enter(mut commands: Commands, asset_server: Res<AssetServer>) {
                                         "Kait Wang",
                                         None,
                                         "default",
-                                        false
+                                        false,
+                                        &theme,
+                                        &locale
                                     );
                                     build_sep_title(
                                         parent,
                                         &asset_server,
                                         "Art",
                                         "palette-fill",
+                                        &theme,
+                                        &locale,
                                     );
                                     app::ui::build_link(
                                         parent,
@@ -164,7 +187,9 @@ fn page_enter(mut commands: Commands, asset_server: Res<AssetServer>) {
                                         "Miya",
                                         None,
                                         "default",
-                                        false
+                                        false,
+                                        &theme,
+                                        &locale
                                     );
                                 });
                             parent
@@ -182,6 +207,8 @@ fn page_enter(mut commands: Commands, asset_server: Res<AssetServer>) {
                                         &asset_server,
                                         "Icon",
                                         "shapes-fill",
+                                        &theme,
+                                        &locale,
                                     );
                                     app::ui::build_link(
                                         parent,
@@ -190,13 +217,17 @@ fn page_enter(mut commands: Commands, asset_server: Res<AssetServer>) {
                                         "Phosphor Icons",
                                         Some("globe-light"),
                                         "default",
-                                        true
+                                        true,
+                                        &theme,
+                                        &locale
                                     );
                                     build_sep_title(
                                         parent,
                                         &asset_server,
                                         "Font",
                                         "text-aa-fill",
+                                        &theme,
+                                        &locale,
                                     );
                                     app::ui::build_link(
                                         parent,
@@ -205,7 +236,9 @@ fn page_enter(mut commands: Commands, asset_server: Res<AssetServer>) {
                                         "SYN NOVA",
                                         Some("globe-light"),
                                         "default",
-                                        true
+                                        true,
+                                        &theme,
+                                        &locale
                                     );
                                     app::ui::build_link(
                                         parent,
@@ -214,7 +247,9 @@ fn page_enter(mut commands: Commands, asset_server: Res<AssetServer>) {
                                         "Telegrama",
                                         Some("globe-light"),
                                         "digit",
-                                        true
+                                        true,
+                                        &theme,
+                                        &locale
                                     );
                                     app::ui::build_link(
                                         parent,
@@ -223,13 +258,17 @@ fn page_enter(mut commands: Commands, asset_server: Res<AssetServer>) {
                                         "VAG-HandWritten",
                                         Some("globe-light"),
                                         "hw",
-                                        true
+                                        true,
+                                        &theme,
+                                        &locale
                                     );
                                     build_sep_title(
                                         parent,
                                         &asset_server,
                                         "Audio",
                                         "microphone-fill",
+                                        &theme,
+                                        &locale,
                                     );
                                     app::ui::build_link(
                                         parent,
@@ -240,7 +279,9 @@ fn page_enter(mut commands: Commands, asset_server: Res<AssetServer>) {
                                         "Synthetic Deception - By GloeleFazzeri",
                                         Some("globe-light"),
                                         "default",
-                                        true
+                                        true,
+                                        &theme,
+                                        &locale
                                     );
                                     app::ui::build_link(
                                         parent,
@@ -251,7 +292,9 @@ fn page_enter(mut commands: Commands, asset_server: Res<AssetServer>) {
                                         "Heavy Cineamtic Hit - By LordSonny",
                                         Some("globe-light"),
                                         "default",
-                                        true
+                                        true,
+                                        &theme,
+                                        &locale
                                     );
                                     app::ui::build_link(
                                         parent,
@@ -263,6 +306,8 @@ fn page_enter(mut commands: Commands, asset_server: Res<AssetServer>) {
                                         Some("globe-light"),
                                         "default",
                                         true,
+                                        &theme,
+                                        &locale,
                                     );
                                     app::ui::build_link(
                                         parent,
@@ -274,6 +319,8 @@ fn page_enter(mut commands: Commands, asset_server: Res<AssetServer>) {
                                         Some("globe-light"),
                                         "default",
                                         true,
+                                        &theme,
+                                        &locale,
 
                                     );
                                     app::ui::build_link(
@@ -285,7 +332,9 @@ fn page_enter(mut commands: Commands, asset_server: Res<AssetServer>) {
                                         "Glass Shatter 3 - From Pixabay",
                                         Some("globe-light"),
                                         "default",
-                                        true
+                                        true,
+                                        &theme,
+                                        &locale
                                     );
                                     app::ui::build_link(
                                         parent,
@@ -296,7 +345,9 @@ fn page_enter(mut commands: Commands, asset_server: Res<AssetServer>) {
                                         "Tada Military 3 - By floraphonic",
                                         Some("globe-light"),
                                         "default",
-                                        true
+                                        true,
+                                        &theme,
+                                        &locale
                                     );
                                     app::ui::build_link(
                                         parent,
@@ -307,7 +358,9 @@ fn page_enter(mut commands: Commands, asset_server: Res<AssetServer>) {
                                         "8-Bit Game 5 - By floraphonic",
                                         Some("globe-light"),
                                         "default",
-                                        true
+                                        true,
+                                        &theme,
+                                        &locale
                                     );
                                 });
                         });
@@ -323,6 +376,8 @@ fn page_enter(mut commands: Commands, asset_server: Res<AssetServer>) {
                         ..default()
                     },
                     "arrow-left-light",
+                    &theme,
+                    &locale,
                 );
         });
 }
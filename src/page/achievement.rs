@@ -42,25 +42,22 @@ enum ButtonAction {
     BackToMainMenu,
 }
 
-#[derive(Component)]
-struct LeaderboardList(String);
-
-#[derive(Component)]
-struct ScreenshotPanel;
-
-#[derive(Component)]
-struct ScreenshotImage;
-
 #[derive(Component)]
 struct AchPanelIcon(String);
 
+#[allow(clippy::too_many_arguments)]
 fn page_enter(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     achievement: Res<Persistent<app::achievement::AchievementStore>>,
+    ach_counters: Res<Persistent<app::achievement::AchievementCounters>>,
+    ach_progress: Res<Persistent<app::achievement::AchievementProgress>>,
+    ach_defs: Res<app::achievement::AchievementDefs>,
+    theme: Res<app::theme::Theme>,
+    locale: Res<app::locale::Locale>,
 ) {
     commands
-        .spawn((build_page_layout(), OnPage))
+        .spawn((build_page_layout(&theme), OnPage))
         .with_children(|parent| {
             parent
                 .spawn(NodeBundle {
@@ -75,7 +72,7 @@ fn page_enter(
                 })
                 .with_children(|parent| {
                     build_game_title(parent, &asset_server);
-                    build_page_title(parent, &asset_server, "Marks", "crosshair");
+                    build_page_title(parent, &asset_server, "Marks", "crosshair", &theme, &locale);
                     parent
                         .spawn(NodeBundle {
                             style: Style {
@@ -101,11 +98,11 @@ fn page_enter(
                                         row_gap: app::ui::px_p(2.0),
                                         ..default()
                                     },
-                                    background_color: app::ui::BG_COLOR.into(),
+                                    background_color: theme.bg.into(),
                                     ..default()
                                 })
                                 .with_children(|parent| {
-                                    for ach_def in app::achievement::ACHIEVEMENTS {
+                                    for ach_def in ach_defs.iter() {
                                         let record = achievement.fetch_record(ach_def.code());
                                         build_panel_ui(
                                             parent,
@@ -113,6 +110,10 @@ fn page_enter(
                                             ach_def,
                                             &record,
                                             &achievement,
+                                            &ach_counters,
+                                            &ach_progress,
+                                            &theme,
+                                            &locale,
                                         );
                                     }
                                 });
@@ -133,6 +134,8 @@ fn page_enter(
                     ..default()
                 },
                 "arrow-left-light",
+                &theme,
+                &locale,
             );
         });
 }
@@ -172,18 +175,28 @@ const ACH_STATUS_SIZE: f32 = app::ui::FONT_SIZE * 1.0;
 const ACH_PANEL_W: f32 = app::ui::FONT_SIZE * 12.0;
 const ACH_DESC_FS: f32 = app::ui::FONT_SIZE * 0.8;
 const ACH_NAME_FS: f32 = app::ui::FONT_SIZE * 1.2;
+const PROGRESS_BAR_H: f32 = 1.0;
+const TIER_PIP_SIZE: f32 = 3.0;
+const PROGRESS_DOT_SIZE: f32 = 3.0;
 
+#[allow(clippy::too_many_arguments)]
 fn build_panel_ui(
     parent: &mut ChildBuilder,
     asset_server: &Res<AssetServer>,
     ach_def: &dyn app::achievement::AchievementDefBase,
     record: &app::achievement::AchievementRecord,
     store: &Res<Persistent<app::achievement::AchievementStore>>,
+    counters: &Res<Persistent<app::achievement::AchievementCounters>>,
+    progress: &Res<Persistent<app::achievement::AchievementProgress>>,
+    theme: &app::theme::Theme,
+    locale: &app::locale::Locale,
 ) {
-    let color = if record.is_done {
-        ach_def.color()
+    let done_tier_count = store.done_tier_count(ach_def);
+    let next_tier = store.next_tier(ach_def);
+    let (color, border_color) = if done_tier_count > 0 {
+        (ach_def.color(), ach_def.tier_color(done_tier_count - 1))
     } else {
-        app::ui::MUTE_COLOR
+        (theme.mute, theme.mute)
     };
     let mut entity = parent.spawn((ButtonBundle {
         style: Style {
@@ -194,12 +207,12 @@ fn build_panel_ui(
             border: UiRect::all(app::ui::px_p(1.0)),
             ..default()
         },
-        background_color: app::ui::BG_COLOR.into(),
-        border_color: color.into(),
+        background_color: theme.bg.into(),
+        border_color: border_color.into(),
         ..default()
     },));
     entity.with_children(|parent| {
-        let icon = if record.is_done {
+        let icon = if done_tier_count > 0 {
             asset_server.load(ach_def.icon_path())
         } else {
             asset_server.load("images/achievement/locked.png")
@@ -225,17 +238,17 @@ fn build_panel_ui(
                     padding: UiRect::right(Val::Px(ACH_STATUS_SIZE)),
                     ..default()
                 },
-                background_color: app::ui::BG_COLOR.into(),
+                background_color: theme.bg.into(),
                 ..default()
             },))
             .with_children(|parent| {
                 parent.spawn(
                     TextBundle::from_section(
-                        ach_def.description(),
+                        ach_def.description(locale, next_tier),
                         TextStyle {
-                            font: asset_server.load(app::ui::FONT),
+                            font: asset_server.load(locale.fetch_font(app::ui::FONT)),
                             font_size: ACH_DESC_FS,
-                            color: app::ui::SECONDARY_COLOR,
+                            color: theme.secondary,
                         },
                     )
                     .with_style(Style {
@@ -243,16 +256,16 @@ fn build_panel_ui(
                         ..default()
                     }),
                 );
-                let name = if record.is_done {
-                    ach_def.name()
+                let name = if done_tier_count > 0 {
+                    app::achievement::format_tier_name(ach_def, done_tier_count - 1, locale)
                 } else {
-                    "???"
+                    String::from("???")
                 };
                 parent.spawn(
                     TextBundle::from_section(
                         name,
                         TextStyle {
-                            font: asset_server.load(app::ui::FONT),
+                            font: asset_server.load(locale.fetch_font(app::ui::FONT)),
                             font_size: ACH_NAME_FS,
                             color,
                         },
@@ -262,6 +275,32 @@ fn build_panel_ui(
                         ..default()
                     }),
                 );
+                if ach_def.tier_count() > 1 {
+                    build_tier_pips(parent, ach_def, done_tier_count, theme);
+                }
+                if !record.is_done {
+                    if let Some(value_total) = ach_def.lifetime_progress(counters, next_tier) {
+                        build_progress_bar(
+                            parent,
+                            asset_server,
+                            ach_def,
+                            value_total,
+                            theme,
+                            locale,
+                        );
+                    } else if let Some(best) = progress.fetch_best(ach_def.code()) {
+                        let total = ach_def.target(next_tier);
+                        build_best_progress(
+                            parent,
+                            asset_server,
+                            ach_def,
+                            &best,
+                            total,
+                            theme,
+                            locale,
+                        );
+                    }
+                }
             });
         if !record.is_done {
             let icon = if store.is_pinned(ach_def.code()) {
@@ -295,3 +334,201 @@ fn build_panel_ui(
         ));
     }
 }
+
+fn build_progress_bar(
+    parent: &mut ChildBuilder,
+    asset_server: &Res<AssetServer>,
+    ach_def: &dyn app::achievement::AchievementDefBase,
+    value_total: (u32, u32),
+    theme: &app::theme::Theme,
+    locale: &app::locale::Locale,
+) {
+    let (value, total) = value_total;
+    let value_bar_ratio = (value as f32 / total as f32 * 100.0).min(100.0);
+    parent
+        .spawn(NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::End,
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|parent| {
+            parent.spawn(TextBundle::from_section(
+                format!(
+                    "{} / {}",
+                    ach_def.format_value(value),
+                    ach_def.format_value(total)
+                ),
+                TextStyle {
+                    font: asset_server.load(locale.fetch_font(app::ui::FONT)),
+                    font_size: ACH_DESC_FS,
+                    color: theme.secondary,
+                },
+            ));
+            parent
+                .spawn(NodeBundle {
+                    style: Style {
+                        width: Val::Percent(100.0),
+                        justify_content: JustifyContent::Start,
+                        ..default()
+                    },
+                    background_color: theme.mute.into(),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    parent.spawn(NodeBundle {
+                        style: Style {
+                            width: Val::Percent(value_bar_ratio),
+                            height: app::ui::px_p(PROGRESS_BAR_H),
+                            ..default()
+                        },
+                        background_color: theme.secondary.into(),
+                        ..default()
+                    });
+                });
+        });
+}
+
+fn build_tier_pips(
+    parent: &mut ChildBuilder,
+    ach_def: &dyn app::achievement::AchievementDefBase,
+    done_tier_count: usize,
+    theme: &app::theme::Theme,
+) {
+    parent
+        .spawn(NodeBundle {
+            style: Style {
+                column_gap: app::ui::px_p(1.0),
+                margin: UiRect::vertical(app::ui::px_p(1.0)),
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|parent| {
+            for tier in 0..ach_def.tier_count() {
+                let color = if tier < done_tier_count {
+                    ach_def.tier_color(tier)
+                } else {
+                    theme.mute
+                };
+                parent.spawn(NodeBundle {
+                    style: Style {
+                        width: app::ui::px_p(TIER_PIP_SIZE),
+                        height: app::ui::px_p(TIER_PIP_SIZE),
+                        ..default()
+                    },
+                    background_color: color.into(),
+                    ..default()
+                });
+            }
+        });
+}
+
+fn build_best_progress(
+    parent: &mut ChildBuilder,
+    asset_server: &Res<AssetServer>,
+    ach_def: &dyn app::achievement::AchievementDefBase,
+    best: &app::achievement::AchievementBest,
+    total: u32,
+    theme: &app::theme::Theme,
+    locale: &app::locale::Locale,
+) {
+    parent
+        .spawn(NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::End,
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|parent| {
+            parent.spawn(TextBundle::from_section(
+                locale
+                    .tr("Best {value} / {total} ({date})")
+                    .replace("{value}", &ach_def.format_value(best.value))
+                    .replace("{total}", &ach_def.format_value(total))
+                    .replace("{date}", best.uid.split('_').next().unwrap_or("")),
+                TextStyle {
+                    font: asset_server.load(locale.fetch_font(app::ui::FONT)),
+                    font_size: ACH_DESC_FS,
+                    color: theme.secondary,
+                },
+            ));
+            match ach_def.progress_ui() {
+                app::achievement::AchievementProgressUi::Bar => {
+                    let best_bar_ratio = (best.value as f32 / total as f32 * 100.0).min(100.0);
+                    parent
+                        .spawn(NodeBundle {
+                            style: Style {
+                                width: Val::Percent(100.0),
+                                height: app::ui::px_p(PROGRESS_BAR_H),
+                                ..default()
+                            },
+                            background_color: theme.mute.into(),
+                            ..default()
+                        })
+                        .with_children(|parent| {
+                            // NOTE: the marker track stops one marker short of the end,
+                            // so a full best stays inside the bar
+                            parent
+                                .spawn(NodeBundle {
+                                    style: Style {
+                                        position_type: PositionType::Absolute,
+                                        left: Val::Px(0.0),
+                                        right: app::ui::px_p(PROGRESS_BAR_H),
+                                        height: app::ui::px_p(PROGRESS_BAR_H),
+                                        ..default()
+                                    },
+                                    ..default()
+                                })
+                                .with_children(|parent| {
+                                    parent.spawn(NodeBundle {
+                                        style: Style {
+                                            position_type: PositionType::Absolute,
+                                            left: Val::Percent(best_bar_ratio),
+                                            width: app::ui::px_p(PROGRESS_BAR_H),
+                                            height: app::ui::px_p(PROGRESS_BAR_H),
+                                            ..default()
+                                        },
+                                        background_color: theme.ghost.into(),
+                                        ..default()
+                                    });
+                                });
+                        });
+                }
+                app::achievement::AchievementProgressUi::Dots => {
+                    parent
+                        .spawn(NodeBundle {
+                            style: Style {
+                                column_gap: app::ui::px_p(1.0),
+                                ..default()
+                            },
+                            ..default()
+                        })
+                        .with_children(|parent| {
+                            for index in 0..total {
+                                let color = if index < best.value {
+                                    theme.ghost
+                                } else {
+                                    theme.mute
+                                };
+                                parent.spawn(NodeBundle {
+                                    style: Style {
+                                        width: app::ui::px_p(PROGRESS_DOT_SIZE),
+                                        height: app::ui::px_p(PROGRESS_DOT_SIZE),
+                                        ..default()
+                                    },
+                                    background_color: color.into(),
+                                    ..default()
+                                });
+                            }
+                        });
+                }
+            }
+        });
+}
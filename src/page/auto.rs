@@ -40,7 +40,12 @@ enum ButtonAction {
     BackToMainMenu,
 }
 
-fn page_enter(mut commands: Commands, asset_server: Res<AssetServer>) {
+fn page_enter(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    theme: Res<app::theme::Theme>,
+    locale: Res<app::locale::Locale>,
+) {
     commands
         .spawn((
             NodeBundle {
@@ -70,6 +75,8 @@ fn page_enter(mut commands: Commands, asset_server: Res<AssetServer>) {
                     ..default()
                 },
                 "arrow-left-light",
+                &theme,
+                &locale,
             );
         });
 }
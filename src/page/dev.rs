@@ -1,8 +1,4 @@
-use crate::{
-    app::{self, ui::BG_COLOR},
-    page::*,
-    reactor,
-};
+use crate::{app, page::*};
 use bevy_ui_navigation::{prelude::*, NavRequestSystem};
 
 const PAGE_CODE: &str = "dev";
@@ -44,30 +40,15 @@ enum ButtonAction {
     MoveToPage(PageState),
 }
 
-#[derive(Component)]
-struct LeaderboardList(String);
-
-#[derive(Component)]
-struct ScreenshotPanel;
-
-#[derive(Component)]
-struct ScreenshotImage;
-
-const COLORS: [Color; 9] = [
-    app::ui::FG_COLOR,
-    app::ui::BG_COLOR,
-    app::ui::SECONDARY_COLOR,
-    app::ui::MUTE_COLOR,
-    reactor::particle::alpha::COLOR,
-    reactor::particle::control::COLOR,
-    reactor::particle::hyper::COLOR,
-    reactor::particle::trigger::COLOR,
-    reactor::particle::uou::COLOR,
-];
-
-fn page_enter(mut commands: Commands, asset_server: Res<AssetServer>) {
+fn page_enter(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    ach_defs: Res<app::achievement::AchievementDefs>,
+    theme: Res<app::theme::Theme>,
+    locale: Res<app::locale::Locale>,
+) {
     commands
-        .spawn((build_page_layout(), OnPage))
+        .spawn((build_page_layout(&theme), OnPage))
         .with_children(|parent| {
             parent
                 .spawn(NodeBundle {
@@ -82,7 +63,7 @@ fn page_enter(mut commands: Commands, asset_server: Res<AssetServer>) {
                 })
                 .with_children(|parent| {
                     build_game_title(parent, &asset_server);
-                    build_page_title(parent, &asset_server, PAGE_NAME, PAGE_ICON);
+                    build_page_title(parent, &asset_server, PAGE_NAME, PAGE_ICON, &theme, &locale);
                     parent
                         .spawn(NodeBundle {
                             style: Style {
@@ -96,14 +77,21 @@ fn page_enter(mut commands: Commands, asset_server: Res<AssetServer>) {
                             ..default()
                         })
                         .with_children(|parent| {
-                            build_sep_title(parent, &asset_server, "Font", "text-aa-fill");
+                            build_sep_title(
+                                parent,
+                                &asset_server,
+                                "Font",
+                                "text-aa-fill",
+                                &theme,
+                                &locale,
+                            );
                             parent.spawn(
                                 TextBundle::from_section(
                                     "ABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789,.-",
                                     TextStyle {
                                         font: asset_server.load(app::ui::FONT),
                                         font_size: app::ui::FONT_SIZE,
-                                        color: app::ui::FG_COLOR,
+                                        color: theme.fg,
                                     },
                                 )
                                 .with_style(Style {
@@ -117,7 +105,7 @@ fn page_enter(mut commands: Commands, asset_server: Res<AssetServer>) {
                                     TextStyle {
                                         font: asset_server.load(app::ui::FONT),
                                         font_size: app::ui::FONT_SIZE,
-                                        color: app::ui::FG_COLOR,
+                                        color: theme.fg,
                                     },
                                 )
                                 .with_style(Style {
@@ -131,7 +119,7 @@ fn page_enter(mut commands: Commands, asset_server: Res<AssetServer>) {
                                     TextStyle {
                                         font: asset_server.load(app::ui::FONT_DIGIT),
                                         font_size: app::ui::FONT_SIZE,
-                                        color: app::ui::FG_COLOR,
+                                        color: theme.fg,
                                     },
                                 )
                                 .with_style(Style {
@@ -145,7 +133,7 @@ fn page_enter(mut commands: Commands, asset_server: Res<AssetServer>) {
                                     TextStyle {
                                         font: asset_server.load(app::ui::FONT_DIGIT),
                                         font_size: app::ui::FONT_SIZE,
-                                        color: app::ui::FG_COLOR,
+                                        color: theme.fg,
                                     },
                                 )
                                 .with_style(Style {
@@ -159,7 +147,7 @@ fn page_enter(mut commands: Commands, asset_server: Res<AssetServer>) {
                                     TextStyle {
                                         font: asset_server.load(app::ui::FONT_HW),
                                         font_size: app::ui::FONT_SIZE,
-                                        color: app::ui::FG_COLOR,
+                                        color: theme.fg,
                                     },
                                 )
                                 .with_style(Style {
@@ -173,7 +161,7 @@ fn page_enter(mut commands: Commands, asset_server: Res<AssetServer>) {
                                     TextStyle {
                                         font: asset_server.load(app::ui::FONT_HW),
                                         font_size: app::ui::FONT_SIZE,
-                                        color: app::ui::FG_COLOR,
+                                        color: theme.fg,
                                     },
                                 )
                                 .with_style(Style {
@@ -181,54 +169,26 @@ fn page_enter(mut commands: Commands, asset_server: Res<AssetServer>) {
                                     ..default()
                                 }),
                             );
-                            build_sep_title(parent, &asset_server, "Color", "palette-fill");
-                            parent
-                                .spawn(NodeBundle {
-                                    style: Style {
-                                        justify_content: JustifyContent::Center,
-                                        ..default()
-                                    },
-                                    background_color: app::ui::BG_COLOR.into(),
-                                    ..default()
-                                })
-                                .with_children(|parent| {
-                                    for color in COLORS {
-                                        parent
-                                            .spawn(NodeBundle {
-                                                style: Style {
-                                                    margin: UiRect::all(app::ui::px_p(2.0)),
-                                                    padding: UiRect::all(app::ui::px_p(2.0)),
-                                                    border: UiRect::all(app::ui::px_p(0.5)),
-                                                    ..default()
-                                                },
-                                                background_color: BG_COLOR.into(),
-                                                border_color: app::ui::FG_COLOR.into(),
-                                                ..default()
-                                            })
-                                            .with_children(|parent| {
-                                                parent.spawn(NodeBundle {
-                                                    style: Style {
-                                                        width: Val::Px(app::ui::ICON_SIZE * 1.5),
-                                                        height: Val::Px(app::ui::ICON_SIZE * 1.5),
-                                                        ..default()
-                                                    },
-                                                    background_color: color.into(),
-                                                    ..default()
-                                                });
-                                            });
-                                    }
-                                });
+                            build_sep_title(
+                                parent,
+                                &asset_server,
+                                "Color",
+                                "palette-fill",
+                                &theme,
+                                &locale,
+                            );
+                            app::ui::build_theme_swatches(parent, &theme);
                             parent
                                 .spawn(NodeBundle {
                                     style: Style {
                                         justify_content: JustifyContent::Center,
                                         ..default()
                                     },
-                                    background_color: app::ui::BG_COLOR.into(),
+                                    background_color: theme.bg.into(),
                                     ..default()
                                 })
                                 .with_children(|parent| {
-                                    for ach_def in app::achievement::ACHIEVEMENTS {
+                                    for ach_def in ach_defs.iter() {
                                         parent
                                             .spawn(NodeBundle {
                                                 style: Style {
@@ -237,8 +197,8 @@ fn page_enter(mut commands: Commands, asset_server: Res<AssetServer>) {
                                                     border: UiRect::all(app::ui::px_p(0.5)),
                                                     ..default()
                                                 },
-                                                background_color: BG_COLOR.into(),
-                                                border_color: app::ui::FG_COLOR.into(),
+                                                background_color: theme.bg.into(),
+                                                border_color: theme.fg.into(),
                                                 ..default()
                                             })
                                             .with_children(|parent| {
@@ -271,6 +231,8 @@ fn page_enter(mut commands: Commands, asset_server: Res<AssetServer>) {
                     ..default()
                 },
                 "arrow-left-light",
+                &theme,
+                &locale,
             );
         });
 }
@@ -54,6 +54,8 @@ fn page_enter(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     mut settings: ResMut<Persistent<app::settings::Settings>>,
+    theme: Res<app::theme::Theme>,
+    locale: Res<app::locale::Locale>,
 ) {
     if settings.is_enabled("first") {
         settings
@@ -63,7 +65,7 @@ fn page_enter(
             .expect("failed to update first run in help");
     }
     commands
-        .spawn((build_page_layout(), OnPage))
+        .spawn((build_page_layout(&theme), OnPage))
         .with_children(|parent| {
             parent
                 .spawn(NodeBundle {
@@ -78,7 +80,7 @@ fn page_enter(
                 })
                 .with_children(|parent| {
                     build_game_title(parent, &asset_server);
-                    build_page_title(parent, &asset_server, PAGE_NAME, PAGE_ICON);
+                    build_page_title(parent, &asset_server, PAGE_NAME, PAGE_ICON, &theme, &locale);
                     parent
                         .spawn(NodeBundle {
                             style: Style {
@@ -101,6 +103,8 @@ fn page_enter(
                                 ),
                                 Style::default(),
                                 "caret-double-left-light",
+                                &theme,
+                                &locale,
                             );
                             parent
                                 .spawn(NodeBundle {
@@ -115,8 +119,8 @@ fn page_enter(
                                     ..default()
                                 })
                                 .with_children(|parent| {
-                                    build_help_dots(parent);
-                                    build_help_panel(parent, &asset_server);
+                                    build_help_dots(parent, &theme);
+                                    build_help_panel(parent, &asset_server, &theme);
                                 });
                             app::ui::build_icon_btn(
                                 parent,
@@ -128,6 +132,8 @@ fn page_enter(
                                 ),
                                 Style::default(),
                                 "caret-double-right-light",
+                                &theme,
+                                &locale,
                             );
                         });
                 });
@@ -146,6 +152,8 @@ fn page_enter(
                     ..default()
                 },
                 "arrow-left-light",
+                &theme,
+                &locale,
             );
             app::ui::build_btn(
                 parent,
@@ -164,6 +172,8 @@ fn page_enter(
                 },
                 Some("Start"),
                 Some("play-light"),
+                &theme,
+                &locale,
             );
         });
 }
@@ -175,6 +185,7 @@ fn handle_ui_navigation(
     mut help_panel_query: Query<(&mut HelpPanel, &mut UiImage), With<HelpPanel>>,
     mut help_dot_query: Query<(&HelpDot, &mut BackgroundColor), With<HelpDot>>,
     asset_server: Res<AssetServer>,
+    theme: Res<app::theme::Theme>,
 ) {
     events.nav_iter().activated_in_query_foreach_mut(
         &mut actions,
@@ -189,9 +200,9 @@ fn handle_ui_navigation(
                 image.texture = icon;
                 for (help_dot, mut bg_color) in help_dot_query.iter_mut() {
                     if help_dot.0 == prev_help {
-                        *bg_color = app::ui::MUTE_COLOR.into();
+                        *bg_color = theme.mute.into();
                     } else {
-                        *bg_color = app::ui::BG_COLOR.into();
+                        *bg_color = theme.bg.into();
                     }
                 }
             }
@@ -204,9 +215,9 @@ fn handle_ui_navigation(
                 image.texture = icon;
                 for (help_dot, mut bg_color) in help_dot_query.iter_mut() {
                     if help_dot.0 == next_help {
-                        *bg_color = app::ui::MUTE_COLOR.into();
+                        *bg_color = theme.mute.into();
                     } else {
-                        *bg_color = app::ui::BG_COLOR.into();
+                        *bg_color = theme.bg.into();
                     }
                 }
             }
@@ -216,9 +227,13 @@ fn handle_ui_navigation(
 }
 
 const HELP_PANEL_SIZE: f32 = 156.0;
-const HELP_COUNT: u8 = 10;
+const HELP_COUNT: u8 = 11;
 
-fn build_help_panel(parent: &mut ChildBuilder, asset_server: &Res<AssetServer>) {
+fn build_help_panel(
+    parent: &mut ChildBuilder,
+    asset_server: &Res<AssetServer>,
+    theme: &app::theme::Theme,
+) {
     parent
         .spawn((NodeBundle {
             style: Style {
@@ -229,8 +244,8 @@ fn build_help_panel(parent: &mut ChildBuilder, asset_server: &Res<AssetServer>)
                 border: UiRect::all(app::ui::px_p(0.5)),
                 ..default()
             },
-            background_color: app::ui::BG_COLOR.into(),
-            border_color: app::ui::MUTE_COLOR.into(),
+            background_color: theme.bg.into(),
+            border_color: theme.mute.into(),
             ..default()
         },))
         .with_children(|parent| {
@@ -247,7 +262,7 @@ fn build_help_panel(parent: &mut ChildBuilder, asset_server: &Res<AssetServer>)
 
 const HELP_DOT_SIZE: f32 = 6.0;
 
-fn build_help_dots(parent: &mut ChildBuilder) {
+fn build_help_dots(parent: &mut ChildBuilder, theme: &app::theme::Theme) {
     parent
         .spawn((NodeBundle {
             style: Style {
@@ -261,9 +276,9 @@ fn build_help_dots(parent: &mut ChildBuilder) {
         },))
         .with_children(|parent| {
             for i in 0..HELP_COUNT {
-                let mut bg_color = app::ui::BG_COLOR.into();
+                let mut bg_color = theme.bg.into();
                 if i == 0 {
-                    bg_color = app::ui::MUTE_COLOR.into();
+                    bg_color = theme.mute.into();
                 }
                 parent.spawn((
                     NodeBundle {
@@ -274,7 +289,7 @@ fn build_help_dots(parent: &mut ChildBuilder) {
                             ..default()
                         },
                         background_color: bg_color,
-                        border_color: app::ui::MUTE_COLOR.into(),
+                        border_color: theme.mute.into(),
                         ..default()
                     },
                     HelpDot(i),
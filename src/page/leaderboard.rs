@@ -1,7 +1,7 @@
 use crate::{
     app,
     page::*,
-    reactor::{field, particle, status},
+    reactor::{field, status},
 };
 use bevy_persistent::prelude::*;
 use bevy_ui_navigation::{prelude::*, NavRequestSystem};
@@ -66,9 +66,11 @@ fn page_enter(
     asset_server: Res<AssetServer>,
     leaderboard: Res<Persistent<app::leaderboard::Leaderboard>>,
     status: Res<status::ReactorStatus>,
+    theme: Res<app::theme::Theme>,
+    locale: Res<app::locale::Locale>,
 ) {
     commands
-        .spawn((build_page_layout(), OnPage))
+        .spawn((build_page_layout(&theme), OnPage))
         .with_children(|parent| {
             parent
                 .spawn(NodeBundle {
@@ -83,7 +85,7 @@ fn page_enter(
                 })
                 .with_children(|parent| {
                     build_game_title(parent, &asset_server);
-                    build_page_title(parent, &asset_server, PAGE_NAME, PAGE_ICON);
+                    build_page_title(parent, &asset_server, PAGE_NAME, PAGE_ICON, &theme, &locale);
                     parent
                         .spawn(NodeBundle {
                             style: Style {
@@ -94,14 +96,32 @@ fn page_enter(
                             ..default()
                         })
                         .with_children(|parent| {
-                            build_list(parent, &asset_server, &leaderboard, &status, "score");
-                            build_list(parent, &asset_server, &leaderboard, &status, "time");
+                            build_list(
+                                parent,
+                                &asset_server,
+                                &leaderboard,
+                                &status,
+                                "score",
+                                &theme,
+                                &locale,
+                            );
+                            build_list(
+                                parent,
+                                &asset_server,
+                                &leaderboard,
+                                &status,
+                                "time",
+                                &theme,
+                                &locale,
+                            );
                             build_list(
                                 parent,
                                 &asset_server,
                                 &leaderboard,
                                 &status,
                                 "max_alpha_count",
+                                &theme,
+                                &locale,
                             );
                             build_list(
                                 parent,
@@ -109,6 +129,8 @@ fn page_enter(
                                 &leaderboard,
                                 &status,
                                 "max_control_chain",
+                                &theme,
+                                &locale,
                             );
                             build_list(
                                 parent,
@@ -116,6 +138,26 @@ fn page_enter(
                                 &leaderboard,
                                 &status,
                                 "max_hyper_chain",
+                                &theme,
+                                &locale,
+                            );
+                            build_list(
+                                parent,
+                                &asset_server,
+                                &leaderboard,
+                                &status,
+                                "max_graze_streak",
+                                &theme,
+                                &locale,
+                            );
+                            build_list(
+                                parent,
+                                &asset_server,
+                                &leaderboard,
+                                &status,
+                                "bomb_free_score",
+                                &theme,
+                                &locale,
                             );
                             parent
                                 .spawn(NodeBundle {
@@ -129,7 +171,7 @@ fn page_enter(
                                         column_gap: app::ui::px_p(4.0),
                                         ..default()
                                     },
-                                    background_color: app::ui::BG_COLOR.into(),
+                                    background_color: theme.bg.into(),
                                     ..default()
                                 })
                                 .with_children(|parent| {
@@ -143,6 +185,8 @@ fn page_enter(
                                         ),
                                         Style::default(),
                                         "trophy-fill",
+                                        &theme,
+                                        &locale,
                                     );
                                     app::ui::build_icon_btn(
                                         parent,
@@ -154,6 +198,8 @@ fn page_enter(
                                         ),
                                         Style::default(),
                                         "timer-fill",
+                                        &theme,
+                                        &locale,
                                     );
                                     app::ui::build_icon_btn(
                                         parent,
@@ -167,6 +213,8 @@ fn page_enter(
                                         ),
                                         Style::default(),
                                         "circles-three-fill",
+                                        &theme,
+                                        &locale,
                                     );
                                     let icon = asset_server.load("images/icons/line-segments.png");
                                     parent.spawn(ImageBundle {
@@ -191,6 +239,8 @@ fn page_enter(
                                         ),
                                         Style::default(),
                                         "square",
+                                        &theme,
+                                        &locale,
                                     );
                                     app::ui::build_icon_btn(
                                         parent,
@@ -204,6 +254,44 @@ fn page_enter(
                                         ),
                                         Style::default(),
                                         "hexagon",
+                                        &theme,
+                                        &locale,
+                                    );
+                                    app::ui::build_icon_btn(
+                                        parent,
+                                        &asset_server,
+                                        (
+                                            ButtonAction::SwitchList(String::from(
+                                                "max_graze_streak",
+                                            )),
+                                            app::interaction::IaButton,
+                                            Focusable::default(),
+                                        ),
+                                        Style {
+                                            margin: UiRect::left(app::ui::px_p(8.0)),
+                                            ..default()
+                                        },
+                                        "circle",
+                                        &theme,
+                                        &locale,
+                                    );
+                                    app::ui::build_icon_btn(
+                                        parent,
+                                        &asset_server,
+                                        (
+                                            ButtonAction::SwitchList(String::from(
+                                                "bomb_free_score",
+                                            )),
+                                            app::interaction::IaButton,
+                                            Focusable::default(),
+                                        ),
+                                        Style {
+                                            margin: UiRect::left(app::ui::px_p(8.0)),
+                                            ..default()
+                                        },
+                                        "star-light",
+                                        &theme,
+                                        &locale,
                                     );
                                 });
                         });
@@ -223,6 +311,8 @@ fn page_enter(
                     ..default()
                 },
                 "arrow-left-light",
+                &theme,
+                &locale,
             );
             parent
                 .spawn((
@@ -238,7 +328,7 @@ fn page_enter(
                             padding: UiRect::all(app::ui::px_p(3.0)),
                             ..default()
                         },
-                        background_color: app::ui::BG_COLOR.into(),
+                        background_color: theme.bg.into(),
                         visibility: Visibility::Hidden,
                         ..default()
                     },
@@ -272,6 +362,8 @@ fn page_enter(
                             ..default()
                         },
                         "arrow-left-light",
+                        &theme,
+                        &locale,
                     );
                 });
         });
@@ -328,6 +420,8 @@ fn build_list(
     leaderboard: &Res<Persistent<app::leaderboard::Leaderboard>>,
     status: &Res<status::ReactorStatus>,
     list: &str,
+    theme: &app::theme::Theme,
+    locale: &app::locale::Locale,
 ) -> Entity {
     let records = leaderboard.fetch_records(list);
     let visibility = if list == "score" {
@@ -364,25 +458,27 @@ fn build_list(
                 if let Some(record) = records.get(i) {
                     let number = record.fetch(list);
                     let number_text = match list {
-                        "score" => field::format_field_text("score", number),
+                        "score" | "bomb_free_score" => field::format_field_text("score", number),
                         "time" => field::format_field_text("time", number),
                         "max_alpha_count" => field::format_field_text("alpha_count", number),
                         "max_control_chain" => field::format_field_text("chain", number),
                         "max_hyper_chain" => field::format_field_text("chain", number),
+                        "max_graze_streak" => field::format_field_text("graze", number),
                         _ => panic!("Invalid list"),
                     };
                     let text_color = if record.uid() == status.highlight_uid {
-                        particle::uou::COLOR
+                        theme.u
                     } else {
-                        app::ui::FG_COLOR
+                        theme.fg
                     };
                     let number_color = if record.uid() == status.highlight_uid {
-                        particle::uou::COLOR
+                        theme.u
                     } else {
                         match list {
-                            "score" | "time" | "max_alpha_count" => app::ui::FG_COLOR,
-                            "max_control_chain" => particle::control::COLOR,
-                            "max_hyper_chain" => particle::hyper::COLOR,
+                            "score" | "time" | "max_alpha_count" | "bomb_free_score" => theme.fg,
+                            "max_control_chain" => theme.control,
+                            "max_hyper_chain" => theme.hyper,
+                            "max_graze_streak" => theme.u,
                             _ => panic!("Invalid list"),
                         }
                     };
@@ -391,6 +487,8 @@ fn build_list(
                         "time" => "images/icons/timer-fill.png",
                         "max_alpha_count" => "images/icons/circles-three-fill.png",
                         "max_control_chain" | "max_hyper_chain" => "images/icons/line-segments.png",
+                        "max_graze_streak" => "images/icons/circle.png",
+                        "bomb_free_score" => "images/icons/star-light.png",
                         _ => panic!("Invalid list"),
                     };
                     if i == 0 {
@@ -444,7 +542,7 @@ fn build_list(
                                         TextStyle {
                                             font: asset_server.load(app::ui::FONT_DIGIT),
                                             font_size: LB_FS * 0.6,
-                                            color: app::ui::BG_COLOR,
+                                            color: theme.bg,
                                         },
                                     ),));
                                 });
@@ -503,6 +601,8 @@ fn build_list(
                                     ),
                                     Style { ..default() },
                                     "frame-corners",
+                                    theme,
+                                    locale,
                                 );
                             }
                         });
@@ -46,17 +46,23 @@ enum ButtonAction {
     Quit,
 }
 
-const MENU_PAGES: [&dyn PageDefBase; 5] = [
+const MENU_PAGES: [&dyn PageDefBase; 6] = [
     &leaderboard::PageDef,
     &achievement::PageDef,
+    &unlock::PageDef,
     &help::PageDef,
     &settings::PageDef,
     &about::PageDef,
 ];
 
-fn page_enter(mut commands: Commands, asset_server: Res<AssetServer>) {
+fn page_enter(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    theme: Res<app::theme::Theme>,
+    locale: Res<app::locale::Locale>,
+) {
     commands
-        .spawn((build_page_layout(), OnPage))
+        .spawn((build_page_layout(&theme), OnPage))
         .with_children(|parent| {
             parent
                 .spawn(NodeBundle {
@@ -102,7 +108,7 @@ fn page_enter(mut commands: Commands, asset_server: Res<AssetServer>) {
                                     margin: UiRect::bottom(Val::Px(app::ui::MENU_ENTRY_PADDING)),
                                     ..default()
                                 },
-                                background_color: app::ui::BG_COLOR.into(),
+                                background_color: theme.bg.into(),
                                 ..default()
                             },
                             ButtonAction::FirstRun,
@@ -123,11 +129,11 @@ fn page_enter(mut commands: Commands, asset_server: Res<AssetServer>) {
                             });
                             parent.spawn(
                                 TextBundle::from_section(
-                                    game::PageDef.name(),
+                                    locale.tr(game::PageDef.name()),
                                     TextStyle {
-                                        font: asset_server.load(app::ui::FONT),
+                                        font: asset_server.load(locale.fetch_font(app::ui::FONT)),
                                         font_size: app::ui::FONT_SIZE * 1.6,
-                                        color: app::ui::FG_COLOR,
+                                        color: theme.fg,
                                     },
                                 )
                                 .with_style(Style {
@@ -159,6 +165,8 @@ fn page_enter(mut commands: Commands, asset_server: Res<AssetServer>) {
                                     ),
                                     page_def.name(),
                                     page_def.icon(),
+                                    &theme,
+                                    &locale,
                                 );
                             }
                             #[cfg(not(target_arch = "wasm32"))]
@@ -172,6 +180,8 @@ fn page_enter(mut commands: Commands, asset_server: Res<AssetServer>) {
                                 ),
                                 "Quit",
                                 "sign-out-light",
+                                &theme,
+                                &locale,
                             );
                         });
                 });
@@ -190,6 +200,8 @@ fn page_enter(mut commands: Commands, asset_server: Res<AssetServer>) {
                     ..default()
                 },
                 "monitor",
+                &theme,
+                &locale,
             );
         });
 }
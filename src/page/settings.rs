@@ -23,10 +23,14 @@ impl PageDefBase for PageDef {
         PageState::Settings
     }
     fn build(&self, app: &mut App) {
-        app.add_systems(OnEnter(self.state()), page_enter)
+        app.init_resource::<RebindingTarget>()
+            .add_systems(OnEnter(self.state()), page_enter)
             .add_systems(
                 Update,
                 (
+                    handle_rebinding
+                        .before(app::key_binding::handle_key_binding)
+                        .before(bevy_ui_navigation::systems::default_keyboard_input),
                     detect_sensitivity_modification,
                     control_test_ball_by_mouse,
                     control_test_ball_by_keyboard,
@@ -37,10 +41,14 @@ impl PageDefBase for PageDef {
                         handle_slider_navigation,
                     )
                         .after(NavRequestSystem),
+                    refresh_gamepad_display.after(handle_ui_navigation),
                 )
                     .run_if(in_state(self.state())),
             )
-            .add_systems(OnExit(self.state()), app::ui::despawn_ui::<OnPage>);
+            .add_systems(
+                OnExit(self.state()),
+                (app::ui::despawn_ui::<OnPage>, page_exit),
+            );
     }
 }
 
@@ -71,8 +79,25 @@ enum ButtonAction {
     Toggle(String),
     SetValue(String),
     PlaySe,
+    BindKey(app::key_binding::GameAction),
+    BindButton(app::key_binding::GameAction),
+    ResetControls,
+    Cycle(String),
+    NextGamepad,
 }
 
+#[derive(Component)]
+struct CycleText(String);
+
+#[derive(Component)]
+struct MoveTestStick;
+
+#[derive(Component)]
+struct BindingText(app::key_binding::GameAction, bool);
+
+#[derive(Resource, Default)]
+struct RebindingTarget(Option<(app::key_binding::GameAction, bool)>);
+
 type RangeBgBarOnly = (With<RangeBgBar>, Without<RangeValueBar>);
 type RangeValueBarOnly = (With<RangeValueBar>, Without<RangeBgBar>);
 
@@ -80,9 +105,12 @@ fn page_enter(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     settings: Res<Persistent<app::settings::Settings>>,
+    active_gamepad: Res<app::gamepad::ActiveGamepad>,
+    theme: Res<app::theme::Theme>,
+    locale: Res<app::locale::Locale>,
 ) {
     commands
-        .spawn((build_page_layout(), OnPage))
+        .spawn((build_page_layout(&theme), OnPage))
         .with_children(|parent| {
             parent
                 .spawn(NodeBundle {
@@ -97,7 +125,7 @@ fn page_enter(
                 })
                 .with_children(|parent| {
                     build_game_title(parent, &asset_server);
-                    build_page_title(parent, &asset_server, PAGE_NAME, PAGE_ICON);
+                    build_page_title(parent, &asset_server, PAGE_NAME, PAGE_ICON, &theme, &locale);
                     parent
                         .spawn(NodeBundle {
                             style: Style {
@@ -132,35 +160,61 @@ fn page_enter(
                                             ..default()
                                         })
                                         .with_children(|parent| {
+                                            build_sep_title(
+                                                parent,
+                                                &asset_server,
+                                                "Master",
+                                                "microphone-fill",
+                                                &theme,
+                                                &locale,
+                                            );
+                                            build_slider_bar(
+                                                parent,
+                                                &asset_server,
+                                                ButtonAction::SetValue(String::from("master")),
+                                                settings.get_value("master"),
+                                                &theme,
+                                                &locale,
+                                            );
                                             build_sep_title(
                                                 parent,
                                                 &asset_server,
                                                 "BGM",
                                                 "music-notes-fill",
+                                                &theme,
+                                                &locale,
                                             );
                                             build_switch_btn(
                                                 parent,
                                                 &asset_server,
                                                 ButtonAction::Toggle(String::from("bgm")),
                                                 settings.is_enabled("bgm"),
+                                                &theme,
+                                                &locale,
                                             );
                                             build_slider_bar(
                                                 parent,
                                                 &asset_server,
                                                 ButtonAction::SetValue(String::from("bgm")),
                                                 settings.get_value("bgm"),
+                                                &theme,
+                                                &locale,
                                             );
                                             build_sep_title(
                                                 parent,
                                                 &asset_server,
                                                 "SE",
                                                 "waveform-fill",
+                                                &theme,
+                                                &locale,
                                             );
                                             build_switch_btn(
                                                 parent,
                                                 &asset_server,
                                                 ButtonAction::Toggle(String::from("se")),
                                                 settings.is_enabled("se"),
+                                                &theme,
+                                                &locale,
                                             );
                                             parent
                                                 .spawn(NodeBundle {
@@ -177,6 +231,8 @@ fn page_enter(
                                                         &asset_server,
                                                         ButtonAction::SetValue(String::from("se")),
                                                         settings.get_value("se"),
+                                                        &theme,
+                                                        &locale,
                                                     );
                                                     app::ui::build_icon_btn(
                                                         parent,
@@ -188,8 +244,52 @@ fn page_enter(
                                                         ),
                                                         Style::default(),
                                                         "play-light",
+                                                        &theme,
+                                                        &locale,
                                                     );
                                                 });
+                                            build_sep_title(
+                                                parent,
+                                                &asset_server,
+                                                "UI SE",
+                                                "circles-three-fill",
+                                                &theme,
+                                                &locale,
+                                            );
+                                            build_switch_btn(
+                                                parent,
+                                                &asset_server,
+                                                ButtonAction::Toggle(String::from("ui_se")),
+                                                settings.is_enabled("ui_se"),
+                                                &theme,
+                                                &locale,
+                                            );
+                                            build_slider_bar(
+                                                parent,
+                                                &asset_server,
+                                                ButtonAction::SetValue(String::from("ui_se")),
+                                                settings.get_value("ui_se"),
+                                                &theme,
+                                                &locale,
+                                            );
+                                            build_sep_title(
+                                                parent,
+                                                &asset_server,
+                                                "Mute Unfocused",
+                                                "monitor",
+                                                &theme,
+                                                &locale,
+                                            );
+                                            build_switch_btn(
+                                                parent,
+                                                &asset_server,
+                                                ButtonAction::Toggle(String::from(
+                                                    "mute_unfocused",
+                                                )),
+                                                settings.is_enabled("mute_unfocused"),
+                                                &theme,
+                                                &locale,
+                                            );
                                             #[cfg(not(target_arch = "wasm32"))]
                                             {
                                                 build_sep_title(
@@ -197,6 +297,8 @@ fn page_enter(
                                                     &asset_server,
                                                     "Fullscreen",
                                                     "frame-corners-fill",
+                                                    &theme,
+                                                    &locale,
                                                 );
                                                 build_switch_btn(
                                                     parent,
@@ -205,8 +307,186 @@ fn page_enter(
                                                         "fullscreen",
                                                     )),
                                                     settings.is_enabled("fullscreen"),
+                                                    &theme,
+                                                    &locale,
                                                 );
                                             }
+                                            build_sep_title(
+                                                parent,
+                                                &asset_server,
+                                                "UI Scale",
+                                                "frame-corners",
+                                                &theme,
+                                                &locale,
+                                            );
+                                            build_slider_bar(
+                                                parent,
+                                                &asset_server,
+                                                ButtonAction::SetValue(String::from("ui_scale")),
+                                                settings.get_value("ui_scale"),
+                                                &theme,
+                                                &locale,
+                                            );
+                                            build_sep_title(
+                                                parent,
+                                                &asset_server,
+                                                "Auto Pause",
+                                                "pause-light",
+                                                &theme,
+                                                &locale,
+                                            );
+                                            build_switch_btn(
+                                                parent,
+                                                &asset_server,
+                                                ButtonAction::Toggle(String::from("auto_pause")),
+                                                settings.is_enabled("auto_pause"),
+                                                &theme,
+                                                &locale,
+                                            );
+                                            build_sep_title(
+                                                parent,
+                                                &asset_server,
+                                                "Reduced Motion",
+                                                "shapes-fill",
+                                                &theme,
+                                                &locale,
+                                            );
+                                            build_switch_btn(
+                                                parent,
+                                                &asset_server,
+                                                ButtonAction::Toggle(String::from(
+                                                    "reduced_motion",
+                                                )),
+                                                settings.is_enabled("reduced_motion"),
+                                                &theme,
+                                                &locale,
+                                            );
+                                            build_sep_title(
+                                                parent,
+                                                &asset_server,
+                                                "Camera Shake",
+                                                "waveform-fill",
+                                                &theme,
+                                                &locale,
+                                            );
+                                            build_switch_btn(
+                                                parent,
+                                                &asset_server,
+                                                ButtonAction::Toggle(String::from("camera_shake")),
+                                                settings.is_enabled("camera_shake"),
+                                                &theme,
+                                                &locale,
+                                            );
+                                            build_sep_title(
+                                                parent,
+                                                &asset_server,
+                                                "Hit Stop",
+                                                "pause-light",
+                                                &theme,
+                                                &locale,
+                                            );
+                                            build_switch_btn(
+                                                parent,
+                                                &asset_server,
+                                                ButtonAction::Toggle(String::from("hit_stop")),
+                                                settings.is_enabled("hit_stop"),
+                                                &theme,
+                                                &locale,
+                                            );
+                                            build_sep_title(
+                                                parent,
+                                                &asset_server,
+                                                "Slow Motion",
+                                                "timer-fill",
+                                                &theme,
+                                                &locale,
+                                            );
+                                            build_switch_btn(
+                                                parent,
+                                                &asset_server,
+                                                ButtonAction::Toggle(String::from("slow_motion")),
+                                                settings.is_enabled("slow_motion"),
+                                                &theme,
+                                                &locale,
+                                            );
+                                            build_sep_title(
+                                                parent,
+                                                &asset_server,
+                                                "Danger Alert",
+                                                "crosshair",
+                                                &theme,
+                                                &locale,
+                                            );
+                                            build_switch_btn(
+                                                parent,
+                                                &asset_server,
+                                                ButtonAction::Toggle(String::from(
+                                                    "danger_indicator",
+                                                )),
+                                                settings.is_enabled("danger_indicator"),
+                                                &theme,
+                                                &locale,
+                                            );
+                                            build_sep_title(
+                                                parent,
+                                                &asset_server,
+                                                "Trails",
+                                                "line-segments",
+                                                &theme,
+                                                &locale,
+                                            );
+                                            build_cycle_btn(
+                                                parent,
+                                                &asset_server,
+                                                ButtonAction::Cycle(String::from("trail_quality")),
+                                                "trail_quality",
+                                                settings.get_text("trail_quality", &locale),
+                                                &theme,
+                                                &locale,
+                                            );
+                                            build_sep_title(
+                                                parent,
+                                                &asset_server,
+                                                "Theme",
+                                                "palette-fill",
+                                                &theme,
+                                                &locale,
+                                            );
+                                            build_cycle_btn(
+                                                parent,
+                                                &asset_server,
+                                                ButtonAction::Cycle(String::from("theme")),
+                                                "theme",
+                                                settings.get_text("theme", &locale),
+                                                &theme,
+                                                &locale,
+                                            );
+                                            app::ui::build_theme_swatches(parent, &theme);
+                                            build_sep_title(
+                                                parent,
+                                                &asset_server,
+                                                "Language",
+                                                "globe-light",
+                                                &theme,
+                                                &locale,
+                                            );
+                                            build_cycle_btn(
+                                                parent,
+                                                &asset_server,
+                                                ButtonAction::Cycle(String::from("language")),
+                                                "language",
+                                                settings.get_text("language", &locale),
+                                                &theme,
+                                                &locale,
+                                            );
+                                            build_gamepad_panel(
+                                                parent,
+                                                &asset_server,
+                                                &settings,
+                                                &active_gamepad,
+                                                &theme,
+                                                &locale,
+                                            );
                                         });
                                     parent
                                         .spawn(NodeBundle {
@@ -223,6 +503,8 @@ fn page_enter(
                                                 &asset_server,
                                                 "Sensitivity",
                                                 "gauge-fill",
+                                                &theme,
+                                                &locale,
                                             );
                                             parent
                                                 .spawn(NodeBundle {
@@ -245,12 +527,15 @@ fn page_enter(
                                                         })
                                                         .with_children(|parent| {
                                                             parent.spawn(TextBundle::from_section(
-                                                                "Default",
+                                                                locale.tr("Default"),
                                                                 TextStyle {
-                                                                    font: asset_server
-                                                                        .load(app::ui::FONT),
+                                                                    font: asset_server.load(
+                                                                        locale.fetch_font(
+                                                                            app::ui::FONT,
+                                                                        ),
+                                                                    ),
                                                                     font_size: app::ui::FONT_SIZE,
-                                                                    color: app::ui::FG_COLOR,
+                                                                    color: theme.fg,
                                                                 },
                                                             ));
                                                             build_slider_bar(
@@ -260,6 +545,8 @@ fn page_enter(
                                                                     String::from("sensitivity"),
                                                                 ),
                                                                 settings.get_value("sensitivity"),
+                                                                &theme,
+                                                                &locale,
                                                             );
                                                         });
                                                     parent
@@ -288,12 +575,15 @@ fn page_enter(
                                                                 ..default()
                                                             });
                                                             parent.spawn(TextBundle::from_section(
-                                                                "Shift",
+                                                                locale.tr("Shift"),
                                                                 TextStyle {
-                                                                    font: asset_server
-                                                                        .load(app::ui::FONT),
+                                                                    font: asset_server.load(
+                                                                        locale.fetch_font(
+                                                                            app::ui::FONT,
+                                                                        ),
+                                                                    ),
                                                                     font_size: app::ui::FONT_SIZE,
-                                                                    color: app::ui::FG_COLOR,
+                                                                    color: theme.fg,
                                                                 },
                                                             ));
                                                             build_slider_bar(
@@ -307,11 +597,20 @@ fn page_enter(
                                                                 settings.get_value(
                                                                     "sensitivity_modified",
                                                                 ),
+                                                                &theme,
+                                                                &locale,
                                                             );
                                                         });
                                                 });
-                                            build_move_testing_panel(parent)
+                                            build_move_testing_panel(parent, &theme)
                                         });
+                                    build_controls_panel(
+                                        parent,
+                                        &asset_server,
+                                        &settings,
+                                        &theme,
+                                        &locale,
+                                    );
                                 });
                         });
                     app::ui::build_icon_btn(
@@ -327,6 +626,8 @@ fn page_enter(
                             ..default()
                         },
                         "arrow-left-light",
+                        &theme,
+                        &locale,
                     );
                 });
         });
@@ -342,7 +643,6 @@ fn handle_slider_mouse_interaction(
     mut range_bar_text_query: Query<(Entity, &mut Text), With<RangeBarText>>,
     mut settings: ResMut<Persistent<app::settings::Settings>>,
     mut mouse_motion_events: EventReader<input::mouse::MouseMotion>,
-    audio_bgm_query: Query<&AudioSink, With<app::audio::AudioBgm>>,
 ) {
     for (interaction, action, children) in &range_bar_query {
         if let ButtonAction::SetValue(target) = action {
@@ -358,9 +658,6 @@ fn handle_slider_mouse_interaction(
                         &mut range_bg_bar_query,
                         &mut range_bar_text_query,
                     );
-                    if target == "bgm" {
-                        update_bgm_volume(&mut settings, &audio_bgm_query);
-                    }
                 }
             }
         }
@@ -391,6 +688,8 @@ fn build_switch_btn(
     asset_server: &Res<AssetServer>,
     bundle: impl Bundle,
     init_value: bool,
+    theme: &app::theme::Theme,
+    locale: &app::locale::Locale,
 ) -> Entity {
     parent
         .spawn((
@@ -402,7 +701,7 @@ fn build_switch_btn(
                     padding: UiRect::all(app::ui::px_p(3.0)),
                     ..default()
                 },
-                background_color: app::ui::BG_COLOR.into(),
+                background_color: theme.bg.into(),
                 ..default()
             },
             bundle,
@@ -411,11 +710,11 @@ fn build_switch_btn(
         ))
         .with_children(|parent| {
             parent.spawn(TextBundle::from_section(
-                "ON",
+                locale.tr("ON"),
                 TextStyle {
-                    font: asset_server.load(app::ui::FONT),
+                    font: asset_server.load(locale.fetch_font(app::ui::FONT)),
                     font_size: app::ui::FONT_SIZE,
-                    color: app::ui::FG_COLOR,
+                    color: theme.fg,
                 },
             ));
             let icon = if init_value {
@@ -437,11 +736,11 @@ fn build_switch_btn(
                 SwitchButtonIcon,
             ));
             parent.spawn(TextBundle::from_section(
-                "OFF",
+                locale.tr("OFF"),
                 TextStyle {
-                    font: asset_server.load(app::ui::FONT),
+                    font: asset_server.load(locale.fetch_font(app::ui::FONT)),
                     font_size: app::ui::FONT_SIZE,
-                    color: app::ui::MUTE_COLOR,
+                    color: theme.mute,
                 },
             ));
         })
@@ -456,6 +755,8 @@ fn build_slider_bar(
     asset_server: &Res<AssetServer>,
     bundle: impl Bundle,
     init_value: u8,
+    theme: &app::theme::Theme,
+    locale: &app::locale::Locale,
 ) -> Entity {
     let range_bar_w = calculate_range_bar_width(init_value);
     parent
@@ -468,7 +769,7 @@ fn build_slider_bar(
                     border: UiRect::all(app::ui::px_p(1.0)),
                     ..default()
                 },
-                background_color: app::ui::BG_COLOR.into(),
+                background_color: theme.bg.into(),
                 ..default()
             },
             bundle,
@@ -483,7 +784,7 @@ fn build_slider_bar(
                         height: app::ui::px_p(RANGE_BAR_H),
                         ..default()
                     },
-                    background_color: app::ui::FG_COLOR.into(),
+                    background_color: theme.fg.into(),
                     ..default()
                 },
                 RangeValueBar,
@@ -495,7 +796,7 @@ fn build_slider_bar(
                         height: app::ui::px_p(RANGE_BAR_H),
                         ..default()
                     },
-                    background_color: app::ui::MUTE_COLOR.into(),
+                    background_color: theme.mute.into(),
                     ..default()
                 },
                 RangeBgBar,
@@ -504,9 +805,9 @@ fn build_slider_bar(
                 TextBundle::from_section(
                     format!("{}", init_value),
                     TextStyle {
-                        font: asset_server.load(app::ui::FONT),
+                        font: asset_server.load(locale.fetch_font(app::ui::FONT)),
                         font_size: app::ui::FONT_SIZE,
-                        color: app::ui::FG_COLOR,
+                        color: theme.fg,
                     },
                 )
                 .with_style(Style {
@@ -532,8 +833,10 @@ fn calculate_range_bar_width(value: u8) -> (f32, f32) {
 const MTP_PANEL_SIZE: f32 = 100.0;
 const MTP_BALL_SIZE: f32 = 5.0;
 const MTP_BALL_POS: f32 = (MTP_PANEL_SIZE - MTP_BALL_SIZE) / 2.0;
+const MTP_STICK_SIZE: f32 = 3.0;
+const MTP_STICK_POS: f32 = (MTP_PANEL_SIZE - MTP_STICK_SIZE) / 2.0;
 
-fn build_move_testing_panel(parent: &mut ChildBuilder) {
+fn build_move_testing_panel(parent: &mut ChildBuilder, theme: &app::theme::Theme) {
     parent
         .spawn((
             NodeBundle {
@@ -544,7 +847,7 @@ fn build_move_testing_panel(parent: &mut ChildBuilder) {
                     border: UiRect::all(app::ui::px_p(0.5)),
                     ..default()
                 },
-                border_color: app::ui::SECONDARY_COLOR.into(),
+                border_color: theme.secondary.into(),
                 ..default()
             },
             Interaction::default(),
@@ -561,11 +864,336 @@ fn build_move_testing_panel(parent: &mut ChildBuilder) {
                         left: app::ui::px_p(MTP_BALL_POS),
                         ..default()
                     },
-                    background_color: reactor::U_COLOR.into(),
+                    background_color: theme.u.into(),
                     ..default()
                 },
                 MoveTestBall,
             ));
+            parent.spawn((
+                NodeBundle {
+                    style: Style {
+                        position_type: PositionType::Absolute,
+                        width: app::ui::px_p(MTP_STICK_SIZE),
+                        height: app::ui::px_p(MTP_STICK_SIZE),
+                        top: app::ui::px_p(MTP_STICK_POS),
+                        left: app::ui::px_p(MTP_STICK_POS),
+                        ..default()
+                    },
+                    background_color: theme.ghost.into(),
+                    ..default()
+                },
+                MoveTestStick,
+            ));
+        });
+}
+
+const GAMEPAD_NONE_TEXT: &str = "No Gamepad";
+
+fn build_gamepad_panel(
+    parent: &mut ChildBuilder,
+    asset_server: &Res<AssetServer>,
+    settings: &Res<Persistent<app::settings::Settings>>,
+    active_gamepad: &Res<app::gamepad::ActiveGamepad>,
+    theme: &app::theme::Theme,
+    locale: &app::locale::Locale,
+) {
+    build_sep_title(parent, asset_server, "Gamepad", "wrench", theme, locale);
+    build_cycle_btn(
+        parent,
+        asset_server,
+        ButtonAction::NextGamepad,
+        "gamepad",
+        fetch_gamepad_text(active_gamepad, locale),
+        theme,
+        locale,
+    );
+    parent
+        .spawn(NodeBundle {
+            style: Style {
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::End,
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|parent| {
+            for (field, label) in [("gamepad_inner", "Inner"), ("gamepad_outer", "Outer")] {
+                parent
+                    .spawn(NodeBundle {
+                        style: Style {
+                            align_items: AlignItems::Center,
+                            column_gap: app::ui::px_p(3.0),
+                            ..default()
+                        },
+                        ..default()
+                    })
+                    .with_children(|parent| {
+                        parent.spawn((
+                            TextBundle::from_section(
+                                locale.tr(label),
+                                TextStyle {
+                                    font: asset_server.load(locale.fetch_font(app::ui::FONT)),
+                                    font_size: app::ui::FONT_SIZE,
+                                    color: theme.fg,
+                                },
+                            ),
+                            app::locale::LocalizedText(String::from(label)),
+                        ));
+                        build_slider_bar(
+                            parent,
+                            asset_server,
+                            ButtonAction::SetValue(String::from(field)),
+                            settings.get_value(field),
+                            theme,
+                            locale,
+                        );
+                    });
+            }
+        });
+    parent
+        .spawn(NodeBundle {
+            style: Style {
+                align_items: AlignItems::Center,
+                column_gap: app::ui::px_p(4.0),
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|parent| {
+            for field in ["gamepad_curve", "gamepad_stick"] {
+                build_cycle_btn(
+                    parent,
+                    asset_server,
+                    ButtonAction::Cycle(String::from(field)),
+                    field,
+                    settings.get_text(field, locale),
+                    theme,
+                    locale,
+                );
+            }
+        });
+}
+
+fn build_cycle_btn(
+    parent: &mut ChildBuilder,
+    asset_server: &Res<AssetServer>,
+    bundle: impl Bundle,
+    field: &str,
+    text: String,
+    theme: &app::theme::Theme,
+    locale: &app::locale::Locale,
+) {
+    parent
+        .spawn((
+            ButtonBundle {
+                style: Style {
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    padding: UiRect::all(app::ui::px_p(3.0)),
+                    border: UiRect::all(app::ui::px_p(1.0)),
+                    ..default()
+                },
+                background_color: theme.bg.into(),
+                ..default()
+            },
+            bundle,
+            app::interaction::IaSwitch,
+            Focusable::default(),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                TextBundle::from_section(
+                    locale.tr(&text),
+                    TextStyle {
+                        font: asset_server.load(locale.fetch_font(app::ui::FONT)),
+                        font_size: app::ui::FONT_SIZE,
+                        color: theme.fg,
+                    },
+                ),
+                CycleText(String::from(field)),
+            ));
+        });
+}
+
+fn fetch_gamepad_text(
+    active_gamepad: &app::gamepad::ActiveGamepad,
+    locale: &app::locale::Locale,
+) -> String {
+    if active_gamepad.gamepad.is_some() {
+        active_gamepad.name.clone()
+    } else {
+        locale.tr(GAMEPAD_NONE_TEXT)
+    }
+}
+
+const CONTROL_FS: f32 = app::ui::FONT_SIZE * 0.7;
+const CONTROL_NAME_W: f32 = app::ui::FONT_SIZE * 3.0;
+const CONTROL_BINDING_W: f32 = app::ui::FONT_SIZE * 7.0;
+
+fn build_controls_panel(
+    parent: &mut ChildBuilder,
+    asset_server: &Res<AssetServer>,
+    settings: &Res<Persistent<app::settings::Settings>>,
+    theme: &app::theme::Theme,
+    locale: &app::locale::Locale,
+) {
+    let input_map = settings.fetch_input_map();
+    parent
+        .spawn(NodeBundle {
+            style: Style {
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                margin: UiRect::left(app::ui::px_p(10.0)),
+                row_gap: app::ui::px_p(1.0),
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|parent| {
+            build_sep_title(parent, asset_server, "Controls", "wrench", theme, locale);
+            for action in app::key_binding::GAME_ACTIONS {
+                parent
+                    .spawn(NodeBundle {
+                        style: Style {
+                            align_items: AlignItems::Center,
+                            column_gap: app::ui::px_p(2.0),
+                            ..default()
+                        },
+                        ..default()
+                    })
+                    .with_children(|parent| {
+                        parent.spawn((
+                            TextBundle::from_section(
+                                locale.tr(action.name()),
+                                TextStyle {
+                                    font: asset_server.load(locale.fetch_font(app::ui::FONT)),
+                                    font_size: CONTROL_FS,
+                                    color: theme.fg,
+                                },
+                            )
+                            .with_style(Style {
+                                width: Val::Px(CONTROL_NAME_W),
+                                ..default()
+                            }),
+                            app::locale::LocalizedText(String::from(action.name())),
+                        ));
+                        build_binding_btn(
+                            parent,
+                            asset_server,
+                            ButtonAction::BindKey(action),
+                            BindingText(action, false),
+                            input_map.keys_text(action),
+                            theme,
+                            locale,
+                        );
+                        build_binding_btn(
+                            parent,
+                            asset_server,
+                            ButtonAction::BindButton(action),
+                            BindingText(action, true),
+                            input_map.buttons_text(action),
+                            theme,
+                            locale,
+                        );
+                    });
+            }
+            app::ui::build_btn(
+                parent,
+                asset_server,
+                (
+                    ButtonAction::ResetControls,
+                    app::interaction::IaButton,
+                    Focusable::default(),
+                ),
+                Style {
+                    margin: UiRect::top(app::ui::px_p(3.0)),
+                    padding: UiRect::all(app::ui::px_p(app::ui::BTN_PADDING)),
+                    ..default()
+                },
+                Some("Reset"),
+                Some("arrow-counter-clockwise"),
+                theme,
+                locale,
+            );
+            build_sep_title(parent, asset_server, "Mouse", "crosshair", theme, locale);
+            for (field, label) in [("follow_cursor", "Follow"), ("cursor_grab", "Lock")] {
+                parent
+                    .spawn(NodeBundle {
+                        style: Style {
+                            align_items: AlignItems::Center,
+                            column_gap: app::ui::px_p(3.0),
+                            ..default()
+                        },
+                        ..default()
+                    })
+                    .with_children(|parent| {
+                        parent.spawn((
+                            TextBundle::from_section(
+                                locale.tr(label),
+                                TextStyle {
+                                    font: asset_server.load(locale.fetch_font(app::ui::FONT)),
+                                    font_size: CONTROL_FS,
+                                    color: theme.fg,
+                                },
+                            )
+                            .with_style(Style {
+                                width: Val::Px(CONTROL_NAME_W),
+                                ..default()
+                            }),
+                            app::locale::LocalizedText(String::from(label)),
+                        ));
+                        build_switch_btn(
+                            parent,
+                            asset_server,
+                            ButtonAction::Toggle(String::from(field)),
+                            settings.is_enabled(field),
+                            theme,
+                            locale,
+                        );
+                    });
+            }
+        });
+}
+
+fn build_binding_btn(
+    parent: &mut ChildBuilder,
+    asset_server: &Res<AssetServer>,
+    bundle: impl Bundle,
+    binding_text: BindingText,
+    text: String,
+    theme: &app::theme::Theme,
+    locale: &app::locale::Locale,
+) {
+    parent
+        .spawn((
+            ButtonBundle {
+                style: Style {
+                    width: Val::Px(CONTROL_BINDING_W),
+                    justify_content: JustifyContent::Center,
+                    padding: UiRect::all(app::ui::px_p(1.5)),
+                    border: UiRect::all(app::ui::px_p(0.5)),
+                    ..default()
+                },
+                background_color: theme.bg.into(),
+                border_color: theme.mute.into(),
+                ..default()
+            },
+            bundle,
+            app::interaction::IaPanel,
+            Focusable::default(),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                TextBundle::from_section(
+                    text,
+                    TextStyle {
+                        font: asset_server.load(locale.fetch_font(app::ui::FONT)),
+                        font_size: CONTROL_FS,
+                        color: theme.secondary,
+                    },
+                ),
+                binding_text,
+            ));
         });
 }
 
@@ -581,101 +1209,60 @@ const KEYBOARD_DELTA_BIAS: f32 = 1.5;
 
 fn control_test_ball_by_keyboard(
     keyboard_input: Res<Input<KeyCode>>,
+    gamepad_input: Res<Input<GamepadButton>>,
+    active_gamepad: Res<app::gamepad::ActiveGamepad>,
     panel_query: Query<(&Interaction, &Children), With<MoveTestPanel>>,
     mut ball_query: Query<&mut Style, With<MoveTestBall>>,
     settings: Res<Persistent<app::settings::Settings>>,
     status: Res<reactor::status::ReactorStatus>,
 ) {
-    let mut delta: Vec2 = Vec2::default();
-    if keyboard_input.pressed(KeyCode::W)
-        || keyboard_input.pressed(KeyCode::Up)
-        || keyboard_input.pressed(KeyCode::K)
-    {
-        delta.y = -KEYBOARD_DELTA_BIAS;
-    }
-    if keyboard_input.pressed(KeyCode::S)
-        || keyboard_input.pressed(KeyCode::Down)
-        || keyboard_input.pressed(KeyCode::J)
-    {
-        delta.y = KEYBOARD_DELTA_BIAS;
-    }
-    if keyboard_input.pressed(KeyCode::A)
-        || keyboard_input.pressed(KeyCode::Left)
-        || keyboard_input.pressed(KeyCode::H)
-    {
-        delta.x = -KEYBOARD_DELTA_BIAS;
-    }
-    if keyboard_input.pressed(KeyCode::D)
-        || keyboard_input.pressed(KeyCode::Right)
-        || keyboard_input.pressed(KeyCode::L)
-    {
-        delta.x = KEYBOARD_DELTA_BIAS;
-    }
+    let delta =
+        settings
+            .fetch_input_map()
+            .move_delta(&keyboard_input, &gamepad_input, &active_gamepad)
+            * KEYBOARD_DELTA_BIAS;
     let (_, children) = panel_query.single();
     move_test_ball(delta, children, &mut ball_query, &status, &settings);
 }
 
 const GAEMPAD_DELTA_BIAS: f32 = 2.0;
-const GAEMPAD_MIN_THRESHOLD: f32 = 0.25;
-
 fn control_test_ball_by_gamepad(
-    mut events: EventReader<input::gamepad::GamepadEvent>,
+    active_gamepad: Res<app::gamepad::ActiveGamepad>,
+    axes: Res<Axis<GamepadAxis>>,
     panel_query: Query<(&Interaction, &Children), With<MoveTestPanel>>,
     mut ball_query: Query<&mut Style, With<MoveTestBall>>,
-    mut last_delta: Local<Vec2>,
+    mut stick_query: Query<&mut Style, (With<MoveTestStick>, Without<MoveTestBall>)>,
     settings: Res<Persistent<app::settings::Settings>>,
     status: Res<reactor::status::ReactorStatus>,
 ) {
-    for event in events.read() {
-        dbg!(event);
-        if let input::gamepad::GamepadEvent::Axis(axis_event) = event {
-            let mut delta: Vec2 = Vec2::default();
-            if axis_event.axis_type == input::gamepad::GamepadAxisType::LeftStickX
-                || axis_event.axis_type == input::gamepad::GamepadAxisType::RightStickX
-            {
-                delta.x = GAEMPAD_DELTA_BIAS * axis_event.value;
-                if axis_event.value.abs() > GAEMPAD_MIN_THRESHOLD {
-                    last_delta.x = delta.x;
-                } else {
-                    last_delta.x = 0.0;
-                }
-            }
-            if axis_event.axis_type == input::gamepad::GamepadAxisType::LeftStickY
-                || axis_event.axis_type == input::gamepad::GamepadAxisType::RightStickY
-            {
-                delta.y = GAEMPAD_DELTA_BIAS * axis_event.value * -1.0;
-                if axis_event.value.abs() > GAEMPAD_MIN_THRESHOLD {
-                    last_delta.y = delta.y;
-                } else {
-                    last_delta.y = 0.0;
-                }
-            }
-        }
+    let stick = app::gamepad::read_stick(&active_gamepad, &axes, &settings.fetch_gamepad_tuning());
+    if let Ok(mut stick_style) = stick_query.get_single_mut() {
+        stick_style.left = app::ui::px_p(MTP_STICK_POS * (1.0 + stick.x));
+        stick_style.top = app::ui::px_p(MTP_STICK_POS * (1.0 - stick.y));
     }
     let (_, children) = panel_query.single();
-    move_test_ball(*last_delta, children, &mut ball_query, &status, &settings);
+    let delta = Vec2::new(stick.x, -stick.y) * GAEMPAD_DELTA_BIAS;
+    move_test_ball(delta, children, &mut ball_query, &status, &settings);
 }
 
 fn detect_sensitivity_modification(
     keyboard_input: Res<Input<KeyCode>>,
     mut button_changed_events: EventReader<input::gamepad::GamepadButtonChangedEvent>,
+    settings: Res<Persistent<app::settings::Settings>>,
     mut status: ResMut<reactor::status::ReactorStatus>,
 ) {
+    let input_map = settings.fetch_input_map();
+    let slow_buttons = input_map.buttons(app::key_binding::GameAction::Slow);
     for btn_event in button_changed_events.read() {
-        if btn_event.button_type == input::gamepad::GamepadButtonType::RightTrigger
-            || btn_event.button_type == input::gamepad::GamepadButtonType::LeftTrigger
-        {
+        if slow_buttons.contains(&btn_event.button_type) {
             status.in_modified_sensitivity = btn_event.value == 1.0;
         }
     }
-    if keyboard_input.just_pressed(KeyCode::ShiftLeft)
-        || keyboard_input.just_pressed(KeyCode::ShiftRight)
-    {
+    let slow_keys = input_map.keys(app::key_binding::GameAction::Slow);
+    if keyboard_input.any_just_pressed(slow_keys.clone()) {
         status.in_modified_sensitivity = true;
     }
-    if keyboard_input.just_released(KeyCode::ShiftLeft)
-        || keyboard_input.just_released(KeyCode::ShiftRight)
-    {
+    if keyboard_input.any_just_released(slow_keys) {
         status.in_modified_sensitivity = false;
     }
 }
@@ -719,7 +1306,6 @@ fn handle_slider_navigation(
     mut range_value_bar_query: Query<(Entity, &mut Style), RangeValueBarOnly>,
     mut range_bg_bar_query: Query<(Entity, &mut Style), RangeBgBarOnly>,
     mut range_bar_text_query: Query<(Entity, &mut Text), With<RangeBarText>>,
-    audio_bgm_query: Query<&AudioSink, With<app::audio::AudioBgm>>,
 ) {
     for event in events.read() {
         if let NavEvent::NoChanges { from, request } = event {
@@ -736,9 +1322,6 @@ fn handle_slider_navigation(
                                 &mut range_bg_bar_query,
                                 &mut range_bar_text_query,
                             );
-                            if target == "bgm" {
-                                update_bgm_volume(&mut settings, &audio_bgm_query);
-                            }
                         }
                     }
                 }
@@ -754,9 +1337,6 @@ fn handle_slider_navigation(
                                 &mut range_bg_bar_query,
                                 &mut range_bar_text_query,
                             );
-                            if target == "bgm" {
-                                update_bgm_volume(&mut settings, &audio_bgm_query);
-                            }
                         }
                     }
                 }
@@ -781,7 +1361,22 @@ fn update_slider_display(
             settings.set_value(target, updated_value);
         })
         .expect("failed to update slider");
-    let value = settings.get_value(target);
+    refresh_slider_display(
+        children,
+        settings.get_value(target),
+        range_value_bar_query,
+        range_bg_bar_query,
+        range_bar_text_query,
+    );
+}
+
+fn refresh_slider_display(
+    children: &Children,
+    value: u8,
+    range_value_bar_query: &mut Query<(Entity, &mut Style), RangeValueBarOnly>,
+    range_bg_bar_query: &mut Query<(Entity, &mut Style), RangeBgBarOnly>,
+    range_bar_text_query: &mut Query<(Entity, &mut Text), With<RangeBarText>>,
+) {
     let range_bar_w = calculate_range_bar_width(value);
     for child in children {
         for (bar_entity, mut bar_style) in range_value_bar_query.iter_mut() {
@@ -805,16 +1400,6 @@ fn update_slider_display(
     }
 }
 
-fn update_bgm_volume(
-    settings: &mut ResMut<Persistent<app::settings::Settings>>,
-    audio_bgm_query: &Query<&AudioSink, With<app::audio::AudioBgm>>,
-) {
-    let value = settings.get_value("bgm");
-    if let Ok(sink) = audio_bgm_query.get_single() {
-        sink.set_volume(app::audio::to_volume(value));
-    }
-}
-
 #[allow(clippy::too_many_arguments)]
 fn handle_ui_navigation(
     mut action_query: Query<(&mut ButtonAction, &Children), Without<app::interaction::IaSlider>>,
@@ -827,6 +1412,12 @@ fn handle_ui_navigation(
     asset_server: Res<AssetServer>,
     audio_bgm_query: Query<&AudioSink, With<app::audio::AudioBgm>>,
     audio_se_asset: Res<app::audio::AudioSeAsset>,
+    mut rebinding_target: ResMut<RebindingTarget>,
+    mut key_binding: ResMut<app::key_binding::KeyBindingConfig>,
+    mut binding_text_query: Query<(&BindingText, &mut Text)>,
+    gamepads: Res<Gamepads>,
+    active_gamepad: Res<app::gamepad::ActiveGamepad>,
+    locale: Res<app::locale::Locale>,
 ) {
     events.nav_iter().activated_in_query_foreach_mut(
         &mut action_query,
@@ -882,8 +1473,204 @@ fn handle_ui_navigation(
                     settings.as_ref(),
                 );
             }
+            ButtonAction::BindKey(action) => {
+                rebinding_target.0 = Some((*action, false));
+                key_binding.mode = app::key_binding::KeyBindingMode::Rebinding;
+                update_binding_texts(
+                    &mut binding_text_query,
+                    &settings,
+                    &rebinding_target,
+                    &locale,
+                );
+            }
+            ButtonAction::BindButton(action) => {
+                rebinding_target.0 = Some((*action, true));
+                key_binding.mode = app::key_binding::KeyBindingMode::Rebinding;
+                update_binding_texts(
+                    &mut binding_text_query,
+                    &settings,
+                    &rebinding_target,
+                    &locale,
+                );
+            }
+            ButtonAction::ResetControls => {
+                settings
+                    .update(|settings| {
+                        settings.reset_input_map();
+                    })
+                    .expect("failed to reset controls");
+                update_binding_texts(
+                    &mut binding_text_query,
+                    &settings,
+                    &rebinding_target,
+                    &locale,
+                );
+            }
+            ButtonAction::Cycle(target) => {
+                settings
+                    .update(|settings| {
+                        settings.cycle(target);
+                    })
+                    .expect("failed to cycle setting");
+            }
+            ButtonAction::NextGamepad => {
+                if let Some(name) = app::gamepad::next_gamepad(&gamepads, &active_gamepad) {
+                    settings
+                        .update(|settings| {
+                            settings.select_gamepad(&name);
+                        })
+                        .expect("failed to update active gamepad");
+                }
+            }
             ButtonAction::BackToMainMenu => page_state.set(PageState::Menu),
             _ => (),
         },
     );
 }
+
+const REBINDING_TEXT: &str = "Press ...";
+const CONFLICT_TEXT: &str = "Used by {action}";
+
+#[allow(clippy::too_many_arguments)]
+fn handle_rebinding(
+    mut keyboard_input: ResMut<Input<KeyCode>>,
+    mut gamepad_input: ResMut<Input<GamepadButton>>,
+    active_gamepad: Res<app::gamepad::ActiveGamepad>,
+    mut settings: ResMut<Persistent<app::settings::Settings>>,
+    mut rebinding_target: ResMut<RebindingTarget>,
+    mut key_binding: ResMut<app::key_binding::KeyBindingConfig>,
+    mut binding_text_query: Query<(&BindingText, &mut Text)>,
+    locale: Res<app::locale::Locale>,
+) {
+    let Some((action, is_button)) = rebinding_target.0 else {
+        return;
+    };
+    // NOTE: Escape and East cancel the rebinding, so they can't be rebound here.
+    // The pressed input is consumed, so the navigation doesn't act on it as well.
+    if is_button {
+        let Some(button) = gamepad_input
+            .get_just_pressed()
+            .find(|button| Some(button.gamepad) == active_gamepad.gamepad)
+            .copied()
+        else {
+            return;
+        };
+        gamepad_input.clear_just_pressed(button);
+        if button.button_type != GamepadButtonType::East {
+            if let Some(other) = settings
+                .fetch_input_map()
+                .button_conflict(action, button.button_type)
+            {
+                show_conflict_text(&mut binding_text_query, action, is_button, other, &locale);
+                return;
+            }
+            settings
+                .update(|settings| {
+                    settings.bind_button(action, button.button_type);
+                })
+                .expect("failed to bind gamepad button");
+        }
+    } else {
+        let Some(key) = keyboard_input.get_just_pressed().next().copied() else {
+            return;
+        };
+        keyboard_input.clear_just_pressed(key);
+        if key != KeyCode::Escape {
+            if let Some(other) = settings.fetch_input_map().key_conflict(action, key) {
+                show_conflict_text(&mut binding_text_query, action, is_button, other, &locale);
+                return;
+            }
+            settings
+                .update(|settings| {
+                    settings.bind_key(action, key);
+                })
+                .expect("failed to bind key");
+        }
+    }
+    rebinding_target.0 = None;
+    key_binding.mode = app::key_binding::KeyBindingMode::Navgation;
+    update_binding_texts(
+        &mut binding_text_query,
+        &settings,
+        &rebinding_target,
+        &locale,
+    );
+}
+
+fn show_conflict_text(
+    binding_text_query: &mut Query<(&BindingText, &mut Text)>,
+    action: app::key_binding::GameAction,
+    is_button: bool,
+    other: app::key_binding::GameAction,
+    locale: &app::locale::Locale,
+) {
+    for (binding_text, mut text) in binding_text_query.iter_mut() {
+        if binding_text.0 == action && binding_text.1 == is_button {
+            text.sections[0].value = locale
+                .tr(CONFLICT_TEXT)
+                .replace("{action}", &locale.tr(other.name()));
+        }
+    }
+}
+
+fn update_binding_texts(
+    binding_text_query: &mut Query<(&BindingText, &mut Text)>,
+    settings: &ResMut<Persistent<app::settings::Settings>>,
+    rebinding_target: &ResMut<RebindingTarget>,
+    locale: &app::locale::Locale,
+) {
+    let input_map = settings.fetch_input_map();
+    for (binding_text, mut text) in binding_text_query.iter_mut() {
+        text.sections[0].value = if rebinding_target.0 == Some((binding_text.0, binding_text.1)) {
+            locale.tr(REBINDING_TEXT)
+        } else if binding_text.1 {
+            input_map.buttons_text(binding_text.0)
+        } else {
+            input_map.keys_text(binding_text.0)
+        };
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn refresh_gamepad_display(
+    settings: Res<Persistent<app::settings::Settings>>,
+    active_gamepad: Res<app::gamepad::ActiveGamepad>,
+    slider_query: Query<(&ButtonAction, &Children), With<app::interaction::IaSlider>>,
+    mut range_value_bar_query: Query<(Entity, &mut Style), RangeValueBarOnly>,
+    mut range_bg_bar_query: Query<(Entity, &mut Style), RangeBgBarOnly>,
+    mut range_bar_text_query: Query<(Entity, &mut Text), With<RangeBarText>>,
+    mut cycle_text_query: Query<(&CycleText, &mut Text), Without<RangeBarText>>,
+    locale: Res<app::locale::Locale>,
+) {
+    if !settings.is_changed() && !active_gamepad.is_changed() {
+        return;
+    }
+    for (action, children) in slider_query.iter() {
+        if let ButtonAction::SetValue(target) = action {
+            if target.starts_with("gamepad_") {
+                refresh_slider_display(
+                    children,
+                    settings.get_value(target),
+                    &mut range_value_bar_query,
+                    &mut range_bg_bar_query,
+                    &mut range_bar_text_query,
+                );
+            }
+        }
+    }
+    for (cycle_text, mut text) in cycle_text_query.iter_mut() {
+        text.sections[0].value = if cycle_text.0 == "gamepad" {
+            fetch_gamepad_text(&active_gamepad, &locale)
+        } else {
+            locale.tr(&settings.get_text(&cycle_text.0, &locale))
+        };
+    }
+}
+
+fn page_exit(
+    mut rebinding_target: ResMut<RebindingTarget>,
+    mut key_binding: ResMut<app::key_binding::KeyBindingConfig>,
+) {
+    rebinding_target.0 = None;
+    key_binding.mode = app::key_binding::KeyBindingMode::Navgation;
+}
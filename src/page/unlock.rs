@@ -0,0 +1,356 @@
+use crate::{app, page::*, reactor::particle::uou};
+use bevy_persistent::prelude::*;
+use bevy_ui_navigation::{prelude::*, NavRequestSystem};
+
+pub const PAGE_CODE: &str = "unlock";
+pub const PAGE_NAME: &str = "Items";
+pub const PAGE_ICON: &str = "palette-fill";
+
+pub struct PageDef;
+
+impl PageDefBase for PageDef {
+    fn code(&self) -> &str {
+        PAGE_CODE
+    }
+    fn name(&self) -> &str {
+        PAGE_NAME
+    }
+    fn icon(&self) -> &str {
+        PAGE_ICON
+    }
+    fn state(&self) -> PageState {
+        PageState::Unlock
+    }
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            OnEnter(self.state()),
+            (app::unlock::check_unlocks, page_enter).chain(),
+        )
+        .add_systems(
+            Update,
+            handle_ui_navigation
+                .after(NavRequestSystem)
+                .run_if(in_state(self.state())),
+        )
+        .add_systems(
+            OnExit(self.state()),
+            (app::ui::despawn_ui::<OnPage>, page_exit),
+        );
+    }
+}
+
+#[derive(Component)]
+struct OnPage;
+
+#[derive(Component)]
+enum ButtonAction {
+    Select(String),
+    BackToMainMenu,
+}
+
+#[derive(Component)]
+struct UnlockPanelIcon(String);
+
+fn page_enter(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    unlock_store: Res<Persistent<app::unlock::UnlockStore>>,
+    ach_defs: Res<app::achievement::AchievementDefs>,
+    theme: Res<app::theme::Theme>,
+    locale: Res<app::locale::Locale>,
+) {
+    commands
+        .spawn((build_page_layout(&theme), OnPage))
+        .with_children(|parent| {
+            parent
+                .spawn(NodeBundle {
+                    style: Style {
+                        width: Val::Percent(100.0),
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        justify_content: JustifyContent::SpaceBetween,
+                        ..default()
+                    },
+                    ..default()
+                })
+                .with_children(|parent| {
+                    build_game_title(parent, &asset_server);
+                    build_page_title(parent, &asset_server, PAGE_NAME, PAGE_ICON, &theme, &locale);
+                    parent
+                        .spawn(NodeBundle {
+                            style: Style {
+                                width: Val::Percent(100.0),
+                                flex_grow: 1.0,
+                                flex_direction: FlexDirection::Column,
+                                align_items: AlignItems::Center,
+                                justify_content: JustifyContent::Center,
+                                row_gap: app::ui::px_p(4.0),
+                                ..default()
+                            },
+                            ..default()
+                        })
+                        .with_children(|parent| {
+                            for slot in app::unlock::UNLOCK_SLOTS {
+                                build_sep_title(
+                                    parent,
+                                    &asset_server,
+                                    app::unlock::fetch_slot_name(slot),
+                                    app::unlock::fetch_slot_icon(slot),
+                                    &theme,
+                                    &locale,
+                                );
+                                parent
+                                    .spawn(NodeBundle {
+                                        style: Style {
+                                            justify_content: JustifyContent::Center,
+                                            column_gap: app::ui::px_p(2.0),
+                                            ..default()
+                                        },
+                                        background_color: theme.bg.into(),
+                                        ..default()
+                                    })
+                                    .with_children(|parent| {
+                                        for unlock_def in app::unlock::UNLOCKS.iter() {
+                                            if unlock_def.slot() == slot {
+                                                build_item_panel(
+                                                    parent,
+                                                    &asset_server,
+                                                    unlock_def,
+                                                    &unlock_store,
+                                                    &ach_defs,
+                                                    &theme,
+                                                    &locale,
+                                                );
+                                            }
+                                        }
+                                    });
+                            }
+                        });
+                });
+            app::ui::build_icon_btn(
+                parent,
+                &asset_server,
+                (
+                    ButtonAction::BackToMainMenu,
+                    app::interaction::IaButton,
+                    Focusable::new().prioritized(),
+                ),
+                Style {
+                    position_type: PositionType::Absolute,
+                    bottom: app::ui::px_p(app::ui::PAGE_PADDING),
+                    left: app::ui::px_p(app::ui::PAGE_PADDING),
+                    ..default()
+                },
+                "arrow-left-light",
+                &theme,
+                &locale,
+            );
+        });
+}
+
+fn page_exit(mut unlock_store: ResMut<Persistent<app::unlock::UnlockStore>>) {
+    if unlock_store.pending_codes().is_empty() {
+        return;
+    }
+    unlock_store
+        .update(|unlock_store| {
+            unlock_store.clear_pending();
+        })
+        .expect("failed to update unlocks");
+}
+
+#[allow(clippy::too_many_arguments)]
+fn handle_ui_navigation(
+    mut actions: Query<&mut ButtonAction>,
+    mut events: EventReader<NavEvent>,
+    mut page_state: ResMut<NextState<PageState>>,
+    mut unlock_store: ResMut<Persistent<app::unlock::UnlockStore>>,
+    mut panel_icon_query: Query<(&UnlockPanelIcon, &mut Visibility), With<UnlockPanelIcon>>,
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    settings: Res<Persistent<app::settings::Settings>>,
+    audio_bgm_query: Query<Entity, With<app::audio::AudioBgm>>,
+) {
+    events.nav_iter().activated_in_query_foreach_mut(
+        &mut actions,
+        |mut action| match &mut *action {
+            ButtonAction::BackToMainMenu => page_state.set(PageState::Menu),
+            ButtonAction::Select(code) => {
+                let bgm_path = unlock_store.bgm_path();
+                unlock_store
+                    .update(|unlock_store| {
+                        unlock_store.select(code);
+                    })
+                    .expect("failed to update unlocks");
+                for (panel_icon, mut visibility) in panel_icon_query.iter_mut() {
+                    *visibility = if unlock_store.is_selected(panel_icon.0.as_str()) {
+                        Visibility::Inherited
+                    } else {
+                        Visibility::Hidden
+                    };
+                }
+                if unlock_store.bgm_path() != bgm_path {
+                    app::audio::switch_bgm(
+                        &mut commands,
+                        &asset_server,
+                        &settings,
+                        &audio_bgm_query,
+                        unlock_store.bgm_path(),
+                    );
+                }
+            }
+        },
+    );
+}
+
+const ITEM_PANEL_W: f32 = app::ui::FONT_SIZE * 7.0;
+const ITEM_PREVIEW_SIZE: f32 = app::ui::FONT_SIZE * 1.2;
+const ITEM_STATUS_SIZE: f32 = app::ui::FONT_SIZE * 0.8;
+const ITEM_NAME_FS: f32 = app::ui::FONT_SIZE * 0.9;
+const ITEM_DESC_FS: f32 = app::ui::FONT_SIZE * 0.6;
+
+fn build_item_panel(
+    parent: &mut ChildBuilder,
+    asset_server: &Res<AssetServer>,
+    unlock_def: &app::unlock::UnlockDef,
+    unlock_store: &Res<Persistent<app::unlock::UnlockStore>>,
+    ach_defs: &Res<app::achievement::AchievementDefs>,
+    theme: &app::theme::Theme,
+    locale: &app::locale::Locale,
+) {
+    let is_unlocked = unlock_store.is_unlocked(unlock_def.code);
+    let mut entity = parent.spawn((ButtonBundle {
+        style: Style {
+            width: Val::Px(ITEM_PANEL_W),
+            align_items: AlignItems::Center,
+            padding: UiRect::all(app::ui::px_p(2.0)),
+            border: UiRect::all(app::ui::px_p(1.0)),
+            column_gap: app::ui::px_p(3.0),
+            ..default()
+        },
+        background_color: theme.bg.into(),
+        border_color: theme.mute.into(),
+        ..default()
+    },));
+    entity.with_children(|parent| {
+        build_item_preview(parent, asset_server, unlock_def, is_unlocked, theme);
+        parent
+            .spawn(NodeBundle {
+                style: Style {
+                    flex_grow: 1.0,
+                    flex_direction: FlexDirection::Column,
+                    padding: UiRect::right(Val::Px(ITEM_STATUS_SIZE)),
+                    ..default()
+                },
+                ..default()
+            })
+            .with_children(|parent| {
+                let name_color = if is_unlocked { theme.fg } else { theme.mute };
+                parent.spawn(TextBundle::from_section(
+                    locale.tr(unlock_def.name),
+                    TextStyle {
+                        font: asset_server.load(locale.fetch_font(app::ui::FONT)),
+                        font_size: ITEM_NAME_FS,
+                        color: name_color,
+                    },
+                ));
+                let desc = if !is_unlocked {
+                    unlock_def.requirement_text(ach_defs, locale)
+                } else if unlock_store.is_pending(unlock_def.code) {
+                    locale.tr("New!")
+                } else {
+                    String::from("")
+                };
+                parent.spawn(TextBundle::from_section(
+                    desc,
+                    TextStyle {
+                        font: asset_server.load(locale.fetch_font(app::ui::FONT)),
+                        font_size: ITEM_DESC_FS,
+                        color: theme.secondary,
+                    },
+                ));
+            });
+        if is_unlocked {
+            let visibility = if unlock_store.is_selected(unlock_def.code) {
+                Visibility::Inherited
+            } else {
+                Visibility::Hidden
+            };
+            parent.spawn((
+                ImageBundle {
+                    style: Style {
+                        position_type: PositionType::Absolute,
+                        top: Val::Px(0.0),
+                        right: Val::Px(0.0),
+                        width: Val::Px(ITEM_STATUS_SIZE),
+                        height: Val::Px(ITEM_STATUS_SIZE),
+                        margin: UiRect::all(app::ui::px_p(1.0)),
+                        ..default()
+                    },
+                    image: UiImage::new(asset_server.load("images/icons/ach-check-circle.png")),
+                    visibility,
+                    ..default()
+                },
+                UnlockPanelIcon(String::from(unlock_def.code)),
+            ));
+        }
+    });
+    if is_unlocked {
+        entity.insert((
+            ButtonAction::Select(String::from(unlock_def.code)),
+            app::interaction::IaPanel,
+            Focusable::default(),
+        ));
+    }
+}
+
+pub fn build_item_preview(
+    parent: &mut ChildBuilder,
+    asset_server: &Res<AssetServer>,
+    unlock_def: &app::unlock::UnlockDef,
+    is_unlocked: bool,
+    theme: &app::theme::Theme,
+) {
+    let color = if is_unlocked { theme.fg } else { theme.mute };
+    let style = Style {
+        width: Val::Px(ITEM_PREVIEW_SIZE),
+        height: Val::Px(ITEM_PREVIEW_SIZE),
+        ..default()
+    };
+    match unlock_def.value {
+        app::unlock::UnlockValue::UouColor(uou_color) => {
+            let preview_color = if is_unlocked {
+                uou_color.unwrap_or(theme.u)
+            } else {
+                theme.mute
+            };
+            parent.spawn(NodeBundle {
+                style,
+                background_color: preview_color.into(),
+                ..default()
+            });
+        }
+        app::unlock::UnlockValue::UouShape(shape) => {
+            let (icon, rotation) = match shape {
+                uou::UouShape::Circle => ("circle", 0.0),
+                uou::UouShape::Square => ("square", 0.0),
+                uou::UouShape::Diamond => ("square", std::f32::consts::FRAC_PI_4),
+            };
+            parent.spawn(ImageBundle {
+                style,
+                image: UiImage::new(asset_server.load(format!("images/icons/{}.png", icon))),
+                background_color: color.into(),
+                transform: Transform::from_rotation(Quat::from_rotation_z(rotation)),
+                ..default()
+            });
+        }
+        app::unlock::UnlockValue::Bgm(_) => {
+            parent.spawn(ImageBundle {
+                style,
+                image: UiImage::new(asset_server.load("images/icons/music-notes-fill.png")),
+                background_color: color.into(),
+                ..default()
+            });
+        }
+    }
+}
@@ -2,12 +2,17 @@ use crate::app;
 use bevy::prelude::*;
 
 pub mod anime_effect;
+pub mod camera;
+pub mod danger;
+pub mod dash;
 pub mod field;
 pub mod field_ach;
 pub mod hit;
+pub mod motion;
 pub mod particle;
 pub mod state;
 pub mod status;
+pub mod tail;
 
 #[derive(Clone, Copy, Default, Eq, PartialEq, Debug, Hash, States)]
 pub enum ReactorState {
@@ -48,7 +53,21 @@ impl Plugin for ReactorPlugin {
             )))
             .insert_resource(ScoreTimer(Timer::from_seconds(1.0, TimerMode::Repeating)))
             .insert_resource(AnimeTimer(Timer::from_seconds(0.5, TimerMode::Once)))
+            .add_systems(
+                Update,
+                (
+                    particle::redraw_particle_shapes,
+                    anime_effect::redraw_anime_effects,
+                    anime_effect::build_anime_effect_labels,
+                    anime_effect::redraw_anime_effect_labels,
+                ),
+            )
             .add_plugins((
+                motion::MotionPlugin,
+                camera::CameraPlugin,
+                danger::DangerPlugin,
+                dash::DashPlugin,
+                tail::TailPlugin,
                 state::demo::StatePlugin,
                 state::ready::StatePlugin,
                 state::running::StatePlugin,
@@ -66,13 +85,17 @@ pub struct ControlParticle;
 pub struct RunningParticle;
 
 pub const U_SIZE: f32 = app::ui::SPACE_SIZE * 3.0;
-pub const U_COLOR: Color = Color::rgb(1.0, 0.84, 0.2);
 
 pub const FIELD_ACH_H: f32 = 120.0;
 pub const FIELD_NAV_H: f32 = 80.0;
 pub const FIELD_W: f32 = app::WINDOW_W;
 pub const FIELD_H: f32 = app::WINDOW_H - FIELD_ACH_H - FIELD_NAV_H;
 
-pub fn startup(commands: &mut Commands, asset_server: &Res<AssetServer>) {
-    field::build_reactor_field(commands, asset_server);
+pub fn startup(
+    commands: &mut Commands,
+    asset_server: &Res<AssetServer>,
+    theme: &app::theme::Theme,
+    locale: &app::locale::Locale,
+) {
+    field::build_reactor_field(commands, asset_server, theme, locale);
 }
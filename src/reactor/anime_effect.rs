@@ -1,4 +1,8 @@
-use crate::reactor;
+use crate::app;
+use crate::reactor::{
+    self,
+    particle::{build_retained_shape, RetainedShapeItem, ShapeCanvas},
+};
 use bevy::prelude::*;
 use bevy_prototype_lyon::prelude::*;
 use bevy_tweening::*;
@@ -24,6 +28,9 @@ pub struct AnimeEffectParam {
 pub enum AnimeEffectKind {
     Explosion,
     Bullet,
+    Score { score: u32, level: u8 },
+    ChainLink(Vec<Vec2>),
+    Graze,
 }
 
 #[derive(PartialEq)]
@@ -32,6 +39,28 @@ pub enum AnimeEffectShape {
     Square,
     Hexagon,
     Triangle,
+    Ring,
+    Dart,
+}
+
+impl AnimeEffectShape {
+    pub fn color(&self, theme: &app::theme::Theme) -> Color {
+        match self {
+            AnimeEffectShape::Circle => theme.alpha,
+            AnimeEffectShape::Square => theme.control,
+            AnimeEffectShape::Hexagon => theme.hyper,
+            AnimeEffectShape::Triangle => theme.trigger,
+            AnimeEffectShape::Ring => theme.well,
+            AnimeEffectShape::Dart => theme.hunter,
+        }
+    }
+}
+
+#[derive(Component, Clone, Copy, PartialEq, Debug)]
+pub enum AnimeEffectPart {
+    Trail,
+    Head,
+    Label,
 }
 
 #[derive(Component)]
@@ -49,20 +78,127 @@ pub struct AnimeEffect {
 }
 
 impl AnimeEffect {
-    fn kind_builder(&self) -> &dyn AnimeEffectKindBase {
+    fn kind_builder(&self) -> Box<dyn AnimeEffectKindBase> {
         kind::fetch_builder(self.kind.clone())
     }
 }
 
 pub const ANIME_EFFECT_DONE_EVENT: u64 = 4;
+const LABEL_FONT_SIZE: f32 = app::ui::FONT_SIZE * 0.5;
+const LABEL_Z: f32 = 10.0;
 const AE_ROTATION_DELTA: f32 = PI / 40.0;
 
-pub fn insert_anime_effect(commands: &mut Commands, param: AnimeEffectParam) {
-    kind::fetch_builder(param.kind.clone()).create(commands, param);
+pub fn insert_anime_effect(
+    commands: &mut Commands,
+    param: AnimeEffectParam,
+    theme: &app::theme::Theme,
+) {
+    kind::fetch_builder(param.kind.clone()).create(commands, param, theme);
 }
 
-pub fn update_anime_effect(commands: &mut Commands, ae: &AnimeEffect) {
-    ae.kind_builder().draw(commands, ae);
+pub fn redraw_anime_effects(
+    ae_query: Query<Ref<AnimeEffect>>,
+    mut part_query: Query<RetainedShapeItem<AnimeEffectPart>, Without<AnimeEffect>>,
+    motion: Res<reactor::motion::MotionPolicy>,
+) {
+    for (part, parent, path, fill, stroke, transform, visibility) in part_query.iter_mut() {
+        let Ok(ae) = ae_query.get(parent.get()) else {
+            continue;
+        };
+        if !(ae.is_changed() || part.is_added() || motion.is_changed()) {
+            continue;
+        }
+        let mut canvas = ShapeCanvas {
+            path,
+            fill,
+            stroke,
+            transform,
+            visibility,
+        };
+        ae.kind_builder().draw(&ae, *part, &mut canvas, &motion);
+    }
+}
+
+pub fn build_anime_effect_labels(
+    mut commands: Commands,
+    ae_query: Query<(Entity, &AnimeEffect), Added<AnimeEffect>>,
+    asset_server: Res<AssetServer>,
+) {
+    for (entity, ae) in ae_query.iter() {
+        let Some(label) = ae.kind_builder().label(ae) else {
+            continue;
+        };
+        commands.entity(entity).with_children(|parent| {
+            parent.spawn((
+                Text2dBundle {
+                    text: Text::from_section(
+                        label,
+                        TextStyle {
+                            font: asset_server.load(app::ui::FONT_DIGIT),
+                            font_size: LABEL_FONT_SIZE,
+                            color: ae.color,
+                        },
+                    ),
+                    transform: Transform::from_translation(ae.current_pos.extend(LABEL_Z)),
+                    ..default()
+                },
+                AnimeEffectPart::Label,
+            ));
+        });
+    }
+}
+
+pub fn redraw_anime_effect_labels(
+    ae_query: Query<Ref<AnimeEffect>>,
+    mut label_query: Query<(&Parent, &mut Text, &mut Transform), With<AnimeEffectPart>>,
+) {
+    for (parent, mut text, mut transform) in label_query.iter_mut() {
+        let Ok(ae) = ae_query.get(parent.get()) else {
+            continue;
+        };
+        if !ae.is_changed() {
+            continue;
+        }
+        transform.translation = ae.current_pos.extend(LABEL_Z);
+        for section in text.sections.iter_mut() {
+            section.style.color = ae.color;
+        }
+    }
+}
+
+fn build_shape_path(shape: &AnimeEffectShape, radius: f32) -> Path {
+    match shape {
+        AnimeEffectShape::Circle | AnimeEffectShape::Ring => {
+            GeometryBuilder::build_as(&shapes::Circle {
+                radius,
+                center: Vec2::new(0.0, 0.0),
+            })
+        }
+        AnimeEffectShape::Square => GeometryBuilder::build_as(&shapes::RegularPolygon {
+            sides: 4,
+            feature: shapes::RegularPolygonFeature::Radius(radius * 2.0_f32.sqrt()),
+            ..shapes::RegularPolygon::default()
+        }),
+        AnimeEffectShape::Hexagon => GeometryBuilder::build_as(&shapes::RegularPolygon {
+            sides: 6,
+            feature: shapes::RegularPolygonFeature::Radius(radius),
+            ..shapes::RegularPolygon::default()
+        }),
+        AnimeEffectShape::Triangle => GeometryBuilder::build_as(&shapes::RegularPolygon {
+            sides: 3,
+            feature: shapes::RegularPolygonFeature::Radius(radius),
+            ..shapes::RegularPolygon::default()
+        }),
+        AnimeEffectShape::Dart => {
+            let mut path_builder = PathBuilder::new();
+            path_builder.move_to(Vec2::new(0.0, radius * 1.4));
+            path_builder.line_to(Vec2::new(radius * 0.8, -radius));
+            path_builder.line_to(Vec2::new(0.0, -radius * 0.4));
+            path_builder.line_to(Vec2::new(-radius * 0.8, -radius));
+            path_builder.close();
+            path_builder.build()
+        }
+    }
 }
 
 pub fn clear_anime_effect(mut commands: Commands, ae_query: Query<Entity, With<AnimeEffect>>) {
@@ -1,16 +1,34 @@
 use crate::reactor::anime_effect::*;
 
 pub mod bullet;
+pub mod chain_link;
 pub mod explosion;
+pub mod graze;
+pub mod score;
 
 pub trait AnimeEffectKindBase {
-    fn create(&self, commands: &mut Commands, param: AnimeEffectParam);
-    fn draw(&self, commands: &mut Commands, ae: &AnimeEffect);
+    fn create(&self, commands: &mut Commands, param: AnimeEffectParam, theme: &app::theme::Theme);
+    fn draw(
+        &self,
+        _ae: &AnimeEffect,
+        _part: AnimeEffectPart,
+        _canvas: &mut ShapeCanvas,
+        _motion: &reactor::motion::MotionPolicy,
+    ) {
+    }
+    fn label(&self, _ae: &AnimeEffect) -> Option<String> {
+        None
+    }
 }
 
-pub fn fetch_builder(kind: AnimeEffectKind) -> &'static dyn AnimeEffectKindBase {
+pub fn fetch_builder(kind: AnimeEffectKind) -> Box<dyn AnimeEffectKindBase> {
     match kind {
-        AnimeEffectKind::Explosion => &kind::explosion::AnimeEffectKindExplosion,
-        AnimeEffectKind::Bullet => &kind::bullet::AnimeEffectKindBullet,
+        AnimeEffectKind::Explosion => Box::new(kind::explosion::AnimeEffectKindExplosion),
+        AnimeEffectKind::Bullet => Box::new(kind::bullet::AnimeEffectKindBullet),
+        AnimeEffectKind::Score { score, level } => {
+            Box::new(kind::score::AnimeEffectKindScore { score, level })
+        }
+        AnimeEffectKind::ChainLink(_) => Box::new(kind::chain_link::AnimeEffectKindChainLink),
+        AnimeEffectKind::Graze => Box::new(kind::graze::AnimeEffectKindGraze),
     }
 }
@@ -5,19 +5,23 @@ pub struct AnimeEffectKindBullet;
 const BULLET_RADIUS: f32 = 6.0;
 
 impl AnimeEffectKindBase for AnimeEffectKindBullet {
-    fn create(&self, commands: &mut Commands, param: AnimeEffectParam) {
-        let color = match param.shape {
-            AnimeEffectShape::Circle => reactor::particle::alpha::COLOR,
-            AnimeEffectShape::Square => reactor::particle::control::COLOR,
-            AnimeEffectShape::Hexagon => reactor::particle::hyper::COLOR,
-            AnimeEffectShape::Triangle => reactor::particle::trigger::COLOR,
-        };
+    fn create(&self, commands: &mut Commands, param: AnimeEffectParam, theme: &app::theme::Theme) {
+        let color = param.shape.color(theme);
         let root_entity = commands
             .spawn((SpriteBundle {
                 transform: Transform::from_xyz(0.0, 0.0, 0.0),
                 sprite: Sprite { color, ..default() },
                 ..default()
             },))
+            .with_children(|parent| {
+                build_retained_shape(
+                    parent,
+                    AnimeEffectPart::Trail,
+                    0.0,
+                    Stroke::new(color, BULLET_RADIUS),
+                );
+                build_retained_shape(parent, AnimeEffectPart::Head, -10.0, Fill::color(color));
+            })
             .id();
         let ae = AnimeEffect {
             kind: param.kind,
@@ -47,92 +51,27 @@ impl AnimeEffectKindBase for AnimeEffectKindBullet {
             .insert((ae, Animator::new(tween)));
     }
 
-    fn draw(&self, commands: &mut Commands, ae: &AnimeEffect) {
-        if let Some(mut entity_commands) = commands.get_entity(ae.root_entity) {
-            entity_commands.despawn_descendants();
-            entity_commands.with_children(|parent| {
+    fn draw(
+        &self,
+        ae: &AnimeEffect,
+        part: AnimeEffectPart,
+        canvas: &mut ShapeCanvas,
+        motion: &reactor::motion::MotionPolicy,
+    ) {
+        let color = motion.flash_color(ae.color);
+        match part {
+            AnimeEffectPart::Trail => {
                 let mut path_builder = PathBuilder::new();
                 path_builder.move_to(ae.start_pos);
                 path_builder.line_to(ae.current_pos);
-                parent.spawn((
-                    ShapeBundle {
-                        path: path_builder.build(),
-                        ..default()
-                    },
-                    Stroke::new(ae.color, BULLET_RADIUS),
-                ));
-                parent
-                    .spawn(SpriteBundle {
-                        transform: Transform::from_rotation(Quat::from_rotation_z(ae.rotation))
-                            .with_translation(Vec3::new(ae.current_pos.x, ae.current_pos.y, -10.0)),
-                        sprite: Sprite {
-                            color: ae.color,
-                            ..default()
-                        },
-                        ..default()
-                    })
-                    .with_children(|parent| {
-                        match ae.shape {
-                            AnimeEffectShape::Circle => {
-                                let shape = shapes::Circle {
-                                    radius: ae.radius,
-                                    center: Vec2::new(0.0, 0.0),
-                                };
-                                parent.spawn((
-                                    ShapeBundle {
-                                        path: GeometryBuilder::build_as(&shape),
-                                        ..default()
-                                    },
-                                    Fill::color(ae.color),
-                                ));
-                            }
-                            AnimeEffectShape::Square => {
-                                let shape = shapes::RegularPolygon {
-                                    sides: 4,
-                                    feature: shapes::RegularPolygonFeature::Radius(
-                                        ae.radius * 2.0_f32.sqrt(),
-                                    ),
-                                    ..shapes::RegularPolygon::default()
-                                };
-                                parent.spawn((
-                                    ShapeBundle {
-                                        path: GeometryBuilder::build_as(&shape),
-                                        ..default()
-                                    },
-                                    Fill::color(ae.color),
-                                ));
-                            }
-                            AnimeEffectShape::Hexagon => {
-                                let shape = shapes::RegularPolygon {
-                                    sides: 6,
-                                    feature: shapes::RegularPolygonFeature::Radius(ae.radius),
-                                    ..shapes::RegularPolygon::default()
-                                };
-                                parent.spawn((
-                                    ShapeBundle {
-                                        path: GeometryBuilder::build_as(&shape),
-                                        ..default()
-                                    },
-                                    Fill::color(ae.color),
-                                ));
-                            }
-                            AnimeEffectShape::Triangle => {
-                                let shape = shapes::RegularPolygon {
-                                    sides: 3,
-                                    feature: shapes::RegularPolygonFeature::Radius(ae.radius),
-                                    ..shapes::RegularPolygon::default()
-                                };
-                                parent.spawn((
-                                    ShapeBundle {
-                                        path: GeometryBuilder::build_as(&shape),
-                                        ..default()
-                                    },
-                                    Fill::color(ae.color),
-                                ));
-                            }
-                        };
-                    });
-            });
+                canvas.draw(path_builder.build(), color);
+            }
+            AnimeEffectPart::Head => {
+                *canvas.transform = Transform::from_rotation(Quat::from_rotation_z(ae.rotation))
+                    .with_translation(Vec3::new(ae.current_pos.x, ae.current_pos.y, -10.0));
+                canvas.draw(build_shape_path(&ae.shape, ae.radius), color);
+            }
+            AnimeEffectPart::Label => (),
         }
     }
 }
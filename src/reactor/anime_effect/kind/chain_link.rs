@@ -0,0 +1,73 @@
+use crate::reactor::anime_effect::kind::*;
+
+pub struct AnimeEffectKindChainLink;
+
+const CHAIN_LINK_BORDER: f32 = 3.0;
+const CHAIN_LINK_ALPHA: f32 = 0.5;
+
+impl AnimeEffectKindBase for AnimeEffectKindChainLink {
+    fn create(&self, commands: &mut Commands, param: AnimeEffectParam, theme: &app::theme::Theme) {
+        let color = param.shape.color(theme);
+        let root_entity = commands
+            .spawn(SpatialBundle::default())
+            .with_children(|parent| {
+                build_retained_shape(
+                    parent,
+                    AnimeEffectPart::Trail,
+                    -10.0,
+                    Stroke::new(color, CHAIN_LINK_BORDER),
+                );
+            })
+            .id();
+        let ae = AnimeEffect {
+            kind: param.kind,
+            shape: param.shape,
+            start_pos: param.start_pos,
+            current_pos: param.start_pos,
+            color,
+            radius: 0.0,
+            rotation: 0.0,
+            rotation_delta: 0.0,
+            border: CHAIN_LINK_BORDER,
+            root_entity,
+        };
+        let tween = Tween::new(
+            EaseFunction::QuadraticIn,
+            Duration::from_millis(700),
+            lens::AnimeEffectLens::new(
+                (0.0, 0.0),
+                (CHAIN_LINK_ALPHA, 0.0),
+                (CHAIN_LINK_BORDER, 0.5),
+                (param.start_pos, param.end_pos),
+            ),
+        )
+        .with_completed_event(ANIME_EFFECT_DONE_EVENT);
+        commands
+            .entity(root_entity)
+            .insert((ae, Animator::new(tween)));
+    }
+
+    fn draw(
+        &self,
+        ae: &AnimeEffect,
+        part: AnimeEffectPart,
+        canvas: &mut ShapeCanvas,
+        motion: &reactor::motion::MotionPolicy,
+    ) {
+        let AnimeEffectKind::ChainLink(points) = &ae.kind else {
+            return;
+        };
+        if part != AnimeEffectPart::Trail || points.len() < 2 {
+            return;
+        }
+        let mut path_builder = PathBuilder::new();
+        path_builder.move_to(points[0]);
+        for point in points.iter().skip(1) {
+            path_builder.line_to(*point);
+        }
+        if let Some(stroke) = canvas.stroke.as_mut() {
+            stroke.options.line_width = ae.border;
+        }
+        canvas.draw(path_builder.build(), motion.flash_color(ae.color));
+    }
+}
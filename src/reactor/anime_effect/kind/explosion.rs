@@ -3,26 +3,31 @@ use crate::reactor::anime_effect::kind::*;
 pub struct AnimeEffectKindExplosion;
 
 const EXPLOSION_BORDER: f32 = 3.0;
+const EXPLOSION_ALPHA: f32 = 0.1;
+const EXPLOSION_STEADY_RADIUS: f32 = 24.0;
 
 impl AnimeEffectKindBase for AnimeEffectKindExplosion {
-    fn create(&self, commands: &mut Commands, param: AnimeEffectParam) {
-        let color = match param.shape {
-            AnimeEffectShape::Circle => reactor::particle::alpha::COLOR,
-            AnimeEffectShape::Square => reactor::particle::control::COLOR,
-            AnimeEffectShape::Hexagon => reactor::particle::hyper::COLOR,
-            AnimeEffectShape::Triangle => reactor::particle::trigger::COLOR,
-        };
+    fn create(&self, commands: &mut Commands, param: AnimeEffectParam, theme: &app::theme::Theme) {
+        let color = param.shape.color(theme);
         let root_entity = commands
             .spawn((SpriteBundle {
                 transform: Transform::from_xyz(0.0, 0.0, 0.0),
                 sprite: Sprite { color, ..default() },
                 ..default()
             },))
+            .with_children(|parent| {
+                build_retained_shape(
+                    parent,
+                    AnimeEffectPart::Head,
+                    -10.0,
+                    Stroke::new(color, EXPLOSION_BORDER),
+                );
+            })
             .id();
         let mut rng = thread_rng();
         let init_rotation = rng.gen_range(0.0..2.0 * std::f32::consts::PI);
         let rotation_delta = if rng.gen_range(-1.0..1.0) < 0.0 {
-            AE_ROTATION_DELTA * -1.0
+            -AE_ROTATION_DELTA
         } else {
             AE_ROTATION_DELTA
         };
@@ -43,7 +48,7 @@ impl AnimeEffectKindBase for AnimeEffectKindExplosion {
             Duration::from_millis(2500),
             lens::AnimeEffectLens::new(
                 (0.0, WINDOW_W),
-                (0.1, 0.0),
+                (EXPLOSION_ALPHA, 0.0),
                 (EXPLOSION_BORDER * 2.0, EXPLOSION_BORDER),
                 (param.start_pos, param.end_pos),
             ),
@@ -54,82 +59,32 @@ impl AnimeEffectKindBase for AnimeEffectKindExplosion {
             .insert((ae, Animator::new(tween)));
     }
 
-    fn draw(&self, commands: &mut Commands, ae: &AnimeEffect) {
-        if let Some(mut entity_commands) = commands.get_entity(ae.root_entity) {
-            entity_commands.despawn_descendants();
-            entity_commands.with_children(|parent| {
-                parent
-                    .spawn(SpriteBundle {
-                        transform: Transform::from_rotation(Quat::from_rotation_z(ae.rotation))
-                            .with_translation(Vec3::new(ae.current_pos.x, ae.current_pos.y, -10.0)),
-                        sprite: Sprite {
-                            color: ae.color,
-                            ..default()
-                        },
-                        ..default()
-                    })
-                    .with_children(|parent| {
-                        match ae.shape {
-                            AnimeEffectShape::Circle => {
-                                let shape = shapes::Circle {
-                                    radius: ae.radius,
-                                    center: Vec2::new(0.0, 0.0),
-                                };
-                                parent.spawn((
-                                    ShapeBundle {
-                                        path: GeometryBuilder::build_as(&shape),
-                                        ..default()
-                                    },
-                                    Stroke::new(ae.color, ae.border),
-                                ));
-                            }
-                            AnimeEffectShape::Square => {
-                                let shape = shapes::RegularPolygon {
-                                    sides: 4,
-                                    feature: shapes::RegularPolygonFeature::Radius(
-                                        ae.radius * 2.0_f32.sqrt(),
-                                    ),
-                                    ..shapes::RegularPolygon::default()
-                                };
-                                parent.spawn((
-                                    ShapeBundle {
-                                        path: GeometryBuilder::build_as(&shape),
-                                        ..default()
-                                    },
-                                    Stroke::new(ae.color, ae.border),
-                                ));
-                            }
-                            AnimeEffectShape::Hexagon => {
-                                let shape = shapes::RegularPolygon {
-                                    sides: 6,
-                                    feature: shapes::RegularPolygonFeature::Radius(ae.radius),
-                                    ..shapes::RegularPolygon::default()
-                                };
-                                parent.spawn((
-                                    ShapeBundle {
-                                        path: GeometryBuilder::build_as(&shape),
-                                        ..default()
-                                    },
-                                    Stroke::new(ae.color, ae.border),
-                                ));
-                            }
-                            AnimeEffectShape::Triangle => {
-                                let shape = shapes::RegularPolygon {
-                                    sides: 3,
-                                    feature: shapes::RegularPolygonFeature::Radius(ae.radius),
-                                    ..shapes::RegularPolygon::default()
-                                };
-                                parent.spawn((
-                                    ShapeBundle {
-                                        path: GeometryBuilder::build_as(&shape),
-                                        ..default()
-                                    },
-                                    Stroke::new(ae.color, ae.border),
-                                ));
-                            }
-                        };
-                    });
-            });
+    fn draw(
+        &self,
+        ae: &AnimeEffect,
+        part: AnimeEffectPart,
+        canvas: &mut ShapeCanvas,
+        motion: &reactor::motion::MotionPolicy,
+    ) {
+        if part != AnimeEffectPart::Head {
+            return;
+        }
+        // NOTE: reduced motion swaps the expanding ring for a static outline fading out
+        let (radius, color) = if motion.reduced {
+            (
+                EXPLOSION_STEADY_RADIUS,
+                ae.color
+                    .with_a(ae.color.a() / EXPLOSION_ALPHA * reactor::motion::MAX_FLASH_ALPHA),
+            )
+        } else {
+            (ae.radius, ae.color)
+        };
+        let rotation = motion.rotation(ae.rotation);
+        *canvas.transform = Transform::from_rotation(Quat::from_rotation_z(rotation))
+            .with_translation(Vec3::new(ae.current_pos.x, ae.current_pos.y, -10.0));
+        if let Some(stroke) = canvas.stroke.as_mut() {
+            stroke.options.line_width = ae.border;
         }
+        canvas.draw(build_shape_path(&ae.shape, radius), color);
     }
 }
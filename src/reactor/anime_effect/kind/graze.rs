@@ -0,0 +1,72 @@
+use crate::reactor::anime_effect::kind::*;
+
+pub struct AnimeEffectKindGraze;
+
+const GRAZE_BORDER: f32 = 2.0;
+const GRAZE_ALPHA: f32 = 0.8;
+
+impl AnimeEffectKindBase for AnimeEffectKindGraze {
+    fn create(&self, commands: &mut Commands, param: AnimeEffectParam, theme: &app::theme::Theme) {
+        let color = theme.u;
+        let root_entity = commands
+            .spawn(SpatialBundle::default())
+            .with_children(|parent| {
+                build_retained_shape(
+                    parent,
+                    AnimeEffectPart::Head,
+                    -10.0,
+                    Stroke::new(color, GRAZE_BORDER),
+                );
+            })
+            .id();
+        let radius = reactor::particle::uou::RADIUS;
+        let ae = AnimeEffect {
+            kind: param.kind,
+            shape: param.shape,
+            start_pos: param.start_pos,
+            current_pos: param.start_pos,
+            color,
+            radius,
+            rotation: 0.0,
+            rotation_delta: 0.0,
+            border: GRAZE_BORDER,
+            root_entity,
+        };
+        let tween = Tween::new(
+            EaseFunction::QuadraticOut,
+            Duration::from_millis(300),
+            lens::AnimeEffectLens::new(
+                (radius, radius + reactor::hit::GRAZE_RADIUS),
+                (GRAZE_ALPHA, 0.0),
+                (GRAZE_BORDER, GRAZE_BORDER * 0.5),
+                (param.start_pos, param.end_pos),
+            ),
+        )
+        .with_completed_event(ANIME_EFFECT_DONE_EVENT);
+        commands
+            .entity(root_entity)
+            .insert((ae, Animator::new(tween)));
+    }
+
+    fn draw(
+        &self,
+        ae: &AnimeEffect,
+        part: AnimeEffectPart,
+        canvas: &mut ShapeCanvas,
+        motion: &reactor::motion::MotionPolicy,
+    ) {
+        if part != AnimeEffectPart::Head {
+            return;
+        }
+        let radius = motion.steady_radius(ae.radius, reactor::particle::uou::RADIUS);
+        *canvas.transform =
+            Transform::from_translation(Vec3::new(ae.current_pos.x, ae.current_pos.y, -10.0));
+        if let Some(stroke) = canvas.stroke.as_mut() {
+            stroke.options.line_width = ae.border;
+        }
+        canvas.draw(
+            build_shape_path(&ae.shape, radius),
+            motion.flash_color(ae.color),
+        );
+    }
+}
@@ -0,0 +1,63 @@
+use crate::reactor::anime_effect::kind::*;
+
+pub struct AnimeEffectKindScore {
+    pub score: u32,
+    pub level: u8,
+}
+
+const SCORE_RISE: f32 = 30.0;
+
+fn level_color(shape: &AnimeEffectShape, level: u8, theme: &app::theme::Theme) -> Color {
+    let max_level = match shape {
+        AnimeEffectShape::Hexagon => reactor::particle::hyper::MAX_LEVEL,
+        _ => reactor::particle::control::MAX_LEVEL,
+    };
+    let ratio = (level as f32 / max_level as f32).clamp(0.0, 1.0);
+    let from = theme.fg.as_rgba_f32();
+    let to = theme.u.as_rgba_f32();
+    Color::rgb(
+        from[0] + (to[0] - from[0]) * ratio,
+        from[1] + (to[1] - from[1]) * ratio,
+        from[2] + (to[2] - from[2]) * ratio,
+    )
+}
+
+impl AnimeEffectKindBase for AnimeEffectKindScore {
+    fn create(&self, commands: &mut Commands, param: AnimeEffectParam, theme: &app::theme::Theme) {
+        let color = level_color(&param.shape, self.level, theme);
+        let root_entity = commands.spawn(SpatialBundle::default()).id();
+        let ae = AnimeEffect {
+            kind: param.kind,
+            shape: param.shape,
+            start_pos: param.start_pos,
+            current_pos: param.start_pos,
+            color,
+            radius: 0.0,
+            rotation: 0.0,
+            rotation_delta: 0.0,
+            border: 0.0,
+            root_entity,
+        };
+        let tween = Tween::new(
+            EaseFunction::QuadraticOut,
+            Duration::from_millis(900),
+            lens::AnimeEffectLens::new(
+                (0.0, 0.0),
+                (1.0, 0.0),
+                (0.0, 0.0),
+                (
+                    param.start_pos,
+                    param.start_pos + Vec2::new(0.0, SCORE_RISE),
+                ),
+            ),
+        )
+        .with_completed_event(ANIME_EFFECT_DONE_EVENT);
+        commands
+            .entity(root_entity)
+            .insert((ae, Animator::new(tween)));
+    }
+
+    fn label(&self, _ae: &AnimeEffect) -> Option<String> {
+        Some(format!("+{}", self.score))
+    }
+}
@@ -0,0 +1,136 @@
+use crate::{app, reactor};
+use bevy::prelude::*;
+use rand::{thread_rng, Rng};
+
+const HIT_STOP_SECS: f32 = 0.08;
+const HIT_STOP_MIN_RELEASE: u32 = 3;
+const SHAKE_DECAY: f32 = 2.5;
+const SHAKE_MAX_OFFSET: f32 = 12.0;
+const SHAKE_PER_RELEASE: f32 = 0.08;
+const DEATH_SECS: f32 = 0.5;
+const DEATH_TIME_SPEED: f32 = 0.25;
+const DEATH_ZOOM: f32 = 0.6;
+
+// NOTE: game-feel effects run on real time, game time is what they slow down
+#[derive(Resource, Default)]
+pub struct CameraFeel {
+    hit_stop: Option<Timer>,
+    trauma: f32,
+    death: Option<(Vec2, Timer)>,
+}
+
+impl CameraFeel {
+    pub fn release(&mut self, settings: &app::settings::Settings, count: u32) {
+        if count > HIT_STOP_MIN_RELEASE && settings.is_enabled("hit_stop") {
+            self.hit_stop = Some(Timer::from_seconds(HIT_STOP_SECS, TimerMode::Once));
+        }
+        if count > 1 && settings.is_enabled("camera_shake") {
+            self.trauma = (self.trauma + count as f32 * SHAKE_PER_RELEASE).min(1.0);
+        }
+    }
+    // NOTE: returns false when the death should be handled right away
+    pub fn start_death(&mut self, settings: &app::settings::Settings, pos: Vec2) -> bool {
+        if !settings.is_enabled("slow_motion") {
+            return false;
+        }
+        if self.death.is_none() {
+            self.death = Some((pos, Timer::from_seconds(DEATH_SECS, TimerMode::Once)));
+        }
+        true
+    }
+    pub fn is_dying(&self) -> bool {
+        self.death.is_some()
+    }
+}
+
+// NOTE: the run is over once the death starts, only the camera and particles keep moving
+pub fn is_alive(feel: Res<CameraFeel>) -> bool {
+    !feel.is_dying()
+}
+
+pub struct CameraPlugin;
+
+impl Plugin for CameraPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CameraFeel>()
+            .add_systems(
+                Update,
+                update_camera_feel.run_if(in_state(reactor::ReactorState::Running)),
+            )
+            .add_systems(OnExit(reactor::ReactorState::Running), restore_camera)
+            .add_systems(OnEnter(reactor::ReactorState::Ready), reset_camera_feel);
+    }
+}
+
+fn update_camera_feel(
+    mut feel: ResMut<CameraFeel>,
+    mut camera_query: Query<(&mut Transform, &mut OrthographicProjection), With<Camera2d>>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+    real_time: Res<Time<Real>>,
+    motion: Res<reactor::motion::MotionPolicy>,
+    mut reactor_state: ResMut<NextState<reactor::ReactorState>>,
+) {
+    let delta = real_time.delta();
+    let Ok((mut transform, mut projection)) = camera_query.get_single_mut() else {
+        return;
+    };
+    let mut speed = 1.0;
+    let mut focus = Vec2::ZERO;
+    let mut scale = 1.0;
+    if let Some(timer) = feel.hit_stop.as_mut() {
+        if timer.tick(delta).finished() {
+            feel.hit_stop = None;
+        } else {
+            speed = 0.0;
+        }
+    }
+    if let Some((pos, timer)) = feel.death.as_mut() {
+        timer.tick(delta);
+        if timer.finished() {
+            reactor_state.set(reactor::ReactorState::Submit);
+        }
+        speed = DEATH_TIME_SPEED;
+        if !motion.reduced {
+            let ratio = timer.percent();
+            focus = *pos * ratio;
+            scale = 1.0 - (1.0 - DEATH_ZOOM) * ratio;
+        }
+    }
+    let mut offset = Vec2::ZERO;
+    if feel.trauma > 0.0 {
+        if !motion.reduced {
+            let mut rng = thread_rng();
+            let strength = feel.trauma * feel.trauma * SHAKE_MAX_OFFSET;
+            offset = Vec2::new(
+                rng.gen_range(-1.0..1.0) * strength,
+                rng.gen_range(-1.0..1.0) * strength,
+            );
+        }
+        feel.trauma = (feel.trauma - SHAKE_DECAY * delta.as_secs_f32()).max(0.0);
+    }
+    if virtual_time.relative_speed() != speed {
+        virtual_time.set_relative_speed(speed);
+    }
+    transform.translation.x = focus.x + offset.x;
+    transform.translation.y = focus.y + offset.y;
+    if projection.scale != scale {
+        projection.scale = scale;
+    }
+}
+
+fn reset_camera_feel(mut feel: ResMut<CameraFeel>) {
+    *feel = CameraFeel::default();
+}
+
+// NOTE: leaving for a pause keeps a pending death, it resumes when running again
+fn restore_camera(
+    mut camera_query: Query<(&mut Transform, &mut OrthographicProjection), With<Camera2d>>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+) {
+    virtual_time.set_relative_speed(1.0);
+    if let Ok((mut transform, mut projection)) = camera_query.get_single_mut() {
+        transform.translation.x = 0.0;
+        transform.translation.y = 0.0;
+        projection.scale = 1.0;
+    }
+}
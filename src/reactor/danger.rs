@@ -0,0 +1,275 @@
+use crate::{
+    app, reactor,
+    reactor::particle::{uou, Particle, ParticleState, ParticleType},
+};
+use bevy::prelude::*;
+use bevy_persistent::prelude::*;
+use bevy_prototype_lyon::prelude::*;
+use std::f32::consts::PI;
+
+// NOTE: measured in reactor ticks, alphas move by their velocity once per tick
+const DANGER_TICKS: f32 = 60.0;
+const DANGER_ARC_COUNT: usize = 4;
+const DANGER_ARC_GAP: f32 = 10.0;
+const DANGER_ARC_SPAN: f32 = PI / 4.0;
+const DANGER_ARC_SEGMENTS: usize = 8;
+const DANGER_ARC_WIDTH: f32 = 3.0;
+const DANGER_ARC_Z: f32 = 5.0;
+const TONE_MIN_SECS: f32 = 0.12;
+const TONE_MAX_SECS: f32 = 0.6;
+const TONE_MAX_PITCH: f32 = 2.0;
+
+struct DangerThreat {
+    direction: Vec2,
+    urgency: f32,
+}
+
+#[derive(Resource, Default)]
+pub struct DangerState {
+    u_pos: Vec2,
+    threats: Vec<DangerThreat>,
+}
+
+impl DangerState {
+    fn max_urgency(&self) -> Option<f32> {
+        self.threats.first().map(|threat| threat.urgency)
+    }
+}
+
+#[derive(Component)]
+struct DangerArc(usize);
+
+pub struct DangerPlugin;
+
+impl Plugin for DangerPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DangerState>()
+            .add_systems(Startup, build_danger_arcs)
+            .add_systems(
+                Update,
+                (detect_danger, play_danger_tone)
+                    .chain()
+                    .run_if(in_state(reactor::ReactorState::Running)),
+            )
+            .add_systems(Update, redraw_danger_arcs)
+            .add_systems(OnExit(reactor::ReactorState::Running), clear_danger);
+    }
+}
+
+pub fn time_to_collision(alpha: &Particle, u: &Particle) -> Option<f32> {
+    let offset = alpha.pos() - u.pos();
+    let v = alpha.v();
+    let reach = alpha.radius + u.radius;
+    let a = v.dot(v);
+    let b = 2.0 * offset.dot(v);
+    let c = offset.dot(offset) - reach * reach;
+    if c <= 0.0 {
+        return Some(0.0);
+    }
+    let discriminant = b * b - 4.0 * a * c;
+    if a == 0.0 || discriminant < 0.0 {
+        return None;
+    }
+    let t = (-b - discriminant.sqrt()) / (2.0 * a);
+    if t >= 0.0 {
+        Some(t)
+    } else {
+        None
+    }
+}
+
+fn build_danger_arcs(mut commands: Commands) {
+    for index in 0..DANGER_ARC_COUNT {
+        commands.spawn((
+            ShapeBundle {
+                spatial: SpatialBundle {
+                    transform: Transform::from_xyz(0.0, 0.0, DANGER_ARC_Z),
+                    visibility: Visibility::Hidden,
+                    ..default()
+                },
+                ..default()
+            },
+            Stroke {
+                options: StrokeOptions::default()
+                    .with_end_cap(LineCap::Round)
+                    .with_start_cap(LineCap::Round)
+                    .with_line_width(DANGER_ARC_WIDTH),
+                color: Color::NONE,
+            },
+            DangerArc(index),
+        ));
+    }
+}
+
+fn detect_danger(
+    settings: Res<Persistent<app::settings::Settings>>,
+    particle_query: Query<&Particle>,
+    mut danger: ResMut<DangerState>,
+) {
+    if !settings.is_enabled("danger_indicator") {
+        if !danger.threats.is_empty() {
+            danger.threats.clear();
+        }
+        return;
+    }
+    let Some(u) = particle_query
+        .iter()
+        .find(|p| p.particle_type() == ParticleType::Uou)
+    else {
+        return;
+    };
+    let mut threats: Vec<(f32, DangerThreat)> = particle_query
+        .iter()
+        .filter(|p| {
+            matches!(
+                p.particle_type(),
+                ParticleType::Alpha | ParticleType::Hunter
+            )
+        })
+        .filter(|p| p.state == ParticleState::Running)
+        .filter_map(|p| {
+            let ttc = time_to_collision(p, u).filter(|ttc| *ttc < DANGER_TICKS)?;
+            Some((
+                ttc,
+                DangerThreat {
+                    direction: (p.pos() - u.pos()).normalize_or_zero(),
+                    urgency: 1.0 - ttc / DANGER_TICKS,
+                },
+            ))
+        })
+        .collect();
+    threats.sort_by(|(t1, _), (t2, _)| t1.total_cmp(t2));
+    danger.u_pos = u.pos();
+    danger.threats = threats
+        .into_iter()
+        .take(DANGER_ARC_COUNT)
+        .map(|(_, threat)| threat)
+        .collect();
+}
+
+// NOTE: the tone repeats faster and higher as the closest threat gets nearer
+fn play_danger_tone(
+    mut commands: Commands,
+    danger: Res<DangerState>,
+    settings: Res<Persistent<app::settings::Settings>>,
+    audio_se_asset: Res<app::audio::AudioSeAsset>,
+    time: Res<Time<Real>>,
+    mut cooldown: Local<f32>,
+) {
+    *cooldown = (*cooldown - time.delta_seconds()).max(0.0);
+    let Some(urgency) = danger.max_urgency() else {
+        return;
+    };
+    if *cooldown > 0.0 {
+        return;
+    }
+    app::audio::play_se_with_speed(
+        app::audio::AudioSe::Warning,
+        1.0 + (TONE_MAX_PITCH - 1.0) * urgency,
+        &mut commands,
+        &audio_se_asset,
+        &settings,
+    );
+    *cooldown = TONE_MAX_SECS - (TONE_MAX_SECS - TONE_MIN_SECS) * urgency;
+}
+
+fn redraw_danger_arcs(
+    danger: Res<DangerState>,
+    mut arc_query: Query<(&DangerArc, &mut Path, &mut Stroke, &mut Visibility)>,
+    theme: Res<app::theme::Theme>,
+) {
+    if !danger.is_changed() {
+        return;
+    }
+    let color = theme.trigger;
+    let arc_radius = uou::RADIUS + DANGER_ARC_GAP;
+    for (arc, mut path, mut stroke, mut visibility) in arc_query.iter_mut() {
+        let Some(threat) = danger.threats.get(arc.0) else {
+            visibility.set_if_neq(Visibility::Hidden);
+            continue;
+        };
+        let angle = threat.direction.y.atan2(threat.direction.x);
+        let mut path_builder = PathBuilder::new();
+        for i in 0..=DANGER_ARC_SEGMENTS {
+            let arc_angle = angle - DANGER_ARC_SPAN * 0.5
+                + DANGER_ARC_SPAN * i as f32 / DANGER_ARC_SEGMENTS as f32;
+            let point = danger.u_pos + Vec2::new(arc_angle.cos(), arc_angle.sin()) * arc_radius;
+            if i == 0 {
+                path_builder.move_to(point);
+            } else {
+                path_builder.line_to(point);
+            }
+        }
+        *path = path_builder.build();
+        stroke.color = color.with_a(0.3 + 0.7 * threat.urgency);
+        visibility.set_if_neq(Visibility::Inherited);
+    }
+}
+
+fn clear_danger(mut danger: ResMut<DangerState>) {
+    danger.threats.clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::theme::{Theme, ThemePreset};
+
+    fn gen_particle(particle_type: ParticleType, pos: Vec2, v: Vec2) -> Particle {
+        let theme = Theme::from_preset(ThemePreset::Default);
+        let mut particle = Particle::create(
+            particle_type,
+            pos,
+            None,
+            None,
+            Entity::PLACEHOLDER,
+            Entity::PLACEHOLDER,
+            &theme,
+        );
+        particle.set_v(v);
+        particle
+    }
+
+    #[test]
+    fn measures_a_head_on_approach() {
+        let u = gen_particle(ParticleType::Uou, Vec2::ZERO, Vec2::ZERO);
+        let alpha = gen_particle(
+            ParticleType::Alpha,
+            Vec2::new(100.0, 0.0),
+            Vec2::new(-2.0, 0.0),
+        );
+        let reach = alpha.radius + u.radius;
+        let time = time_to_collision(&alpha, &u).unwrap();
+        assert!((time - (100.0 - reach) / 2.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn reports_an_overlap_as_immediate() {
+        let u = gen_particle(ParticleType::Uou, Vec2::ZERO, Vec2::ZERO);
+        let alpha = gen_particle(
+            ParticleType::Alpha,
+            Vec2::new(1.0, 0.0),
+            Vec2::new(1.0, 0.0),
+        );
+        assert_eq!(time_to_collision(&alpha, &u), Some(0.0));
+    }
+
+    #[test]
+    fn ignores_alphas_that_never_reach_u() {
+        let u = gen_particle(ParticleType::Uou, Vec2::ZERO, Vec2::ZERO);
+        let leaving = gen_particle(
+            ParticleType::Alpha,
+            Vec2::new(100.0, 0.0),
+            Vec2::new(2.0, 0.0),
+        );
+        assert_eq!(time_to_collision(&leaving, &u), None);
+        let passing = gen_particle(
+            ParticleType::Alpha,
+            Vec2::new(100.0, 50.0),
+            Vec2::new(-2.0, 0.0),
+        );
+        assert_eq!(time_to_collision(&passing, &u), None);
+        let still = gen_particle(ParticleType::Alpha, Vec2::new(100.0, 0.0), Vec2::ZERO);
+        assert_eq!(time_to_collision(&still, &u), None);
+    }
+}
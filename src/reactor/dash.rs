@@ -0,0 +1,166 @@
+use crate::{
+    app,
+    reactor::{
+        self, field,
+        particle::{uou, Particle},
+        status,
+    },
+};
+use bevy::prelude::*;
+use bevy_persistent::prelude::*;
+use bevy_prototype_lyon::prelude::*;
+use std::f32::consts::{PI, TAU};
+
+const DASH_COOLDOWN_SECS: f32 = 3.0;
+const DASH_DISTANCE: f32 = 96.0;
+const DASH_SECS: f32 = 0.12;
+const DASH_INVULNERABLE_SECS: f32 = 0.25;
+const DASH_RING_GAP: f32 = 5.0;
+const DASH_RING_WIDTH: f32 = 2.0;
+const DASH_RING_SEGMENTS: usize = 32;
+const DASH_RING_Z: f32 = 4.0;
+
+// NOTE: timings are kept in game seconds, so hit-stop and slow motion stretch them too
+#[derive(Resource, Default)]
+pub struct DashState {
+    u_pos: Option<Vec2>,
+    direction: Vec2,
+    burst_left: f32,
+    invulnerable_left: f32,
+    cooldown_left: f32,
+}
+
+impl DashState {
+    pub fn is_invulnerable(&self) -> bool {
+        self.invulnerable_left > 0.0
+    }
+    pub fn is_ready(&self) -> bool {
+        self.cooldown_left == 0.0
+    }
+    pub fn cooldown_left(&self) -> f32 {
+        self.cooldown_left
+    }
+}
+
+#[derive(Component)]
+struct DashRing;
+
+pub struct DashPlugin;
+
+impl Plugin for DashPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DashState>()
+            .add_systems(Startup, build_dash_ring)
+            .add_systems(
+                Update,
+                update_dash
+                    .run_if(in_state(reactor::ReactorState::Running))
+                    .run_if(reactor::camera::is_alive),
+            )
+            .add_systems(Update, redraw_dash_ring)
+            .add_systems(OnEnter(reactor::ReactorState::Ready), reset_dash)
+            .add_systems(OnEnter(reactor::ReactorState::Demo), reset_dash)
+            .add_systems(OnEnter(reactor::ReactorState::Submit), reset_dash)
+            .add_systems(OnEnter(reactor::ReactorState::Ended), reset_dash);
+    }
+}
+
+fn build_dash_ring(mut commands: Commands) {
+    commands.spawn((
+        ShapeBundle {
+            spatial: SpatialBundle {
+                transform: Transform::from_xyz(0.0, 0.0, DASH_RING_Z),
+                visibility: Visibility::Hidden,
+                ..default()
+            },
+            ..default()
+        },
+        Stroke::new(Color::NONE, DASH_RING_WIDTH),
+        DashRing,
+    ));
+}
+
+#[allow(clippy::too_many_arguments)]
+fn update_dash(
+    keyboard_input: Res<Input<KeyCode>>,
+    gamepad_input: Res<Input<GamepadButton>>,
+    active_gamepad: Res<app::gamepad::ActiveGamepad>,
+    settings: Res<Persistent<app::settings::Settings>>,
+    mut u_particle_query: Query<(&mut Particle, &mut Transform), With<reactor::ControlParticle>>,
+    mut dash: ResMut<DashState>,
+    mut status: ResMut<status::ReactorStatus>,
+    time: Res<Time>,
+) {
+    let Ok((mut u_particle, mut u_transform)) = u_particle_query.get_single_mut() else {
+        return;
+    };
+    let delta_seconds = time.delta_seconds();
+    dash.invulnerable_left = (dash.invulnerable_left - delta_seconds).max(0.0);
+    dash.cooldown_left = (dash.cooldown_left - delta_seconds).max(0.0);
+    if dash.burst_left > 0.0 {
+        let step = DASH_DISTANCE / DASH_SECS * delta_seconds.min(dash.burst_left);
+        let field_rect = field::get_field_rect(uou::RADIUS + 3.0);
+        let new_pos =
+            (u_particle.pos() + dash.direction * step).clamp(field_rect.min, field_rect.max);
+        u_particle.jump(new_pos);
+        u_transform.translation.x = new_pos.x;
+        u_transform.translation.y = new_pos.y;
+        dash.burst_left = (dash.burst_left - delta_seconds).max(0.0);
+    } else if let Some(prev_pos) = dash.u_pos {
+        let moved = u_particle.pos() - prev_pos;
+        if moved != Vec2::ZERO {
+            dash.direction = moved.normalize();
+        }
+    }
+    dash.u_pos = Some(u_particle.pos());
+    let is_triggered = settings.fetch_input_map().is_just_pressed(
+        app::key_binding::GameAction::Dash,
+        &keyboard_input,
+        &gamepad_input,
+        &active_gamepad,
+    );
+    if is_triggered && dash.is_ready() && dash.direction != Vec2::ZERO {
+        dash.burst_left = DASH_SECS;
+        dash.invulnerable_left = DASH_INVULNERABLE_SECS;
+        dash.cooldown_left = DASH_COOLDOWN_SECS;
+        status.increase("dash_count", 1);
+    }
+}
+
+fn redraw_dash_ring(
+    dash: Res<DashState>,
+    mut ring_query: Query<(&mut Path, &mut Stroke, &mut Visibility), With<DashRing>>,
+    theme: Res<app::theme::Theme>,
+) {
+    if !dash.is_changed() {
+        return;
+    }
+    let Ok((mut path, mut stroke, mut visibility)) = ring_query.get_single_mut() else {
+        return;
+    };
+    let Some(u_pos) = dash.u_pos.filter(|_| !dash.is_ready()) else {
+        visibility.set_if_neq(Visibility::Hidden);
+        return;
+    };
+    // NOTE: the ring fills up clockwise from the top as the cooldown runs out
+    let progress = 1.0 - dash.cooldown_left / DASH_COOLDOWN_SECS;
+    let radius = uou::RADIUS + DASH_RING_GAP;
+    let segments = ((DASH_RING_SEGMENTS as f32 * progress).ceil() as usize).max(1);
+    let mut path_builder = PathBuilder::new();
+    for i in 0..=segments {
+        let angle = PI * 0.5 - TAU * progress * i as f32 / segments as f32;
+        let point = u_pos + Vec2::new(angle.cos(), angle.sin()) * radius;
+        if i == 0 {
+            path_builder.move_to(point);
+        } else {
+            path_builder.line_to(point);
+        }
+    }
+    *path = path_builder.build();
+    stroke.color = theme.u.with_a(0.5);
+    visibility.set_if_neq(Visibility::Inherited);
+}
+
+fn reset_dash(mut dash: ResMut<DashState>) {
+    *dash = DashState::default();
+}
@@ -11,7 +11,7 @@ const FIELD_PADDING: f32 = (reactor::FIELD_NAV_H - FIELD_TEXT_SIZE) / 2.0;
 const FIELD_COLOR: Color = Color::rgb(0.2, 0.2, 0.2);
 const FIELD_TEXT_COLOR: Color = Color::rgb(0.5, 0.5, 0.5);
 
-pub const REACTOR_FIELDS: [&str; 4] = ["score", "time", "alpha_count", "chain"];
+pub const REACTOR_FIELDS: [&str; 6] = ["score", "time", "alpha_count", "chain", "dash", "bomb"];
 
 #[derive(Component)]
 pub struct ReactorField(String);
@@ -64,8 +64,12 @@ pub fn format_field_text(field: &str, value: u32) -> String {
         let value_str = format!("{:0>6}", value);
         let (first, second) = value_str.split_at(3);
         format!("{},{}", first, second)
-    } else if field == "chain" {
+    } else if field == "chain" || field == "graze" {
         format!("{:0>4}", value)
+    } else if field == "dash" {
+        format!("{}.{}", value / 10, value % 10)
+    } else if field == "bomb" {
+        format!("{:0>3}", value)
     } else {
         format!("{}", value)
     }
@@ -76,7 +80,12 @@ const TARGET_COLOR_ALPHA: f32 = 0.2;
 const TARGET_COLOR: Color = Color::rgba(0.5, 0.5, 0.5, TARGET_COLOR_ALPHA);
 const TARGET_BG_COLOR: Color = Color::rgba(0.5, 0.5, 0.5, TARGET_COLOR_ALPHA * 0.5);
 
-pub fn build_reactor_field(commands: &mut Commands, asset_server: &Res<AssetServer>) -> Entity {
+pub fn build_reactor_field(
+    commands: &mut Commands,
+    asset_server: &Res<AssetServer>,
+    theme: &app::theme::Theme,
+    locale: &app::locale::Locale,
+) -> Entity {
     commands
         .spawn((NodeBundle {
             style: Style {
@@ -92,39 +101,51 @@ pub fn build_reactor_field(commands: &mut Commands, asset_server: &Res<AssetServ
         },))
         .with_children(|parent| {
             parent
-                .spawn((NodeBundle {
-                    style: Style {
-                        width: Val::Px(app::WINDOW_W),
-                        height: Val::Px(app::WINDOW_H),
-                        flex_direction: FlexDirection::Column,
-                        justify_content: JustifyContent::SpaceBetween,
-                        border: UiRect::all(app::ui::px_p(0.5)),
+                .spawn((
+                    NodeBundle {
+                        style: Style {
+                            width: Val::Px(app::WINDOW_W),
+                            height: Val::Px(app::WINDOW_H),
+                            flex_direction: FlexDirection::Column,
+                            justify_content: JustifyContent::SpaceBetween,
+                            border: UiRect::all(app::ui::px_p(0.5)),
+                            ..default()
+                        },
+                        border_color: FIELD_COLOR.into(),
                         ..default()
                     },
-                    border_color: FIELD_COLOR.into(),
-                    ..default()
-                },))
+                    app::ui::FitToField {
+                        width: Some(app::WINDOW_W),
+                        height: Some(app::WINDOW_H),
+                    },
+                ))
                 .with_children(|parent| {
                     parent
-                        .spawn(NodeBundle {
-                            style: Style {
-                                height: Val::Px(reactor::FIELD_ACH_H),
-                                border: UiRect::bottom(app::ui::px_p(0.5)),
-                                align_items: AlignItems::Center,
-                                justify_content: JustifyContent::Start,
-                                padding: UiRect::left(app::ui::px_p(app::ui::PAGE_PADDING)),
+                        .spawn((
+                            NodeBundle {
+                                style: Style {
+                                    height: Val::Px(reactor::FIELD_ACH_H),
+                                    border: UiRect::bottom(app::ui::px_p(0.5)),
+                                    align_items: AlignItems::Center,
+                                    justify_content: JustifyContent::Start,
+                                    padding: UiRect::left(app::ui::px_p(app::ui::PAGE_PADDING)),
+                                    ..default()
+                                },
+                                border_color: FIELD_COLOR.into(),
                                 ..default()
                             },
-                            border_color: FIELD_COLOR.into(),
-                            ..default()
-                        })
+                            app::ui::FitToField {
+                                width: None,
+                                height: Some(reactor::FIELD_ACH_H),
+                            },
+                        ))
                         .with_children(|parent| {
                             parent.spawn((TextBundle::from_section(
                                 "Chain Reaction",
                                 TextStyle {
-                                    font: asset_server.load(app::ui::FONT),
+                                    font: asset_server.load(locale.fetch_font(app::ui::FONT)),
                                     font_size: app::ui::FONT_SIZE * 3.0,
-                                    color: app::ui::MUTE_COLOR,
+                                    color: theme.mute,
                                 },
                             ),));
                         });
@@ -136,7 +157,7 @@ pub fn build_reactor_field(commands: &mut Commands, asset_server: &Res<AssetServ
                             },
                             ..default()
                         },))
-                        .with_children(|parent| build_target_fields(parent, asset_server));
+                        .with_children(|parent| build_target_fields(parent, asset_server, theme));
                     build_reactor_fields(parent, asset_server);
                     parent.spawn((
                         NodeBundle {
@@ -173,7 +194,11 @@ pub fn build_reactor_field(commands: &mut Commands, asset_server: &Res<AssetServ
         .id()
 }
 
-fn build_target_fields(parent: &mut ChildBuilder, asset_server: &Res<AssetServer>) {
+fn build_target_fields(
+    parent: &mut ChildBuilder,
+    asset_server: &Res<AssetServer>,
+    theme: &app::theme::Theme,
+) {
     parent
         .spawn(NodeBundle {
             style: Style {
@@ -193,7 +218,7 @@ fn build_target_fields(parent: &mut ChildBuilder, asset_server: &Res<AssetServer
             for field in app::leaderboard::LEADERBOARD_LISTS {
                 let flex_grow = match field {
                     "score" | "time" | "max_alpha_count" => 1.2,
-                    "max_control_chain" | "max_hyper_chain" => 0.3,
+                    "max_control_chain" | "max_hyper_chain" | "max_graze_streak" => 0.3,
                     _ => panic!("Invalid field"),
                 };
                 parent
@@ -228,6 +253,7 @@ fn build_target_fields(parent: &mut ChildBuilder, asset_server: &Res<AssetServer
                                     }
                                     "max_control_chain" => format_field_text("chain", target_value),
                                     "max_hyper_chain" => format_field_text("chain", target_value),
+                                    "max_graze_streak" => format_field_text("graze", target_value),
                                     _ => panic!("Invalid field"),
                                 };
                                 let target_rank_text = match target_rank {
@@ -239,12 +265,15 @@ fn build_target_fields(parent: &mut ChildBuilder, asset_server: &Res<AssetServer
                                 };
                                 let number_color = match field {
                                     "score" | "time" | "max_alpha_count" => TARGET_COLOR,
-                                    "max_control_chain" => *reactor::particle::control::COLOR
-                                        .clone()
-                                        .set_a(TARGET_COLOR_ALPHA),
-                                    "max_hyper_chain" => *reactor::particle::hyper::COLOR
-                                        .clone()
-                                        .set_a(TARGET_COLOR_ALPHA),
+                                    "max_control_chain" => {
+                                        *theme.control.clone().set_a(TARGET_COLOR_ALPHA)
+                                    }
+                                    "max_hyper_chain" => {
+                                        *theme.hyper.clone().set_a(TARGET_COLOR_ALPHA)
+                                    }
+                                    "max_graze_streak" => {
+                                        *theme.u.clone().set_a(TARGET_COLOR_ALPHA)
+                                    }
                                     _ => panic!("Invalid field"),
                                 };
                                 parent
@@ -265,7 +294,7 @@ fn build_target_fields(parent: &mut ChildBuilder, asset_server: &Res<AssetServer
                                                 TextStyle {
                                                     font: asset_server.load(app::ui::FONT_DIGIT),
                                                     font_size: TARGET_TEXT_SIZE * 0.6,
-                                                    color: app::ui::BG_COLOR,
+                                                    color: theme.bg,
                                                 },
                                             ),
                                             TargetRankField(String::from(field)),
@@ -315,19 +344,25 @@ fn build_target_fields(parent: &mut ChildBuilder, asset_server: &Res<AssetServer
 
 fn build_reactor_fields(parent: &mut ChildBuilder, asset_server: &Res<AssetServer>) {
     parent
-        .spawn((NodeBundle {
-            style: Style {
-                height: Val::Px(reactor::FIELD_NAV_H),
-                align_items: AlignItems::Center,
-                justify_content: JustifyContent::SpaceBetween,
-                border: UiRect::top(app::ui::px_p(0.5)),
-                padding: UiRect::horizontal(Val::Px(FIELD_PADDING * 1.4)),
-                column_gap: Val::Px(FIELD_PADDING * 1.4),
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    height: Val::Px(reactor::FIELD_NAV_H),
+                    align_items: AlignItems::Center,
+                    justify_content: JustifyContent::SpaceBetween,
+                    border: UiRect::top(app::ui::px_p(0.5)),
+                    padding: UiRect::horizontal(Val::Px(FIELD_PADDING * 1.4)),
+                    column_gap: Val::Px(FIELD_PADDING * 1.4),
+                    ..default()
+                },
+                border_color: FIELD_COLOR.into(),
                 ..default()
             },
-            border_color: FIELD_COLOR.into(),
-            ..default()
-        },))
+            app::ui::FitToField {
+                width: None,
+                height: Some(reactor::FIELD_NAV_H),
+            },
+        ))
         .with_children(|parent| {
             parent.spawn((NodeBundle {
                 style: Style {
@@ -356,6 +391,8 @@ fn build_reactor_fields(parent: &mut ChildBuilder, asset_server: &Res<AssetServe
                             "time" => "images/icons/timer-fill.png",
                             "alpha_count" => "images/icons/circles-three-fill.png",
                             "chain" => "images/icons/line-segments.png",
+                            "dash" => "images/icons/arrow-fat-up-fill.png",
+                            "bomb" => "images/icons/star-light.png",
                             _ => panic!("Invalid field"),
                         };
                         parent
@@ -424,9 +461,11 @@ pub fn update_reactor_fields(
     mut status: ResMut<reactor::status::ReactorStatus>,
     particle_query: Query<&reactor::particle::Particle, With<reactor::particle::Particle>>,
     asset_server: Res<AssetServer>,
+    dash: Res<reactor::dash::DashState>,
     #[cfg(not(target_arch = "wasm32"))] main_window: Query<Entity, With<PrimaryWindow>>,
     #[cfg(not(target_arch = "wasm32"))] mut screenshot_manager: ResMut<ScreenshotManager>,
     #[cfg(not(target_arch = "wasm32"))] reactor_status: Res<State<reactor::ReactorState>>,
+    theme: Res<app::theme::Theme>,
 ) {
     if reactor_timer.0.tick(time.delta()).just_finished() {
         let mut control_count = 0;
@@ -482,9 +521,27 @@ pub fn update_reactor_fields(
                     text.sections[0].value =
                         format_field_text("chain", status.fetch("chain_length"));
                     text.sections[0].style.color = match status.current_chain() {
-                        reactor::status::StatusChain::Control => reactor::particle::control::COLOR,
+                        reactor::status::StatusChain::Control => theme.control,
                         reactor::status::StatusChain::None => FIELD_TEXT_COLOR,
-                        reactor::status::StatusChain::Hyper => reactor::particle::hyper::COLOR,
+                        reactor::status::StatusChain::Hyper => theme.hyper,
+                    };
+                }
+                "dash" => {
+                    let cooldown = (dash.cooldown_left() * 10.0).ceil() as u32;
+                    text.sections[0].value = format_field_text("dash", cooldown);
+                    text.sections[0].style.color = if dash.is_ready() {
+                        theme.u
+                    } else {
+                        FIELD_TEXT_COLOR
+                    };
+                }
+                "bomb" => {
+                    let ratio = status.bomb_charge_ratio();
+                    text.sections[0].value = format_field_text("bomb", (ratio * 100.0) as u32);
+                    text.sections[0].style.color = if ratio >= 1.0 {
+                        theme.u
+                    } else {
+                        FIELD_TEXT_COLOR
                     };
                 }
                 _ => (),
@@ -557,6 +614,7 @@ pub fn update_target_fields(
             "max_alpha_count" => format_field_text("alpha_count", shown_value),
             "max_control_chain" => format_field_text("chain", shown_value),
             "max_hyper_chain" => format_field_text("chain", shown_value),
+            "max_graze_streak" => format_field_text("graze", shown_value),
             _ => panic!("Invalid field"),
         };
         text.sections[0].value = target_value_text;
@@ -600,6 +658,7 @@ pub fn reset_target_fields(
             "max_alpha_count" => format_field_text("alpha_count", target_value),
             "max_control_chain" => format_field_text("chain", target_value),
             "max_hyper_chain" => format_field_text("chain", target_value),
+            "max_graze_streak" => format_field_text("graze", target_value),
             _ => panic!("Invalid field"),
         };
         text.sections[0].value = target_value_text;
@@ -15,22 +15,41 @@ const ACH_COLOR_ALPHA: f32 = 1.0;
 
 const ACH_DONE_ANIME_END_EVENT: u64 = 3;
 
+#[allow(clippy::too_many_arguments)]
 pub fn reset_ach_fields(
     mut commands: Commands,
     ach_running_panel: Query<Entity, With<reactor::field::ReactorAchRunning>>,
     ach_done_panel: Query<Entity, With<reactor::field::ReactorAchDone>>,
     mut ach_info: ResMut<app::achievement::AchievementInfo>,
     ach_store: ResMut<Persistent<app::achievement::AchievementStore>>,
+    ach_counters: Res<Persistent<app::achievement::AchievementCounters>>,
+    ach_progress: Res<Persistent<app::achievement::AchievementProgress>>,
+    ach_defs: Res<app::achievement::AchievementDefs>,
     asset_server: Res<AssetServer>,
-    status: ResMut<reactor::status::ReactorStatus>,
+    mut status: ResMut<reactor::status::ReactorStatus>,
+    theme: Res<app::theme::Theme>,
+    locale: Res<app::locale::Locale>,
 ) {
-    ach_info.reset(&ach_store);
+    status.lifetime_counters = ach_counters.snapshot();
+    ach_info.reset(&ach_store, &ach_defs);
     let running_panel_entity = ach_running_panel.single();
     if let Some(mut entity_commands) = commands.get_entity(running_panel_entity) {
         entity_commands.despawn_descendants();
         entity_commands.with_children(|parent| {
             for code in ach_info.running_codes() {
-                build_ach_running_ui(parent, &asset_server, &status, &ach_store, &code);
+                if let Some(ach_def) = ach_defs.find(&code) {
+                    let best = ach_progress.best_value(&code);
+                    build_ach_running_ui(
+                        parent,
+                        &asset_server,
+                        &status,
+                        &ach_store,
+                        ach_def,
+                        best,
+                        &theme,
+                        &locale,
+                    );
+                }
             }
         });
     }
@@ -39,77 +58,107 @@ pub fn reset_ach_fields(
         entity_commands.despawn_descendants();
         entity_commands.with_children(|parent| {
             let done_code = ach_info.next_done();
-            if let Some(code) = done_code {
-                build_ach_done_ui(parent, &asset_server, code.as_str());
+            if let Some((code, tier)) = done_code {
+                if let Some(ach_def) = ach_defs.find(&code) {
+                    build_ach_done_ui(parent, &asset_server, ach_def, tier, &theme, &locale);
+                }
             }
         });
     }
 }
 
 #[allow(clippy::too_many_arguments)]
-pub fn update_ach_fields(
+pub fn paint_ach_progress(
     mut commands: Commands,
-    ach_running_panel: Query<Entity, With<reactor::field::ReactorAchRunning>>,
-    ach_done_panel: Query<Entity, With<reactor::field::ReactorAchDone>>,
-    mut ach_info: ResMut<app::achievement::AchievementInfo>,
-    asset_server: Res<AssetServer>,
-    mut tween_completed_events: EventReader<TweenCompleted>,
-    mut status: ResMut<reactor::status::ReactorStatus>,
-    mut ach_store: ResMut<Persistent<app::achievement::AchievementStore>>,
+    status: ResMut<reactor::status::ReactorStatus>,
+    ach_store: Res<Persistent<app::achievement::AchievementStore>>,
+    ach_progress: Res<Persistent<app::achievement::AchievementProgress>>,
+    ach_defs: Res<app::achievement::AchievementDefs>,
     mut ap_bar_texts: Query<(&mut Text, &AchProgressText), With<AchProgressText>>,
     mut ap_bar_values: Query<(&mut Style, &AchProgressBarValue), With<AchProgressBarValue>>,
     mut ap_dots_panels: Query<(Entity, &AchProgressDotsPanel), With<AchProgressDotsPanel>>,
     mut painter_timer: ResMut<reactor::PainterTimer>,
     time: Res<Time>,
-    settings: Res<Persistent<app::settings::Settings>>,
-    audio_se_asset: Res<app::audio::AudioSeAsset>,
+    theme: Res<app::theme::Theme>,
 ) {
     if painter_timer.0.tick(time.delta()).just_finished() {
         for (mut text, ap_bar_text) in ap_bar_texts.iter_mut() {
-            let code = &ap_bar_text.0;
-            let ach_def = app::achievement::fetch_ach_def(code);
-            let (current, _, _) = ach_def.check_done(&status);
+            let Some(ach_def) = ach_defs.find(&ap_bar_text.0) else {
+                continue;
+            };
+            let (current, _, _) = ach_def.check_done(&status, ach_store.next_tier(ach_def));
             text.sections[0].value = ach_def.format_value(current);
         }
         for (mut style, ap_bar_value) in ap_bar_values.iter_mut() {
-            let code = &ap_bar_value.0;
-            let ach_def = app::achievement::fetch_ach_def(code);
-            let (current, total, _) = ach_def.check_done(&status);
+            let Some(ach_def) = ach_defs.find(&ap_bar_value.0) else {
+                continue;
+            };
+            let (current, total, _) = ach_def.check_done(&status, ach_store.next_tier(ach_def));
             let value_bar_ratio = current as f32 / total as f32 * 100.0;
             style.width = Val::Percent(value_bar_ratio);
         }
         for (entity, ap_bar_dots_panel) in ap_dots_panels.iter_mut() {
+            let code = &ap_bar_dots_panel.0;
+            let Some(ach_def) = ach_defs.find(code) else {
+                continue;
+            };
             if let Some(mut panel_commands) = commands.get_entity(entity) {
-                let code = &ap_bar_dots_panel.0;
-                let ach_def = app::achievement::fetch_ach_def(code);
-                let (current, total, _) = ach_def.check_done(&status);
+                let (current, total, _) = ach_def.check_done(&status, ach_store.next_tier(ach_def));
                 panel_commands.despawn_descendants();
                 panel_commands.with_children(|parent| {
-                    build_ach_running_progress_dots(parent, (current, total));
+                    build_ach_running_progress_dots(
+                        parent,
+                        (current, total),
+                        ach_progress.best_value(code),
+                        &theme,
+                    );
                 });
             }
         }
     }
-    let mut trigger_done_code: Option<String> = None;
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn update_ach_fields(
+    mut commands: Commands,
+    ach_running_panel: Query<Entity, With<reactor::field::ReactorAchRunning>>,
+    ach_done_panel: Query<Entity, With<reactor::field::ReactorAchDone>>,
+    mut ach_info: ResMut<app::achievement::AchievementInfo>,
+    asset_server: Res<AssetServer>,
+    mut tween_completed_events: EventReader<TweenCompleted>,
+    mut status: ResMut<reactor::status::ReactorStatus>,
+    mut ach_store: ResMut<Persistent<app::achievement::AchievementStore>>,
+    ach_progress: Res<Persistent<app::achievement::AchievementProgress>>,
+    ach_defs: Res<app::achievement::AchievementDefs>,
+    settings: Res<Persistent<app::settings::Settings>>,
+    audio_se_asset: Res<app::audio::AudioSeAsset>,
+    theme: Res<app::theme::Theme>,
+    locale: Res<app::locale::Locale>,
+) {
+    let mut trigger_done_code: Option<(String, usize)> = None;
     let mut is_running_updated: bool = false;
-    for ach_def in app::achievement::ACHIEVEMENTS {
-        if !status
-            .done_achievements
-            .contains(&String::from(ach_def.code()))
-        {
-            let (_, _, is_done) = ach_def.check_done(&status);
+    for ach_def in ach_defs.iter() {
+        let tier = status.fetch_done_tier(ach_def.code());
+        if tier < ach_def.tier_count() {
+            let (current, _, is_done) = ach_def.check_done(&status, tier);
+            status.update_ach_progress(ach_def.code(), current);
             if is_done {
                 ach_store
                     .update(|ach_store| {
-                        ach_store.mark_done(ach_def.code());
+                        ach_store.mark_tier_done(ach_def, tier);
                     })
                     .expect("failed to mark achievement done");
-                status.done_achievements.push(String::from(ach_def.code()));
+                status
+                    .done_tiers
+                    .insert(String::from(ach_def.code()), tier + 1);
+                if tier == 0 {
+                    status.done_achievements.push(String::from(ach_def.code()));
+                }
                 if ach_info.is_running(ach_def.code()) {
-                    ach_info.update_running_codes(&ach_store);
+                    ach_info.update_running_codes(&ach_store, &ach_defs);
                     is_running_updated = true;
                 }
-                trigger_done_code = ach_info.push_to_done(ach_def.code());
+                trigger_done_code = ach_info.push_to_done(ach_def.code(), tier);
                 app::audio::play_se(
                     app::audio::AudioSe::Tada,
                     &mut commands,
@@ -125,24 +174,41 @@ pub fn update_ach_fields(
         running_commands.despawn_descendants();
         running_commands.with_children(|parent| {
             for code in ach_info.running_codes() {
-                build_ach_running_ui(parent, &asset_server, &status, &ach_store, &code);
+                if let Some(ach_def) = ach_defs.find(&code) {
+                    let best = ach_progress.best_value(&code);
+                    build_ach_running_ui(
+                        parent,
+                        &asset_server,
+                        &status,
+                        &ach_store,
+                        ach_def,
+                        best,
+                        &theme,
+                        &locale,
+                    );
+                }
             }
         });
     }
     let done_panel_entity = ach_done_panel.single();
     let mut done_commands = commands.get_entity(done_panel_entity).unwrap();
-    if let Some(code) = trigger_done_code {
-        done_commands.despawn_descendants();
-        done_commands.with_children(|parent| {
-            build_ach_done_ui(parent, &asset_server, code.as_str());
-        });
+    if let Some((code, tier)) = trigger_done_code {
+        if let Some(ach_def) = ach_defs.find(&code) {
+            done_commands.despawn_descendants();
+            done_commands.with_children(|parent| {
+                build_ach_done_ui(parent, &asset_server, ach_def, tier, &theme, &locale);
+            });
+        }
     }
     for tween_event in tween_completed_events.read() {
         if tween_event.user_data == ACH_DONE_ANIME_END_EVENT {
-            if let Some(code) = ach_info.next_done() {
+            let done_def = ach_info
+                .next_done()
+                .and_then(|(code, tier)| ach_defs.find(&code).map(|ach_def| (ach_def, tier)));
+            if let Some((ach_def, tier)) = done_def {
                 done_commands.despawn_descendants();
                 done_commands.with_children(|parent| {
-                    build_ach_done_ui(parent, &asset_server, code.as_str());
+                    build_ach_done_ui(parent, &asset_server, ach_def, tier, &theme, &locale);
                 });
             } else {
                 done_commands.despawn_descendants();
@@ -151,6 +217,28 @@ pub fn update_ach_fields(
     }
 }
 
+pub fn store_ach_progress(
+    status: Res<reactor::status::ReactorStatus>,
+    mut ach_progress: ResMut<Persistent<app::achievement::AchievementProgress>>,
+) {
+    ach_progress
+        .update(|ach_progress| {
+            ach_progress.merge(&status);
+        })
+        .expect("failed to update achievement progress");
+}
+
+pub fn store_ach_counters(
+    status: Res<reactor::status::ReactorStatus>,
+    mut ach_counters: ResMut<Persistent<app::achievement::AchievementCounters>>,
+) {
+    ach_counters
+        .update(|ach_counters| {
+            ach_counters.accumulate(&status);
+        })
+        .expect("failed to update achievement counters");
+}
+
 struct AchRunningAnimeLens {
     start_left: f32,
     end_left: f32,
@@ -162,15 +250,20 @@ impl lens::Lens<Style> for AchRunningAnimeLens {
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn build_ach_running_ui(
     parent: &mut ChildBuilder,
     asset_server: &Res<AssetServer>,
     status: &ResMut<reactor::status::ReactorStatus>,
     ach_store: &ResMut<Persistent<app::achievement::AchievementStore>>,
-    code: &str,
+    ach_def: &dyn app::achievement::AchievementDefBase,
+    best: u32,
+    theme: &app::theme::Theme,
+    locale: &app::locale::Locale,
 ) {
-    let ach_def = app::achievement::fetch_ach_def(code);
-    let color = app::ui::MUTE_COLOR.with_a(ACH_COLOR_ALPHA);
+    let code = ach_def.code();
+    let tier = ach_store.next_tier(ach_def);
+    let color = theme.mute.with_a(ACH_COLOR_ALPHA);
     let tween = Tween::new(
         EaseFunction::CubicOut,
         Duration::from_millis(500),
@@ -192,7 +285,7 @@ fn build_ach_running_ui(
                     column_gap: app::ui::px_p(4.0),
                     ..default()
                 },
-                background_color: app::ui::BG_COLOR.with_a(ACH_COLOR_ALPHA).into(),
+                background_color: theme.bg.with_a(ACH_COLOR_ALPHA).into(),
                 border_color: color.into(),
                 ..default()
             },
@@ -222,11 +315,11 @@ fn build_ach_running_ui(
                         justify_content: JustifyContent::SpaceBetween,
                         ..default()
                     },
-                    background_color: app::ui::BG_COLOR.with_a(ACH_COLOR_ALPHA).into(),
+                    background_color: theme.bg.with_a(ACH_COLOR_ALPHA).into(),
                     ..default()
                 },))
                 .with_children(|parent| {
-                    let (value, total, _) = ach_def.check_done(status);
+                    let (value, total, _) = ach_def.check_done(status, tier);
                     parent
                         .spawn((NodeBundle {
                             style: Style {
@@ -238,11 +331,11 @@ fn build_ach_running_ui(
                         .with_children(|parent| {
                             parent.spawn(
                                 TextBundle::from_section(
-                                    ach_def.description(),
+                                    ach_def.description(locale, tier),
                                     TextStyle {
-                                        font: asset_server.load(app::ui::FONT),
+                                        font: asset_server.load(locale.fetch_font(app::ui::FONT)),
                                         font_size: ACH_DESC_FS,
-                                        color: app::ui::SECONDARY_COLOR.with_a(ACH_COLOR_ALPHA),
+                                        color: theme.secondary.with_a(ACH_COLOR_ALPHA),
                                     },
                                 )
                                 .with_style(Style {
@@ -254,9 +347,9 @@ fn build_ach_running_ui(
                                 TextBundle::from_section(
                                     ach_def.format_value(value),
                                     TextStyle {
-                                        font: asset_server.load(app::ui::FONT),
+                                        font: asset_server.load(locale.fetch_font(app::ui::FONT)),
                                         font_size: PROGRESS_FS,
-                                        color: app::ui::FG_COLOR.with_a(ACH_COLOR_ALPHA),
+                                        color: theme.fg.with_a(ACH_COLOR_ALPHA),
                                     },
                                 )
                                 .with_style(Style {
@@ -268,7 +361,13 @@ fn build_ach_running_ui(
                         });
                     match ach_def.progress_ui() {
                         app::achievement::AchievementProgressUi::Bar => {
-                            build_ach_running_progress_bar(parent, (value, total), code);
+                            build_ach_running_progress_bar(
+                                parent,
+                                (value, total),
+                                best,
+                                code,
+                                theme,
+                            );
                         }
                         app::achievement::AchievementProgressUi::Dots => {
                             parent
@@ -281,15 +380,18 @@ fn build_ach_running_ui(
                                             column_gap: app::ui::px_p(PROGRESS_BAR_H * 0.5),
                                             ..default()
                                         },
-                                        background_color: app::ui::BG_COLOR
-                                            .with_a(ACH_COLOR_ALPHA)
-                                            .into(),
+                                        background_color: theme.bg.with_a(ACH_COLOR_ALPHA).into(),
                                         ..default()
                                     },
                                     AchProgressDotsPanel(String::from(code)),
                                 ))
                                 .with_children(|parent| {
-                                    build_ach_running_progress_dots(parent, (value, total));
+                                    build_ach_running_progress_dots(
+                                        parent,
+                                        (value, total),
+                                        best,
+                                        theme,
+                                    );
                                 });
                         }
                     }
@@ -307,11 +409,19 @@ pub struct AchProgressBarValue(String);
 pub struct AchProgressDotsPanel(String);
 
 const PROGRESS_BAR_H: f32 = 3.0;
+const GHOST_MARKER_W: f32 = 1.0;
 const PROGRESS_FS: f32 = app::ui::FONT_SIZE * 0.8;
 
-fn build_ach_running_progress_bar(parent: &mut ChildBuilder, value_total: (u32, u32), code: &str) {
+fn build_ach_running_progress_bar(
+    parent: &mut ChildBuilder,
+    value_total: (u32, u32),
+    best: u32,
+    code: &str,
+    theme: &app::theme::Theme,
+) {
     let (value, total) = value_total;
     let value_bar_ratio = value as f32 / total as f32 * 100.0;
+    let best_bar_ratio = (best as f32 / total as f32 * 100.0).min(100.0);
     parent
         .spawn((ButtonBundle {
             style: Style {
@@ -321,7 +431,7 @@ fn build_ach_running_progress_bar(parent: &mut ChildBuilder, value_total: (u32,
                 margin: UiRect::top(app::ui::px_p(4.0)),
                 ..default()
             },
-            background_color: app::ui::MUTE_COLOR.with_a(ACH_COLOR_ALPHA).into(),
+            background_color: theme.mute.with_a(ACH_COLOR_ALPHA).into(),
             ..default()
         },))
         .with_children(|parent| {
@@ -332,17 +442,52 @@ fn build_ach_running_progress_bar(parent: &mut ChildBuilder, value_total: (u32,
                         height: app::ui::px_p(PROGRESS_BAR_H),
                         ..default()
                     },
-                    background_color: app::ui::SECONDARY_COLOR.with_a(ACH_COLOR_ALPHA).into(),
+                    background_color: theme.secondary.with_a(ACH_COLOR_ALPHA).into(),
                     ..default()
                 },
                 AchProgressBarValue(String::from(code)),
             ));
+            if best > 0 {
+                // NOTE: the marker track stops one marker short of the end,
+                // so a full best stays inside the bar
+                parent
+                    .spawn(NodeBundle {
+                        style: Style {
+                            position_type: PositionType::Absolute,
+                            left: Val::Px(0.0),
+                            right: app::ui::px_p(GHOST_MARKER_W),
+                            height: app::ui::px_p(PROGRESS_BAR_H),
+                            ..default()
+                        },
+                        ..default()
+                    })
+                    .with_children(|parent| {
+                        parent.spawn(NodeBundle {
+                            style: Style {
+                                position_type: PositionType::Absolute,
+                                left: Val::Percent(best_bar_ratio),
+                                width: app::ui::px_p(GHOST_MARKER_W),
+                                height: app::ui::px_p(PROGRESS_BAR_H),
+                                ..default()
+                            },
+                            background_color: theme.ghost.into(),
+                            ..default()
+                        });
+                    });
+            }
         });
 }
 
-fn build_ach_running_progress_dots(parent: &mut ChildBuilder, value_total: (u32, u32)) {
+fn build_ach_running_progress_dots(
+    parent: &mut ChildBuilder,
+    value_total: (u32, u32),
+    best: u32,
+    theme: &app::theme::Theme,
+) {
     let (value, total) = value_total;
-    let remaining = total - value;
+    let value = value.min(total);
+    let ghost = best.clamp(value, total) - value;
+    let remaining = total - value - ghost;
     for _ in 0..value {
         parent.spawn(NodeBundle {
             style: Style {
@@ -350,7 +495,18 @@ fn build_ach_running_progress_dots(parent: &mut ChildBuilder, value_total: (u32,
                 height: app::ui::px_p(PROGRESS_BAR_H),
                 ..default()
             },
-            background_color: app::ui::SECONDARY_COLOR.with_a(ACH_COLOR_ALPHA).into(),
+            background_color: theme.secondary.with_a(ACH_COLOR_ALPHA).into(),
+            ..default()
+        });
+    }
+    for _ in 0..ghost {
+        parent.spawn(NodeBundle {
+            style: Style {
+                width: app::ui::px_p(PROGRESS_BAR_H),
+                height: app::ui::px_p(PROGRESS_BAR_H),
+                ..default()
+            },
+            background_color: theme.ghost.into(),
             ..default()
         });
     }
@@ -361,7 +517,7 @@ fn build_ach_running_progress_dots(parent: &mut ChildBuilder, value_total: (u32,
                 height: app::ui::px_p(PROGRESS_BAR_H),
                 ..default()
             },
-            background_color: app::ui::MUTE_COLOR.with_a(ACH_COLOR_ALPHA).into(),
+            background_color: theme.mute.with_a(ACH_COLOR_ALPHA).into(),
             ..default()
         });
     }
@@ -378,9 +534,16 @@ impl lens::Lens<Style> for AchDoneAnimeLens {
     }
 }
 
-fn build_ach_done_ui(parent: &mut ChildBuilder, asset_server: &Res<AssetServer>, code: &str) {
-    let ach_def = app::achievement::fetch_ach_def(code);
+fn build_ach_done_ui(
+    parent: &mut ChildBuilder,
+    asset_server: &Res<AssetServer>,
+    ach_def: &dyn app::achievement::AchievementDefBase,
+    tier: usize,
+    theme: &app::theme::Theme,
+    locale: &app::locale::Locale,
+) {
     let color = ach_def.color();
+    let tier_color = ach_def.tier_color(tier);
     let tween = Tween::new(
         EaseFunction::CubicOut,
         Duration::from_millis(8000),
@@ -403,8 +566,8 @@ fn build_ach_done_ui(parent: &mut ChildBuilder, asset_server: &Res<AssetServer>,
                     border: UiRect::all(app::ui::px_p(1.0)),
                     ..default()
                 },
-                background_color: app::ui::BG_COLOR.with_a(ACH_COLOR_ALPHA).into(),
-                border_color: color.into(),
+                background_color: theme.bg.with_a(ACH_COLOR_ALPHA).into(),
+                border_color: tier_color.into(),
                 ..default()
             },
             Animator::new(tween),
@@ -430,17 +593,17 @@ fn build_ach_done_ui(parent: &mut ChildBuilder, asset_server: &Res<AssetServer>,
                         margin: UiRect::right(app::ui::px_p(4.0)),
                         ..default()
                     },
-                    background_color: app::ui::BG_COLOR.with_a(0.0).into(),
+                    background_color: theme.bg.with_a(0.0).into(),
                     ..default()
                 },))
                 .with_children(|parent| {
                     parent.spawn(
                         TextBundle::from_section(
-                            ach_def.description(),
+                            ach_def.description(locale, tier),
                             TextStyle {
-                                font: asset_server.load(app::ui::FONT),
+                                font: asset_server.load(locale.fetch_font(app::ui::FONT)),
                                 font_size: ACH_DESC_FS,
-                                color: app::ui::SECONDARY_COLOR,
+                                color: theme.secondary,
                             },
                         )
                         .with_style(Style {
@@ -450,9 +613,9 @@ fn build_ach_done_ui(parent: &mut ChildBuilder, asset_server: &Res<AssetServer>,
                     );
                     parent.spawn(
                         TextBundle::from_section(
-                            ach_def.name(),
+                            app::achievement::format_tier_name(ach_def, tier, locale),
                             TextStyle {
-                                font: asset_server.load(app::ui::FONT),
+                                font: asset_server.load(locale.fetch_font(app::ui::FONT)),
                                 font_size: ACH_NAME_FS,
                                 color,
                             },
@@ -466,8 +629,12 @@ fn build_ach_done_ui(parent: &mut ChildBuilder, asset_server: &Res<AssetServer>,
         });
 }
 
-pub fn build_ach_icon(parent: &mut ChildBuilder, asset_server: &Res<AssetServer>, code: &str) {
-    let ach_def = app::achievement::fetch_ach_def(code);
+pub fn build_ach_icon(
+    parent: &mut ChildBuilder,
+    asset_server: &Res<AssetServer>,
+    ach_def: &dyn app::achievement::AchievementDefBase,
+    theme: &app::theme::Theme,
+) {
     parent
         .spawn((ButtonBundle {
             style: Style {
@@ -475,7 +642,7 @@ pub fn build_ach_icon(parent: &mut ChildBuilder, asset_server: &Res<AssetServer>
                 align_items: AlignItems::Center,
                 ..default()
             },
-            background_color: app::ui::BG_COLOR.into(),
+            background_color: theme.bg.into(),
             ..default()
         },))
         .with_children(|parent| {
@@ -4,10 +4,38 @@ use std::{cell::RefCell, collections::HashMap, rc::Rc};
 
 use super::particle;
 
+pub const GRAZE_RADIUS: f32 = 16.0;
+
 pub fn is_hit(p1: &Particle, p2: &Particle) -> bool {
     p1.pos().distance(p2.pos()) <= p1.radius + p2.radius
 }
 
+pub fn is_graze(p1: &Particle, p2: &Particle) -> bool {
+    p1.pos().distance(p2.pos()) <= p1.radius + p2.radius + GRAZE_RADIUS
+}
+
+// NOTE: maps every alpha inside the graze ring to whether it is touching U right now
+pub fn detect_graze(
+    particle_query: &Query<(Entity, &mut Particle, &mut Transform), With<Particle>>,
+) -> HashMap<Entity, bool> {
+    let mut grazing: HashMap<Entity, bool> = HashMap::new();
+    let Some((_, u, _)) = particle_query
+        .iter()
+        .find(|(_, p, _)| p.particle_type() == ParticleType::Uou)
+    else {
+        return grazing;
+    };
+    for (e, p, _) in particle_query.iter() {
+        if p.particle_type() == ParticleType::Alpha
+            && p.state == particle::ParticleState::Running
+            && is_graze(p, u)
+        {
+            grazing.insert(e, is_hit(p, u));
+        }
+    }
+    grazing
+}
+
 #[derive(Debug, Copy, Clone)]
 pub enum HitAction {
     MoveOnly,
@@ -15,6 +43,7 @@ pub enum HitAction {
     UouHit,
     Kill,
     Release(u32),
+    PassThrough(Vec2),
 }
 
 type EntityParticle<'a> = (Rc<RefCell<Entity>>, Rc<RefCell<Mut<'a, Particle>>>);
@@ -60,20 +89,18 @@ pub fn record_hit_action(
     let mut e2_action: HitAction = hit_map.get(&e2).copied().unwrap_or(HitAction::MoveOnly);
     match p1.particle_type() {
         ParticleType::Alpha => match p2.particle_type() {
-            ParticleType::Alpha => {
-                if p1.countdown_ratio() == 0.0 && p1.countdown_ratio() == 0.0 {
-                    if p1.level() == 1 && p2.level() == 1 {
-                        e1_action = HitAction::Release(2_u32.pow(p1.level() as u32));
-                        e2_action = HitAction::MoveOnly;
-                    } else if p1.level() == 1 && p2.level() != 1 {
-                        e2_action = HitAction::Release(2_u32.pow(p2.level() as u32));
-                    } else if p1.level() != 1 && p2.level() == 1 {
-                        e1_action = HitAction::Release(2_u32.pow(p1.level() as u32));
-                    } else {
-                        // p1.level and p2.level both > 1
-                        e1_action = HitAction::Release(2_u32.pow(p1.level() as u32));
-                        e2_action = HitAction::Release(2_u32.pow(p2.level() as u32));
-                    }
+            ParticleType::Alpha if p1.countdown_ratio() == 0.0 && p2.countdown_ratio() == 0.0 => {
+                if p1.level() == 1 && p2.level() == 1 {
+                    e1_action = HitAction::Release(2_u32.pow(p1.level() as u32));
+                    e2_action = HitAction::MoveOnly;
+                } else if p1.level() == 1 && p2.level() != 1 {
+                    e2_action = HitAction::Release(2_u32.pow(p2.level() as u32));
+                } else if p1.level() != 1 && p2.level() == 1 {
+                    e1_action = HitAction::Release(2_u32.pow(p1.level() as u32));
+                } else {
+                    // p1.level and p2.level both > 1
+                    e1_action = HitAction::Release(2_u32.pow(p1.level() as u32));
+                    e2_action = HitAction::Release(2_u32.pow(p2.level() as u32));
                 }
             }
             ParticleType::Control => {
@@ -116,6 +143,9 @@ pub fn record_hit_action(
             ParticleType::Hyper => {
                 e1_action = HitAction::Kill;
             }
+            ParticleType::Hunter => {
+                e2_action = HitAction::Kill;
+            }
             _ => (),
         },
         ParticleType::Uou => match p2.particle_type() {
@@ -131,6 +161,12 @@ pub fn record_hit_action(
             ParticleType::Trigger => {
                 e2_action = HitAction::UouHit;
             }
+            ParticleType::Hunter => {
+                e1_action = HitAction::Kill;
+            }
+            ParticleType::Well if !matches!(e1_action, HitAction::Kill) => {
+                e1_action = HitAction::PassThrough(well::pass_through_pos(p1, p2));
+            }
             _ => (),
         },
         ParticleType::Hyper => match p2.particle_type() {
@@ -147,6 +183,20 @@ pub fn record_hit_action(
                 e1_action = HitAction::UouHit;
             }
         }
+        ParticleType::Well => {
+            if p2.particle_type() == ParticleType::Uou && !matches!(e2_action, HitAction::Kill) {
+                e2_action = HitAction::PassThrough(well::pass_through_pos(p2, p1));
+            }
+        }
+        ParticleType::Hunter => match p2.particle_type() {
+            ParticleType::Control => {
+                e1_action = HitAction::Kill;
+            }
+            ParticleType::Uou => {
+                e2_action = HitAction::Kill;
+            }
+            _ => (),
+        },
     }
     hit_map.insert(e1, e1_action);
     hit_map.insert(e2, e2_action);
@@ -0,0 +1,82 @@
+use crate::{
+    app,
+    reactor::particle::{Particle, ParticleType},
+};
+use bevy::prelude::*;
+use bevy_persistent::prelude::*;
+
+pub const MAX_FLASH_ALPHA: f32 = 0.2;
+
+// NOTE: only decides how things are drawn, particle state and timings stay untouched
+#[derive(Resource, Clone, Copy, PartialEq, Default)]
+pub struct MotionPolicy {
+    pub reduced: bool,
+}
+
+impl MotionPolicy {
+    pub fn steady_radius(&self, radius: f32, end_radius: f32) -> f32 {
+        if self.reduced {
+            end_radius
+        } else {
+            radius
+        }
+    }
+    pub fn flash_color(&self, color: Color) -> Color {
+        if self.reduced {
+            color.with_a(color.a().min(MAX_FLASH_ALPHA))
+        } else {
+            color
+        }
+    }
+    pub fn rotation(&self, rotation: f32) -> f32 {
+        if self.reduced {
+            0.0
+        } else {
+            rotation
+        }
+    }
+}
+
+pub struct MotionPlugin;
+
+impl Plugin for MotionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MotionPolicy>()
+            .add_systems(PreUpdate, sync_motion_policy)
+            .add_systems(Update, steady_trigger_rotation);
+    }
+}
+
+fn sync_motion_policy(
+    settings: Res<Persistent<app::settings::Settings>>,
+    mut motion: ResMut<MotionPolicy>,
+) {
+    if settings.is_changed() {
+        motion.set_if_neq(MotionPolicy {
+            reduced: settings.is_enabled("reduced_motion"),
+        });
+    }
+}
+
+// NOTE: triggers aim by their own rotation, so the drawing is turned back instead
+fn steady_trigger_rotation(
+    motion: Res<MotionPolicy>,
+    particle_query: Query<(&Particle, &Transform, &Children)>,
+    mut child_query: Query<&mut Transform, Without<Particle>>,
+) {
+    for (particle, transform, children) in particle_query.iter() {
+        if particle.particle_type() != ParticleType::Trigger {
+            continue;
+        }
+        let rotation = if motion.reduced {
+            transform.rotation.inverse()
+        } else {
+            Quat::IDENTITY
+        };
+        for child in children.iter() {
+            if let Ok(mut child_transform) = child_query.get_mut(*child) {
+                child_transform.rotation = rotation;
+            }
+        }
+    }
+}
@@ -1,5 +1,6 @@
-use crate::reactor;
+use crate::{app::theme::Theme, reactor};
 use bevy::prelude::*;
+use bevy_prototype_lyon::prelude::*;
 use bevy_tweening::lens::*;
 use circular_queue::CircularQueue;
 use rand::{thread_rng, Rng};
@@ -7,21 +8,25 @@ use std::fmt;
 
 pub mod alpha;
 pub mod control;
+pub mod hunter;
 pub mod hyper;
 pub mod trigger;
 pub mod uou;
+pub mod well;
 
 pub const STARTING_DONE_EVENT: u64 = 0;
 pub const ENDING_DONE_EVENT: u64 = 1;
 const SIDE_THICKNESS: f32 = 2.0;
 
-#[derive(Component, Debug, PartialEq)]
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
 pub enum ParticleType {
     Alpha,
     Hyper,
     Control,
     Trigger,
     Uou,
+    Well,
+    Hunter,
 }
 
 #[derive(Debug, PartialEq)]
@@ -33,6 +38,64 @@ pub enum ParticleState {
     Dead,
 }
 
+// NOTE: shapes are spawned once under the canvas entity,
+// then their paths and paints are updated in place every frame
+#[derive(Component, Clone, Copy, PartialEq, Debug)]
+pub enum ParticleShape {
+    Body,
+    Level,
+    Countdown,
+    Core,
+    Range,
+}
+
+pub struct ShapeCanvas<'a> {
+    pub path: Mut<'a, Path>,
+    pub fill: Option<Mut<'a, Fill>>,
+    pub stroke: Option<Mut<'a, Stroke>>,
+    pub transform: Mut<'a, Transform>,
+    pub visibility: Mut<'a, Visibility>,
+}
+
+impl ShapeCanvas<'_> {
+    pub fn show(&mut self) {
+        self.visibility.set_if_neq(Visibility::Inherited);
+    }
+    pub fn hide(&mut self) {
+        self.visibility.set_if_neq(Visibility::Hidden);
+    }
+    pub fn draw(&mut self, path: Path, color: Color) {
+        self.show();
+        *self.path = path;
+        if let Some(fill) = self.fill.as_mut() {
+            fill.color = color;
+        }
+        if let Some(stroke) = self.stroke.as_mut() {
+            stroke.color = color;
+        }
+    }
+}
+
+pub fn build_retained_shape(
+    parent: &mut ChildBuilder,
+    marker: impl Component,
+    z: f32,
+    paint: impl Bundle,
+) {
+    parent.spawn((
+        ShapeBundle {
+            spatial: SpatialBundle {
+                transform: Transform::from_xyz(0.0, 0.0, z),
+                visibility: Visibility::Hidden,
+                ..default()
+            },
+            ..default()
+        },
+        paint,
+        marker,
+    ));
+}
+
 pub struct PosV {
     pub pos: Vec2,
     pub v: Vec2,
@@ -58,7 +121,7 @@ pub trait ParticleAbility {
     fn min_v(&self) -> f32;
     fn max_v(&self) -> f32;
     fn radius(&self) -> f32;
-    fn color(&self) -> Color;
+    fn color(&self, theme: &Theme) -> Color;
     fn current_countdown(&self) -> u32 {
         0
     }
@@ -82,15 +145,34 @@ pub trait ParticleAbility {
     fn tailings(&self) -> Option<&CircularQueue<Vec2>> {
         None
     }
+    fn clear_tailings(&mut self) {}
     fn record_tailing(&mut self, _pos: Vec2) {}
     fn is_traveling(&self, _particle: &Particle) -> bool {
         true
     }
-    fn state_setup(&self, _commands: &mut Commands, _particle: &Particle) -> ParticleState {
+    fn state_setup(
+        &self,
+        _commands: &mut Commands,
+        _particle: &Particle,
+        _theme: &Theme,
+    ) -> ParticleState {
         ParticleState::Running
     }
-    fn state_update(&self, _commands: &mut Commands, _particle: &Particle) {}
-    fn state_starting_done(&self, _commands: &mut Commands, _particle: &Particle) -> ParticleState {
+    fn redraw(
+        &self,
+        _particle: &Particle,
+        _shape: ParticleShape,
+        _canvas: &mut ShapeCanvas,
+        _motion: &reactor::motion::MotionPolicy,
+        _theme: &Theme,
+    ) {
+    }
+    fn state_starting_done(
+        &self,
+        _commands: &mut Commands,
+        _particle: &Particle,
+        _theme: &Theme,
+    ) -> ParticleState {
         ParticleState::Running
     }
 }
@@ -103,23 +185,60 @@ impl Particle {
         level: Option<u8>,
         root_entity: Entity,
         canvas_entity: Entity,
+        theme: &Theme,
     ) -> Self {
         match particle_type {
-            ParticleType::Alpha => {
-                alpha::Ability::gen_particle(pos, direction, level, root_entity, canvas_entity)
-            }
-            ParticleType::Hyper => {
-                hyper::Ability::gen_particle(pos, direction, level, root_entity, canvas_entity)
-            }
-            ParticleType::Control => {
-                control::Ability::gen_particle(pos, direction, level, root_entity, canvas_entity)
-            }
-            ParticleType::Trigger => {
-                trigger::Ability::gen_particle(pos, direction, level, root_entity, canvas_entity)
-            }
+            ParticleType::Alpha => alpha::Ability::gen_particle(
+                pos,
+                direction,
+                level,
+                root_entity,
+                canvas_entity,
+                theme,
+            ),
+            ParticleType::Hyper => hyper::Ability::gen_particle(
+                pos,
+                direction,
+                level,
+                root_entity,
+                canvas_entity,
+                theme,
+            ),
+            ParticleType::Control => control::Ability::gen_particle(
+                pos,
+                direction,
+                level,
+                root_entity,
+                canvas_entity,
+                theme,
+            ),
+            ParticleType::Trigger => trigger::Ability::gen_particle(
+                pos,
+                direction,
+                level,
+                root_entity,
+                canvas_entity,
+                theme,
+            ),
             ParticleType::Uou => {
-                uou::Ability::gen_particle(pos, direction, level, root_entity, canvas_entity)
+                uou::Ability::gen_particle(pos, direction, level, root_entity, canvas_entity, theme)
             }
+            ParticleType::Well => well::Ability::gen_particle(
+                pos,
+                direction,
+                level,
+                root_entity,
+                canvas_entity,
+                theme,
+            ),
+            ParticleType::Hunter => hunter::Ability::gen_particle(
+                pos,
+                direction,
+                level,
+                root_entity,
+                canvas_entity,
+                theme,
+            ),
         }
     }
     pub fn new(
@@ -129,13 +248,14 @@ impl Particle {
         level: Option<u8>,
         root_entity: Entity,
         canvas_entity: Entity,
+        theme: &Theme,
     ) -> Self {
         let level = match level {
             Some(level) => level.clamp(ability.min_level(), ability.max_level()),
             None => ability.min_level(),
         };
         let radius = ability.radius();
-        let color = ability.color();
+        let color = ability.color(theme);
         let v = ability.gen_random_v(direction);
         Self {
             radius,
@@ -187,6 +307,11 @@ impl Particle {
         self.pos = pos;
         self.ability.record_tailing(self.pos);
     }
+    // NOTE: a warp is a teleport, so the tail must not stretch across the field
+    pub fn warp(&mut self, pos: Vec2) {
+        self.ability.clear_tailings();
+        self.jump(pos);
+    }
     pub fn update_level(&mut self, delta: i32) {
         let new_level = (self.level as i32 + delta).clamp(
             self.ability.min_level() as i32,
@@ -212,16 +337,41 @@ impl Particle {
     pub fn assign_random_v(&mut self, direction: Option<Vec2>) {
         self.v = self.ability.gen_random_v(direction);
     }
-    pub fn state_setup(&mut self, commands: &mut Commands) {
-        self.state = self.ability.state_setup(commands, self);
+    pub fn state_setup(&mut self, commands: &mut Commands, theme: &Theme) {
+        self.state = self.ability.state_setup(commands, self, theme);
     }
-    pub fn state_update(&self, commands: &mut Commands) {
-        self.ability.state_update(commands, self);
+    pub fn redraw(
+        &self,
+        shape: ParticleShape,
+        canvas: &mut ShapeCanvas,
+        motion: &reactor::motion::MotionPolicy,
+        theme: &Theme,
+    ) {
+        self.ability.redraw(self, shape, canvas, motion, theme);
     }
-    pub fn state_starting_done(&mut self, commands: &mut Commands) {
-        self.state = self.ability.state_starting_done(commands, self);
+    pub fn state_starting_done(&mut self, commands: &mut Commands, theme: &Theme) {
+        self.state = self.ability.state_starting_done(commands, self, theme);
         self.reset_countdown();
     }
+    // NOTE: ending drops every running layer, so the canvas is rebuilt with the given shapes
+    pub fn rebuild_canvas(
+        &mut self,
+        commands: &mut Commands,
+        build_shapes: impl FnOnce(&mut ChildBuilder),
+    ) {
+        let Some(mut entity_commands) = commands.get_entity(self.root_entity) else {
+            return;
+        };
+        entity_commands.despawn_descendants();
+        let mut canvas_entity = Entity::PLACEHOLDER;
+        entity_commands.with_children(|parent| {
+            canvas_entity = parent
+                .spawn(SpatialBundle::default())
+                .with_children(build_shapes)
+                .id();
+        });
+        self.canvas_entity = canvas_entity;
+    }
     fn next_pos(pos: Vec2, v: Vec2, r: f32) -> Vec2 {
         let field_rect = reactor::field::get_field_rect(0.0);
         let mut new_pos = pos + v;
@@ -274,6 +424,45 @@ impl fmt::Debug for Particle {
     }
 }
 
+pub type RetainedShapeItem<'a, T> = (
+    Ref<'a, T>,
+    &'a Parent,
+    &'a mut Path,
+    Option<&'a mut Fill>,
+    Option<&'a mut Stroke>,
+    &'a mut Transform,
+    &'a mut Visibility,
+);
+
+pub fn redraw_particle_shapes(
+    particle_query: Query<Ref<Particle>>,
+    canvas_query: Query<&Parent, Without<ParticleShape>>,
+    mut shape_query: Query<RetainedShapeItem<ParticleShape>, Without<Particle>>,
+    motion: Res<reactor::motion::MotionPolicy>,
+    theme: Res<Theme>,
+) {
+    for (shape, parent, path, fill, stroke, transform, visibility) in shape_query.iter_mut() {
+        let Ok(root) = canvas_query.get(parent.get()) else {
+            continue;
+        };
+        let Ok(particle) = particle_query.get(root.get()) else {
+            continue;
+        };
+        if !(particle.is_changed() || shape.is_added() || motion.is_changed() || theme.is_changed())
+        {
+            continue;
+        }
+        let mut canvas = ShapeCanvas {
+            path,
+            fill,
+            stroke,
+            transform,
+            visibility,
+        };
+        particle.redraw(*shape, &mut canvas, &motion, &theme);
+    }
+}
+
 struct ParticleAnimeLens {
     start_radius: f32,
     start_color_alpha: f32,
@@ -1,18 +1,16 @@
-use crate::reactor::{field, particle::*};
-use bevy_prototype_lyon::prelude::*;
+use crate::reactor::{field, motion::MotionPolicy, particle::*};
 use bevy_tweening::*;
 use circular_queue::CircularQueue;
 use std::f32::consts::PI;
 use std::time::Duration;
 
 pub const RADIUS: f32 = 6.0;
-pub const COLOR: Color = Color::SILVER;
 const MIN_LEVEL: u8 = 1;
 const MAX_LEVEL: u8 = 5;
 const MIN_V: f32 = 0.3;
 const MAX_V: f32 = 1.0;
 const MAX_COUNTDOWN: u32 = 300;
-const TAILING_SIZE: usize = 5;
+pub const TAILING_SIZE: usize = 5;
 const TAILING_WINDOW: u8 = 3;
 
 pub struct Ability {
@@ -28,6 +26,7 @@ impl Ability {
         level: Option<u8>,
         root_entity: Entity,
         canvas_entity: Entity,
+        theme: &Theme,
     ) -> Particle {
         let level = level.unwrap_or(pick_random_alpha_level());
         let mut particle = Particle::new(
@@ -41,6 +40,7 @@ impl Ability {
             Some(level),
             root_entity,
             canvas_entity,
+            theme,
         );
         particle.reset_countdown();
         particle
@@ -54,8 +54,8 @@ impl ParticleAbility for Ability {
     fn radius(&self) -> f32 {
         RADIUS
     }
-    fn color(&self) -> Color {
-        COLOR
+    fn color(&self, theme: &Theme) -> Color {
+        theme.alpha
     }
     fn min_level(&self) -> u8 {
         MIN_LEVEL
@@ -87,6 +87,9 @@ impl ParticleAbility for Ability {
     fn tailings(&self) -> Option<&CircularQueue<Vec2>> {
         Some(&self.tailings)
     }
+    fn clear_tailings(&mut self) {
+        self.tailings.clear();
+    }
     fn record_tailing(&mut self, pos: Vec2) {
         if self.tailing_counter == 0 {
             self.tailings.push(pos);
@@ -98,11 +101,35 @@ impl ParticleAbility for Ability {
     fn is_traveling(&self, particle: &Particle) -> bool {
         particle.state == ParticleState::Running
     }
-    fn state_setup(&self, commands: &mut Commands, particle: &Particle) -> ParticleState {
-        setup_particle_running(commands, particle)
+    fn state_setup(
+        &self,
+        commands: &mut Commands,
+        particle: &Particle,
+        theme: &Theme,
+    ) -> ParticleState {
+        setup_particle_running(commands, particle, theme)
     }
-    fn state_update(&self, commands: &mut Commands, particle: &Particle) {
-        update_particle_ending(commands, particle);
+    fn redraw(
+        &self,
+        particle: &Particle,
+        shape: ParticleShape,
+        canvas: &mut ShapeCanvas,
+        motion: &MotionPolicy,
+        _theme: &Theme,
+    ) {
+        match (shape, &particle.state) {
+            (ParticleShape::Body, ParticleState::Ending) => {
+                let shape = shapes::Circle {
+                    radius: motion.steady_radius(particle.radius, RADIUS),
+                    center: Vec2::new(0.0, 0.0),
+                };
+                canvas.draw(
+                    GeometryBuilder::build_as(&shape),
+                    motion.flash_color(particle.color),
+                );
+            }
+            _ => canvas.hide(),
+        }
     }
 }
 
@@ -112,6 +139,7 @@ pub fn build_particle_sprite(
     pos: Option<Vec2>,
     direction: Option<Vec2>,
     level: Option<u8>,
+    theme: &Theme,
 ) {
     let pos = match pos {
         Some(pos) => pos,
@@ -123,7 +151,7 @@ pub fn build_particle_sprite(
             SpriteBundle {
                 transform: Transform::from_xyz(pos.x, pos.y, 0.0),
                 sprite: Sprite {
-                    color: COLOR,
+                    color: theme.alpha,
                     ..default()
                 },
                 ..default()
@@ -135,7 +163,7 @@ pub fn build_particle_sprite(
                 .spawn(SpriteBundle {
                     transform: Transform::from_xyz(0.0, 0.0, 0.1),
                     sprite: Sprite {
-                        color: COLOR,
+                        color: theme.alpha,
                         ..default()
                     },
                     ..default()
@@ -150,18 +178,23 @@ pub fn build_particle_sprite(
         level,
         root_entity,
         canvas_entity,
+        theme,
     );
     commands.entity(root_entity).insert(particle);
 }
 
-pub fn setup_particle_running(commands: &mut Commands, particle: &Particle) -> ParticleState {
+pub fn setup_particle_running(
+    commands: &mut Commands,
+    particle: &Particle,
+    theme: &Theme,
+) -> ParticleState {
     if let Some(mut entity_commands) = commands.get_entity(particle.root_entity()) {
         entity_commands.with_children(|parent| {
             parent
                 .spawn(SpriteBundle {
                     transform: Transform::from_xyz(0.0, 0.0, 0.2),
                     sprite: Sprite {
-                        color: COLOR,
+                        color: theme.alpha,
                         ..default()
                     },
                     ..default()
@@ -176,7 +209,7 @@ pub fn setup_particle_running(commands: &mut Commands, particle: &Particle) -> P
                             path: GeometryBuilder::build_as(&shape),
                             ..default()
                         },
-                        Fill::color(COLOR),
+                        Fill::color(theme.alpha),
                     ));
                 });
             if particle.level() > 1 {
@@ -184,7 +217,7 @@ pub fn setup_particle_running(commands: &mut Commands, particle: &Particle) -> P
                     .spawn(SpriteBundle {
                         transform: Transform::from_xyz(0.0, 0.0, 0.3),
                         sprite: Sprite {
-                            color: COLOR,
+                            color: theme.alpha,
                             ..default()
                         },
                         ..default()
@@ -205,7 +238,7 @@ pub fn setup_particle_running(commands: &mut Commands, particle: &Particle) -> P
                                 path: path_builder.build(),
                                 ..default()
                             },
-                            Stroke::new(COLOR, SIDE_THICKNESS),
+                            Stroke::new(theme.alpha, SIDE_THICKNESS),
                         ));
                     });
             }
@@ -214,34 +247,12 @@ pub fn setup_particle_running(commands: &mut Commands, particle: &Particle) -> P
     ParticleState::Running
 }
 
-pub fn setup_particle_ending(commands: &mut Commands, particle: &mut Particle) {
+pub fn setup_particle_ending(commands: &mut Commands, particle: &mut Particle, theme: &Theme) {
     particle.state = ParticleState::Ending;
+    particle.rebuild_canvas(commands, |parent| {
+        build_retained_shape(parent, ParticleShape::Body, 0.1, Fill::color(theme.alpha));
+    });
     if let Some(mut entity_commands) = commands.get_entity(particle.root_entity()) {
-        entity_commands.despawn_descendants();
-        entity_commands.with_children(|parent| {
-            parent
-                .spawn(SpriteBundle {
-                    transform: Transform::from_xyz(0.0, 0.0, 0.1),
-                    sprite: Sprite {
-                        color: COLOR,
-                        ..default()
-                    },
-                    ..default()
-                })
-                .with_children(|parent| {
-                    let shape = shapes::Circle {
-                        radius: particle.radius,
-                        center: Vec2::new(0.0, 0.0),
-                    };
-                    parent.spawn((
-                        ShapeBundle {
-                            path: GeometryBuilder::build_as(&shape),
-                            ..default()
-                        },
-                        Fill::color(particle.color),
-                    ));
-                });
-        });
         let tween = Tween::new(
             EaseFunction::QuadraticIn,
             Duration::from_millis(500),
@@ -257,39 +268,6 @@ pub fn setup_particle_ending(commands: &mut Commands, particle: &mut Particle) {
     }
 }
 
-pub fn update_particle_ending(commands: &mut Commands, particle: &Particle) {
-    if particle.state != ParticleState::Ending {
-        return;
-    }
-    if let Some(mut entity_commands) = commands.get_entity(particle.root_entity()) {
-        entity_commands.despawn_descendants();
-        entity_commands.with_children(|parent| {
-            parent
-                .spawn(SpriteBundle {
-                    transform: Transform::from_xyz(0.0, 0.0, 0.1),
-                    sprite: Sprite {
-                        color: COLOR,
-                        ..default()
-                    },
-                    ..default()
-                })
-                .with_children(|parent| {
-                    let shape = shapes::Circle {
-                        radius: particle.radius,
-                        center: Vec2::new(0.0, 0.0),
-                    };
-                    parent.spawn((
-                        ShapeBundle {
-                            path: GeometryBuilder::build_as(&shape),
-                            ..default()
-                        },
-                        Fill::color(particle.color),
-                    ));
-                });
-        });
-    }
-}
-
 pub fn pick_random_alpha_level() -> u8 {
     let mut rng = thread_rng();
     let pick = rng.gen_range(0.0..100.0);
@@ -1,21 +1,21 @@
-use crate::{
-    app,
-    reactor::{field, particle::*},
-};
-use bevy_prototype_lyon::prelude::*;
+use crate::reactor::{field, motion::MotionPolicy, particle::*};
 use bevy_tweening::*;
+use circular_queue::CircularQueue;
 use std::f32::consts::{PI, TAU};
 use std::time::Duration;
 
-pub const COLOR: Color = Color::LIME_GREEN;
 pub const MAX_LEVEL: u8 = 8;
 const MIN_LEVEL: u8 = 1;
 const MIN_V: f32 = 2.0;
 const MAX_V: f32 = 4.0;
 const RADIUS: f32 = 12.0;
+pub const TAILING_SIZE: usize = 5;
+const TAILING_WINDOW: u8 = 3;
 
 pub struct Ability {
     countdown: u32,
+    tailings: CircularQueue<Vec2>,
+    tailing_counter: u8,
 }
 
 impl Ability {
@@ -25,14 +25,20 @@ impl Ability {
         level: Option<u8>,
         root_entity: Entity,
         canvas_entity: Entity,
+        theme: &Theme,
     ) -> Particle {
         let mut particle = Particle::new(
-            Box::new(Ability { countdown: 0 }),
+            Box::new(Ability {
+                countdown: 0,
+                tailings: CircularQueue::with_capacity(TAILING_SIZE),
+                tailing_counter: 0,
+            }),
             pos,
             direction,
             level,
             root_entity,
             canvas_entity,
+            theme,
         );
         particle.reset_countdown();
         particle
@@ -46,8 +52,8 @@ impl ParticleAbility for Ability {
     fn radius(&self) -> f32 {
         RADIUS
     }
-    fn color(&self) -> Color {
-        COLOR
+    fn color(&self, theme: &Theme) -> Color {
+        theme.control
     }
     fn min_level(&self) -> u8 {
         MIN_LEVEL
@@ -76,19 +82,64 @@ impl ParticleAbility for Ability {
         }
         self.countdown
     }
+    fn tailings(&self) -> Option<&CircularQueue<Vec2>> {
+        Some(&self.tailings)
+    }
+    fn clear_tailings(&mut self) {
+        self.tailings.clear();
+    }
+    fn record_tailing(&mut self, pos: Vec2) {
+        if self.tailing_counter == 0 {
+            self.tailings.push(pos);
+            self.tailing_counter = TAILING_WINDOW;
+        } else {
+            self.tailing_counter -= 1;
+        }
+    }
     fn is_traveling(&self, particle: &Particle) -> bool {
         particle.state != ParticleState::Starting
     }
-    fn state_setup(&self, commands: &mut Commands, particle: &Particle) -> ParticleState {
+    fn state_setup(
+        &self,
+        commands: &mut Commands,
+        particle: &Particle,
+        _theme: &Theme,
+    ) -> ParticleState {
         setup_particle_starting(commands, particle)
     }
-    fn state_update(&self, commands: &mut Commands, particle: &Particle) {
-        update_particle_starting(commands, particle);
-        update_particle_running(commands, particle);
-        update_particle_ending(commands, particle);
+    fn redraw(
+        &self,
+        particle: &Particle,
+        shape: ParticleShape,
+        canvas: &mut ShapeCanvas,
+        motion: &MotionPolicy,
+        theme: &Theme,
+    ) {
+        match (shape, &particle.state) {
+            (ParticleShape::Body, ParticleState::Starting) => canvas.draw(
+                build_body_path(motion.steady_radius(particle.radius, RADIUS)),
+                particle.color,
+            ),
+            (ParticleShape::Body, ParticleState::Ending) => {
+                canvas.draw(build_body_path(RADIUS), particle.color)
+            }
+            (ParticleShape::Level, ParticleState::Running) => {
+                canvas.draw(build_level_path(particle.level_ratio()), theme.control)
+            }
+            (ParticleShape::Countdown, ParticleState::Running) => canvas.draw(
+                build_countdown_path(particle.countdown_ratio()),
+                theme.control,
+            ),
+            _ => canvas.hide(),
+        }
     }
-    fn state_starting_done(&self, commands: &mut Commands, particle: &Particle) -> ParticleState {
-        setup_particle_running(commands, particle)
+    fn state_starting_done(
+        &self,
+        commands: &mut Commands,
+        particle: &Particle,
+        theme: &Theme,
+    ) -> ParticleState {
+        setup_particle_running(commands, particle, theme)
     }
 }
 
@@ -98,6 +149,7 @@ pub fn build_particle_sprite(
     pos: Option<Vec2>,
     direction: Option<Vec2>,
     level: Option<u8>,
+    theme: &Theme,
 ) {
     let pos = match pos {
         Some(pos) => pos,
@@ -109,7 +161,7 @@ pub fn build_particle_sprite(
             SpriteBundle {
                 transform: Transform::from_xyz(pos.x, pos.y, 0.0),
                 sprite: Sprite {
-                    color: COLOR,
+                    color: theme.control,
                     ..default()
                 },
                 ..default()
@@ -121,11 +173,31 @@ pub fn build_particle_sprite(
                 .spawn(SpriteBundle {
                     transform: Transform::from_xyz(0.0, 0.0, 0.4),
                     sprite: Sprite {
-                        color: COLOR,
+                        color: theme.control,
                         ..default()
                     },
                     ..default()
                 })
+                .with_children(|parent| {
+                    build_retained_shape(
+                        parent,
+                        ParticleShape::Body,
+                        0.1,
+                        Fill::color(theme.control),
+                    );
+                    build_retained_shape(
+                        parent,
+                        ParticleShape::Level,
+                        0.4,
+                        Fill::color(theme.control),
+                    );
+                    build_retained_shape(
+                        parent,
+                        ParticleShape::Countdown,
+                        0.4,
+                        Stroke::new(theme.control, SIDE_THICKNESS),
+                    );
+                })
                 .id();
         })
         .id();
@@ -136,6 +208,7 @@ pub fn build_particle_sprite(
         level,
         root_entity,
         canvas_entity,
+        theme,
     );
     commands.entity(root_entity).insert(particle);
 }
@@ -158,50 +231,18 @@ pub fn setup_particle_starting(commands: &mut Commands, particle: &Particle) ->
     ParticleState::Starting
 }
 
-pub fn update_particle_starting(commands: &mut Commands, particle: &Particle) {
-    if particle.state != ParticleState::Starting {
-        return;
-    }
-    if let Some(mut entity_commands) = commands.get_entity(particle.canvas_entity()) {
-        entity_commands.despawn_descendants();
-        entity_commands.with_children(|parent| {
-            parent
-                .spawn(SpriteBundle {
-                    transform: Transform::from_xyz(0.0, 0.0, 0.1),
-                    sprite: Sprite {
-                        color: COLOR,
-                        ..default()
-                    },
-                    ..default()
-                })
-                .with_children(|parent| {
-                    let shape = shapes::RegularPolygon {
-                        sides: 4,
-                        feature: shapes::RegularPolygonFeature::Radius(
-                            particle.radius * 2.0_f32.sqrt(),
-                        ),
-                        ..shapes::RegularPolygon::default()
-                    };
-                    parent.spawn((
-                        ShapeBundle {
-                            path: GeometryBuilder::build_as(&shape),
-                            ..default()
-                        },
-                        Fill::color(particle.color),
-                    ));
-                });
-        });
-    }
-}
-
-pub fn setup_particle_running(commands: &mut Commands, particle: &Particle) -> ParticleState {
+pub fn setup_particle_running(
+    commands: &mut Commands,
+    particle: &Particle,
+    theme: &Theme,
+) -> ParticleState {
     if let Some(mut entity_commands) = commands.get_entity(particle.root_entity()) {
         entity_commands.with_children(|parent| {
             parent
                 .spawn(SpriteBundle {
                     transform: Transform::from_xyz(0.0, 0.0, 0.1),
                     sprite: Sprite {
-                        color: COLOR,
+                        color: theme.control,
                         ..default()
                     },
                     ..default()
@@ -217,14 +258,14 @@ pub fn setup_particle_running(commands: &mut Commands, particle: &Particle) -> P
                             path: GeometryBuilder::build_as(&shape),
                             ..default()
                         },
-                        Fill::color(COLOR),
+                        Fill::color(theme.control),
                     ));
                 });
             parent
                 .spawn(SpriteBundle {
                     transform: Transform::from_xyz(0.0, 0.0, 0.2),
                     sprite: Sprite {
-                        color: COLOR,
+                        color: theme.control,
                         ..default()
                     },
                     ..default()
@@ -240,14 +281,14 @@ pub fn setup_particle_running(commands: &mut Commands, particle: &Particle) -> P
                             path: GeometryBuilder::build_as(&shape),
                             ..default()
                         },
-                        Fill::color(app::ui::BG_COLOR),
+                        Fill::color(theme.bg),
                     ));
                 });
             parent
                 .spawn(SpriteBundle {
                     transform: Transform::from_xyz(0.0, 0.0, 0.3),
                     sprite: Sprite {
-                        color: COLOR,
+                        color: theme.control,
                         ..default()
                     },
                     ..default()
@@ -264,7 +305,7 @@ pub fn setup_particle_running(commands: &mut Commands, particle: &Particle) -> P
                             path: path_builder.build(),
                             ..default()
                         },
-                        Stroke::new(COLOR, SIDE_THICKNESS),
+                        Stroke::new(theme.control, SIDE_THICKNESS),
                     ));
                 });
         });
@@ -272,93 +313,12 @@ pub fn setup_particle_running(commands: &mut Commands, particle: &Particle) -> P
     ParticleState::Running
 }
 
-pub fn update_particle_running(commands: &mut Commands, particle: &Particle) {
-    if particle.state != ParticleState::Running {
-        return;
-    }
-    if let Some(mut entity_commands) = commands.get_entity(particle.canvas_entity()) {
-        entity_commands.despawn_descendants();
-        entity_commands.with_children(|parent| {
-            parent
-                .spawn(SpriteBundle {
-                    transform: Transform::from_xyz(0.0, 0.0, 0.4),
-                    sprite: Sprite {
-                        color: COLOR,
-                        ..default()
-                    },
-                    ..default()
-                })
-                .with_children(|parent| {
-                    let level_ratio = particle.level_ratio();
-                    let level_angle = TAU * level_ratio;
-                    let start_angle = PI * 2.5 - level_angle;
-                    let mut path_builder = PathBuilder::new();
-                    path_builder.move_to(Vec2::new(0.0, 0.0));
-                    path_builder.line_to(Vec2::from_angle(start_angle) * RADIUS * 0.8);
-                    path_builder.arc(
-                        Vec2::default(),
-                        Vec2::new(RADIUS * 0.8, RADIUS * 0.8),
-                        level_angle,
-                        0.0,
-                    );
-                    path_builder.close();
-                    parent.spawn((
-                        ShapeBundle {
-                            path: path_builder.build(),
-                            ..default()
-                        },
-                        Fill::color(COLOR),
-                    ));
-                    let side_ratio = particle.countdown_ratio();
-                    let mut path_builder = PathBuilder::new();
-                    path_builder.move_to(Vec2::new(0.0, RADIUS * 1.5));
-                    path_builder.arc(
-                        Vec2::default(),
-                        Vec2::new(RADIUS * 1.5, RADIUS * 1.5),
-                        -TAU * side_ratio,
-                        0.0,
-                    );
-                    parent.spawn((
-                        ShapeBundle {
-                            path: path_builder.build(),
-                            ..default()
-                        },
-                        Stroke::new(COLOR, SIDE_THICKNESS),
-                    ));
-                });
-        });
-    }
-}
-
-pub fn setup_particle_ending(commands: &mut Commands, particle: &mut Particle) {
+pub fn setup_particle_ending(commands: &mut Commands, particle: &mut Particle, theme: &Theme) {
     particle.state = ParticleState::Ending;
+    particle.rebuild_canvas(commands, |parent| {
+        build_retained_shape(parent, ParticleShape::Body, 0.1, Fill::color(theme.control));
+    });
     if let Some(mut entity_commands) = commands.get_entity(particle.root_entity()) {
-        entity_commands.despawn_descendants();
-        entity_commands.with_children(|parent| {
-            parent
-                .spawn(SpriteBundle {
-                    transform: Transform::from_xyz(0.0, 0.0, 0.1),
-                    sprite: Sprite {
-                        color: COLOR,
-                        ..default()
-                    },
-                    ..default()
-                })
-                .with_children(|parent| {
-                    let shape = shapes::RegularPolygon {
-                        sides: 4,
-                        feature: shapes::RegularPolygonFeature::Radius(RADIUS * 2.0_f32.sqrt()),
-                        ..shapes::RegularPolygon::default()
-                    };
-                    parent.spawn((
-                        ShapeBundle {
-                            path: GeometryBuilder::build_as(&shape),
-                            ..default()
-                        },
-                        Fill::color(particle.color),
-                    ));
-                });
-        });
         let tween = Tween::new(
             EaseFunction::QuadraticIn,
             Duration::from_millis(300),
@@ -374,36 +334,39 @@ pub fn setup_particle_ending(commands: &mut Commands, particle: &mut Particle) {
     }
 }
 
-pub fn update_particle_ending(commands: &mut Commands, particle: &Particle) {
-    if particle.state != ParticleState::Ending {
-        return;
-    }
-    if let Some(mut entity_commands) = commands.get_entity(particle.root_entity()) {
-        entity_commands.despawn_descendants();
-        entity_commands.with_children(|parent| {
-            parent
-                .spawn(SpriteBundle {
-                    transform: Transform::from_xyz(0.0, 0.0, 0.1),
-                    sprite: Sprite {
-                        color: COLOR,
-                        ..default()
-                    },
-                    ..default()
-                })
-                .with_children(|parent| {
-                    let shape = shapes::RegularPolygon {
-                        sides: 4,
-                        feature: shapes::RegularPolygonFeature::Radius(RADIUS * 2.0_f32.sqrt()),
-                        ..shapes::RegularPolygon::default()
-                    };
-                    parent.spawn((
-                        ShapeBundle {
-                            path: GeometryBuilder::build_as(&shape),
-                            ..default()
-                        },
-                        Fill::color(particle.color),
-                    ));
-                });
-        });
-    }
+fn build_body_path(radius: f32) -> Path {
+    let shape = shapes::RegularPolygon {
+        sides: 4,
+        feature: shapes::RegularPolygonFeature::Radius(radius * 2.0_f32.sqrt()),
+        ..shapes::RegularPolygon::default()
+    };
+    GeometryBuilder::build_as(&shape)
+}
+
+fn build_level_path(level_ratio: f32) -> Path {
+    let level_angle = TAU * level_ratio;
+    let start_angle = PI * 2.5 - level_angle;
+    let mut path_builder = PathBuilder::new();
+    path_builder.move_to(Vec2::new(0.0, 0.0));
+    path_builder.line_to(Vec2::from_angle(start_angle) * RADIUS * 0.8);
+    path_builder.arc(
+        Vec2::default(),
+        Vec2::new(RADIUS * 0.8, RADIUS * 0.8),
+        level_angle,
+        0.0,
+    );
+    path_builder.close();
+    path_builder.build()
+}
+
+fn build_countdown_path(side_ratio: f32) -> Path {
+    let mut path_builder = PathBuilder::new();
+    path_builder.move_to(Vec2::new(0.0, RADIUS * 1.5));
+    path_builder.arc(
+        Vec2::default(),
+        Vec2::new(RADIUS * 1.5, RADIUS * 1.5),
+        -TAU * side_ratio,
+        0.0,
+    );
+    path_builder.build()
 }
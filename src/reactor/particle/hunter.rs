@@ -0,0 +1,357 @@
+use crate::reactor::{field, motion::MotionPolicy, particle::*};
+use bevy_tweening::*;
+use std::f32::consts::{PI, TAU};
+use std::time::Duration;
+
+const MIN_LEVEL: u8 = 1;
+const MAX_LEVEL: u8 = 1;
+const MIN_V: f32 = 1.0;
+const MAX_V: f32 = 1.6;
+const RADIUS: f32 = 9.0;
+const AIM_TICKS: u32 = 40;
+const MAX_TURN: f32 = PI / 6.0;
+const SPAWN_MIN_DISTANCE: f32 = 160.0;
+
+pub struct Ability {
+    countdown: u32,
+}
+
+impl Ability {
+    pub fn gen_particle(
+        pos: Vec2,
+        direction: Option<Vec2>,
+        level: Option<u8>,
+        root_entity: Entity,
+        canvas_entity: Entity,
+        theme: &Theme,
+    ) -> Particle {
+        let mut particle = Particle::new(
+            Box::new(Ability { countdown: 0 }),
+            pos,
+            direction,
+            level,
+            root_entity,
+            canvas_entity,
+            theme,
+        );
+        particle.reset_countdown();
+        particle
+    }
+}
+
+impl ParticleAbility for Ability {
+    fn particle_type(&self) -> ParticleType {
+        ParticleType::Hunter
+    }
+    fn radius(&self) -> f32 {
+        RADIUS
+    }
+    fn color(&self, theme: &Theme) -> Color {
+        theme.hunter
+    }
+    fn min_level(&self) -> u8 {
+        MIN_LEVEL
+    }
+    fn max_level(&self) -> u8 {
+        MAX_LEVEL
+    }
+    fn min_v(&self) -> f32 {
+        MIN_V
+    }
+    fn max_v(&self) -> f32 {
+        MAX_V
+    }
+    fn current_countdown(&self) -> u32 {
+        self.countdown
+    }
+    fn max_countdown(&self, _level: u8) -> u32 {
+        AIM_TICKS
+    }
+    fn reset_countdown(&mut self, level: u8) {
+        self.countdown = self.max_countdown(level);
+    }
+    fn tick_countdown(&mut self) -> u32 {
+        if self.countdown > 0 {
+            self.countdown -= 1;
+        }
+        self.countdown
+    }
+    fn is_traveling(&self, particle: &Particle) -> bool {
+        particle.state == ParticleState::Running
+    }
+    fn state_setup(
+        &self,
+        commands: &mut Commands,
+        particle: &Particle,
+        _theme: &Theme,
+    ) -> ParticleState {
+        setup_particle_starting(commands, particle)
+    }
+    fn redraw(
+        &self,
+        particle: &Particle,
+        shape: ParticleShape,
+        canvas: &mut ShapeCanvas,
+        motion: &MotionPolicy,
+        theme: &Theme,
+    ) {
+        match (shape, &particle.state) {
+            (ParticleShape::Body, ParticleState::Starting) => canvas.draw(
+                build_body_path(motion.steady_radius(particle.radius, RADIUS), particle.v()),
+                particle.color,
+            ),
+            (ParticleShape::Body, ParticleState::Running) => {
+                canvas.draw(build_body_path(RADIUS, particle.v()), theme.hunter)
+            }
+            (ParticleShape::Body, ParticleState::Ending) => canvas.draw(
+                build_body_path(particle.radius, particle.v()),
+                particle.color,
+            ),
+            (ParticleShape::Countdown, ParticleState::Running) => canvas.draw(
+                build_countdown_path(particle.countdown_ratio()),
+                theme.hunter,
+            ),
+            _ => canvas.hide(),
+        }
+    }
+    fn state_starting_done(
+        &self,
+        _commands: &mut Commands,
+        _particle: &Particle,
+        _theme: &Theme,
+    ) -> ParticleState {
+        ParticleState::Running
+    }
+}
+
+pub fn build_particle_sprite(
+    commands: &mut Commands,
+    bundle: impl Bundle,
+    pos: Option<Vec2>,
+    direction: Option<Vec2>,
+    level: Option<u8>,
+    theme: &Theme,
+) {
+    let pos = match pos {
+        Some(pos) => pos,
+        None => field::gen_random_pos_in_field(RADIUS * 2.0),
+    };
+    let mut canvas_entity: Entity = Entity::PLACEHOLDER;
+    let root_entity = commands
+        .spawn((
+            SpriteBundle {
+                transform: Transform::from_xyz(pos.x, pos.y, 0.0),
+                sprite: Sprite {
+                    color: theme.hunter,
+                    ..default()
+                },
+                ..default()
+            },
+            bundle,
+        ))
+        .with_children(|parent| {
+            canvas_entity = parent
+                .spawn(SpriteBundle {
+                    transform: Transform::from_xyz(0.0, 0.0, 0.3),
+                    sprite: Sprite {
+                        color: theme.hunter,
+                        ..default()
+                    },
+                    ..default()
+                })
+                .with_children(|parent| {
+                    build_retained_shape(
+                        parent,
+                        ParticleShape::Body,
+                        0.1,
+                        Fill::color(theme.hunter),
+                    );
+                    build_retained_shape(
+                        parent,
+                        ParticleShape::Countdown,
+                        0.3,
+                        Stroke::new(theme.hunter, SIDE_THICKNESS),
+                    );
+                })
+                .id();
+        })
+        .id();
+    let particle = Particle::create(
+        ParticleType::Hunter,
+        pos,
+        direction,
+        level,
+        root_entity,
+        canvas_entity,
+        theme,
+    );
+    commands.entity(root_entity).insert(particle);
+}
+
+pub fn setup_particle_starting(commands: &mut Commands, particle: &Particle) -> ParticleState {
+    if let Some(mut entity_commands) = commands.get_entity(particle.root_entity()) {
+        let tween = Tween::new(
+            EaseFunction::QuadraticIn,
+            Duration::from_millis(800),
+            ParticleAnimeLens {
+                start_radius: RADIUS * 2.5,
+                start_color_alpha: 0.0,
+                end_radius: RADIUS,
+                end_color_alpha: 0.5,
+            },
+        )
+        .with_completed_event(STARTING_DONE_EVENT);
+        entity_commands.insert(Animator::new(tween));
+    }
+    ParticleState::Starting
+}
+
+pub fn setup_particle_ending(commands: &mut Commands, particle: &mut Particle, theme: &Theme) {
+    particle.state = ParticleState::Ending;
+    particle.rebuild_canvas(commands, |parent| {
+        build_retained_shape(parent, ParticleShape::Body, 0.1, Fill::color(theme.hunter));
+    });
+    if let Some(mut entity_commands) = commands.get_entity(particle.root_entity()) {
+        let tween = Tween::new(
+            EaseFunction::QuadraticIn,
+            Duration::from_millis(500),
+            ParticleAnimeLens {
+                start_radius: RADIUS,
+                start_color_alpha: 0.5,
+                end_radius: RADIUS * 4.0,
+                end_color_alpha: 0.0,
+            },
+        )
+        .with_completed_event(ENDING_DONE_EVENT);
+        entity_commands.insert(Animator::new(tween));
+    }
+}
+
+// NOTE: the dart points along the velocity, so the player can read where it is heading
+fn build_body_path(radius: f32, v: Vec2) -> Path {
+    let direction = v.try_normalize().unwrap_or(Vec2::Y);
+    let side = direction.perp();
+    let mut path_builder = PathBuilder::new();
+    path_builder.move_to(direction * radius * 1.4);
+    path_builder.line_to(-direction * radius + side * radius * 0.8);
+    path_builder.line_to(-direction * radius * 0.4);
+    path_builder.line_to(-direction * radius - side * radius * 0.8);
+    path_builder.close();
+    path_builder.build()
+}
+
+fn build_countdown_path(side_ratio: f32) -> Path {
+    let mut path_builder = PathBuilder::new();
+    path_builder.move_to(Vec2::new(0.0, RADIUS * 1.8));
+    path_builder.arc(
+        Vec2::default(),
+        Vec2::new(RADIUS * 1.8, RADIUS * 1.8),
+        TAU * side_ratio,
+        0.0,
+    );
+    path_builder.build()
+}
+
+// NOTE: the turn is capped, so a sharp sidestep right before the re-aim shakes it off
+pub fn aim(particle: &mut Particle, target: Vec2) {
+    let v = particle.v();
+    let Some(to_target) = (target - particle.pos()).try_normalize() else {
+        return;
+    };
+    let angle = v.angle_between(to_target);
+    if angle.is_nan() {
+        return;
+    }
+    let turn = angle.clamp(-MAX_TURN, MAX_TURN);
+    particle.set_v(Vec2::from_angle(turn).rotate(v));
+}
+
+pub fn gen_spawn_pos(u_pos: Vec2) -> Vec2 {
+    let pos = field::gen_random_pos_in_field(RADIUS * 2.0);
+    if pos.distance(u_pos) >= SPAWN_MIN_DISTANCE {
+        return pos;
+    }
+    let field_rect = field::get_field_rect(RADIUS * 2.0);
+    let away = (pos - u_pos).try_normalize().unwrap_or(Vec2::Y);
+    (u_pos + away * SPAWN_MIN_DISTANCE).clamp(field_rect.min, field_rect.max)
+}
+
+const INIT_SURVIVAL_TIME: u32 = 3000;
+const MAX_COUNT: u32 = 3;
+const RESPAWN_TIME: u32 = 1500;
+
+pub fn fetch_target_count(survival_time: u32) -> usize {
+    (survival_time / INIT_SURVIVAL_TIME).min(MAX_COUNT) as usize
+}
+
+// NOTE: a destroyed hunter is only replaced after a while, so kills can't be farmed
+pub fn is_respawn_ready(time_since_kill: Option<u32>) -> bool {
+    match time_since_kill {
+        Some(time) => time >= RESPAWN_TIME,
+        None => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::theme::ThemePreset;
+
+    fn gen_hunter(pos: Vec2, v: Vec2) -> Particle {
+        let theme = Theme::from_preset(ThemePreset::Default);
+        let mut particle = Particle::create(
+            ParticleType::Hunter,
+            pos,
+            None,
+            None,
+            Entity::PLACEHOLDER,
+            Entity::PLACEHOLDER,
+            &theme,
+        );
+        particle.set_v(v);
+        particle
+    }
+
+    #[test]
+    fn turns_toward_a_target_ahead() {
+        let mut hunter = gen_hunter(Vec2::ZERO, Vec2::new(1.0, 0.0));
+        aim(&mut hunter, Vec2::new(100.0, 10.0));
+        let expected = Vec2::new(100.0, 10.0).normalize();
+        assert!((hunter.v() - expected).length() < 1e-4);
+    }
+
+    #[test]
+    fn caps_the_turn_at_the_same_speed() {
+        let mut hunter = gen_hunter(Vec2::ZERO, Vec2::new(1.5, 0.0));
+        aim(&mut hunter, Vec2::new(0.0, 100.0));
+        assert!((hunter.v().length() - 1.5).abs() < 1e-4);
+        assert!((Vec2::X.angle_between(hunter.v()) - MAX_TURN).abs() < 1e-4);
+    }
+
+    #[test]
+    fn keeps_its_heading_on_the_target() {
+        let mut hunter = gen_hunter(Vec2::new(5.0, 5.0), Vec2::new(1.0, 0.0));
+        aim(&mut hunter, Vec2::new(5.0, 5.0));
+        assert_eq!(hunter.v(), Vec2::new(1.0, 0.0));
+    }
+
+    #[test]
+    fn adds_hunters_with_survival_time() {
+        assert_eq!(fetch_target_count(0), 0);
+        assert_eq!(fetch_target_count(INIT_SURVIVAL_TIME - 1), 0);
+        assert_eq!(fetch_target_count(INIT_SURVIVAL_TIME), 1);
+        assert_eq!(fetch_target_count(INIT_SURVIVAL_TIME * 2), 2);
+        assert_eq!(
+            fetch_target_count(INIT_SURVIVAL_TIME * 10),
+            MAX_COUNT as usize
+        );
+    }
+
+    #[test]
+    fn holds_respawns_after_a_kill() {
+        assert!(is_respawn_ready(None));
+        assert!(!is_respawn_ready(Some(0)));
+        assert!(!is_respawn_ready(Some(RESPAWN_TIME - 1)));
+        assert!(is_respawn_ready(Some(RESPAWN_TIME)));
+    }
+}
@@ -1,10 +1,8 @@
-use crate::reactor::{field, particle::*};
-use bevy_prototype_lyon::prelude::*;
+use crate::reactor::{field, motion::MotionPolicy, particle::*};
 use bevy_tweening::*;
 use std::f32::consts::TAU;
 use std::time::Duration;
 
-pub const COLOR: Color = Color::rgb(1.0, 0.39, 0.29);
 const MIN_LEVEL: u8 = 1;
 const MAX_LEVEL: u8 = 5;
 const MIN_V: f32 = 0.5;
@@ -22,6 +20,7 @@ impl Ability {
         level: Option<u8>,
         root_entity: Entity,
         canvas_entity: Entity,
+        theme: &Theme,
     ) -> Particle {
         let mut particle = Particle::new(
             Box::new(Ability { countdown: 0 }),
@@ -30,6 +29,7 @@ impl Ability {
             level,
             root_entity,
             canvas_entity,
+            theme,
         );
         particle.reset_countdown();
         particle
@@ -43,8 +43,8 @@ impl ParticleAbility for Ability {
     fn radius(&self) -> f32 {
         RADIUS
     }
-    fn color(&self) -> Color {
-        COLOR
+    fn color(&self, theme: &Theme) -> Color {
+        theme.trigger
     }
     fn min_level(&self) -> u8 {
         MIN_LEVEL
@@ -76,15 +76,41 @@ impl ParticleAbility for Ability {
     fn is_traveling(&self, particle: &Particle) -> bool {
         particle.state == ParticleState::Running
     }
-    fn state_setup(&self, commands: &mut Commands, particle: &Particle) -> ParticleState {
+    fn state_setup(
+        &self,
+        commands: &mut Commands,
+        particle: &Particle,
+        _theme: &Theme,
+    ) -> ParticleState {
         setup_particle_starting(commands, particle)
     }
-    fn state_update(&self, commands: &mut Commands, particle: &Particle) {
-        update_particle_starting(commands, particle);
-        update_particle_running(commands, particle);
+    fn redraw(
+        &self,
+        particle: &Particle,
+        shape: ParticleShape,
+        canvas: &mut ShapeCanvas,
+        motion: &MotionPolicy,
+        theme: &Theme,
+    ) {
+        match (shape, &particle.state) {
+            (ParticleShape::Body, ParticleState::Starting) => canvas.draw(
+                build_body_path(motion.steady_radius(particle.radius, RADIUS)),
+                particle.color,
+            ),
+            (ParticleShape::Countdown, ParticleState::Running) => canvas.draw(
+                build_countdown_path(particle.countdown_ratio()),
+                theme.trigger,
+            ),
+            _ => canvas.hide(),
+        }
     }
-    fn state_starting_done(&self, commands: &mut Commands, particle: &Particle) -> ParticleState {
-        setup_particle_running(commands, particle)
+    fn state_starting_done(
+        &self,
+        commands: &mut Commands,
+        particle: &Particle,
+        theme: &Theme,
+    ) -> ParticleState {
+        setup_particle_running(commands, particle, theme)
     }
 }
 
@@ -94,6 +120,7 @@ pub fn build_particle_sprite(
     pos: Option<Vec2>,
     direction: Option<Vec2>,
     level: Option<u8>,
+    theme: &Theme,
 ) {
     let pos = match pos {
         Some(pos) => pos,
@@ -105,7 +132,7 @@ pub fn build_particle_sprite(
             SpriteBundle {
                 transform: Transform::from_xyz(pos.x, pos.y, 0.0),
                 sprite: Sprite {
-                    color: COLOR,
+                    color: theme.trigger,
                     ..default()
                 },
                 ..default()
@@ -117,11 +144,25 @@ pub fn build_particle_sprite(
                 .spawn(SpriteBundle {
                     transform: Transform::from_xyz(0.0, 0.0, 0.3),
                     sprite: Sprite {
-                        color: COLOR,
+                        color: theme.trigger,
                         ..default()
                     },
                     ..default()
                 })
+                .with_children(|parent| {
+                    build_retained_shape(
+                        parent,
+                        ParticleShape::Body,
+                        0.1,
+                        Fill::color(theme.trigger),
+                    );
+                    build_retained_shape(
+                        parent,
+                        ParticleShape::Countdown,
+                        0.3,
+                        Stroke::new(theme.trigger, SIDE_THICKNESS),
+                    );
+                })
                 .id();
         })
         .id();
@@ -132,6 +173,7 @@ pub fn build_particle_sprite(
         level,
         root_entity,
         canvas_entity,
+        theme,
     );
     commands.entity(root_entity).insert(particle);
 }
@@ -154,48 +196,18 @@ pub fn setup_particle_starting(commands: &mut Commands, particle: &Particle) ->
     ParticleState::Starting
 }
 
-pub fn update_particle_starting(commands: &mut Commands, particle: &Particle) {
-    if particle.state != ParticleState::Starting {
-        return;
-    }
-    if let Some(mut entity_commands) = commands.get_entity(particle.canvas_entity()) {
-        entity_commands.despawn_descendants();
-        entity_commands.with_children(|parent| {
-            parent
-                .spawn(SpriteBundle {
-                    transform: Transform::from_xyz(0.0, 0.0, 0.1),
-                    sprite: Sprite {
-                        color: COLOR,
-                        ..default()
-                    },
-                    ..default()
-                })
-                .with_children(|parent| {
-                    let shape = shapes::RegularPolygon {
-                        sides: 3,
-                        feature: shapes::RegularPolygonFeature::Radius(particle.radius),
-                        ..shapes::RegularPolygon::default()
-                    };
-                    parent.spawn((
-                        ShapeBundle {
-                            path: GeometryBuilder::build_as(&shape),
-                            ..default()
-                        },
-                        Fill::color(particle.color),
-                    ));
-                });
-        });
-    }
-}
-
-pub fn setup_particle_running(commands: &mut Commands, particle: &Particle) -> ParticleState {
+pub fn setup_particle_running(
+    commands: &mut Commands,
+    particle: &Particle,
+    theme: &Theme,
+) -> ParticleState {
     if let Some(mut entity_commands) = commands.get_entity(particle.root_entity()) {
         entity_commands.with_children(|parent| {
             parent
                 .spawn(SpriteBundle {
                     transform: Transform::from_xyz(0.0, 0.0, 0.1),
                     sprite: Sprite {
-                        color: COLOR,
+                        color: theme.trigger,
                         ..default()
                     },
                     ..default()
@@ -211,7 +223,7 @@ pub fn setup_particle_running(commands: &mut Commands, particle: &Particle) -> P
                             path: GeometryBuilder::build_as(&shape),
                             ..default()
                         },
-                        Fill::color(COLOR),
+                        Fill::color(theme.trigger),
                     ));
                     let shape = shapes::Circle {
                         radius: alpha::RADIUS,
@@ -222,7 +234,7 @@ pub fn setup_particle_running(commands: &mut Commands, particle: &Particle) -> P
                             path: GeometryBuilder::build_as(&shape),
                             ..default()
                         },
-                        Stroke::new(COLOR, SIDE_THICKNESS),
+                        Stroke::new(theme.trigger, SIDE_THICKNESS),
                     ));
                 });
         });
@@ -230,42 +242,25 @@ pub fn setup_particle_running(commands: &mut Commands, particle: &Particle) -> P
     ParticleState::Running
 }
 
-pub fn update_particle_running(commands: &mut Commands, particle: &Particle) {
-    if particle.state != ParticleState::Running {
-        return;
-    }
-    if let Some(mut entity_commands) = commands.get_entity(particle.canvas_entity()) {
-        entity_commands.despawn_descendants();
-        entity_commands.with_children(|parent| {
-            let side_ratio = particle.countdown_ratio();
-            let mut path_builder = PathBuilder::new();
-            path_builder.move_to(Vec2::new(0.0, RADIUS * 1.5));
-            path_builder.arc(
-                Vec2::default(),
-                Vec2::new(RADIUS * 1.5, RADIUS * 1.5),
-                TAU * side_ratio,
-                0.0,
-            );
-            parent
-                .spawn(SpriteBundle {
-                    transform: Transform::from_xyz(0.0, 0.0, 0.3),
-                    sprite: Sprite {
-                        color: COLOR,
-                        ..default()
-                    },
-                    ..default()
-                })
-                .with_children(|parent| {
-                    parent.spawn((
-                        ShapeBundle {
-                            path: path_builder.build(),
-                            ..default()
-                        },
-                        Stroke::new(COLOR, SIDE_THICKNESS),
-                    ));
-                });
-        });
-    }
+fn build_body_path(radius: f32) -> Path {
+    let shape = shapes::RegularPolygon {
+        sides: 3,
+        feature: shapes::RegularPolygonFeature::Radius(radius),
+        ..shapes::RegularPolygon::default()
+    };
+    GeometryBuilder::build_as(&shape)
+}
+
+fn build_countdown_path(side_ratio: f32) -> Path {
+    let mut path_builder = PathBuilder::new();
+    path_builder.move_to(Vec2::new(0.0, RADIUS * 1.5));
+    path_builder.arc(
+        Vec2::default(),
+        Vec2::new(RADIUS * 1.5, RADIUS * 1.5),
+        TAU * side_ratio,
+        0.0,
+    );
+    path_builder.build()
 }
 
 const LEVEL_INIT_BIAS_COUNT: u32 = 30;
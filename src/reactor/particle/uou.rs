@@ -1,18 +1,32 @@
 use crate::reactor::{field, particle::*};
-use bevy_prototype_lyon::prelude::*;
 
 pub const RADIUS: f32 = 8.0;
-pub const COLOR: Color = Color::rgb(1.0, 0.84, 0.2);
 const MIN_LEVEL: u8 = 1;
 const MAX_LEVEL: u8 = 5;
 const MIN_V: f32 = 0.0;
 const MAX_V: f32 = 0.0;
-const TAILING_SIZE: usize = 10;
+pub const TAILING_SIZE: usize = 10;
 const TAILING_WINDOW: u8 = 3;
 
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
+pub enum UouShape {
+    #[default]
+    Circle,
+    Square,
+    Diamond,
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct UouSkin {
+    /// `None` follows the active theme.
+    pub color: Option<Color>,
+    pub shape: UouShape,
+}
+
 pub struct Ability {
     tailings: CircularQueue<Vec2>,
     tailing_counter: u8,
+    skin: UouSkin,
 }
 
 impl Ability {
@@ -22,17 +36,39 @@ impl Ability {
         level: Option<u8>,
         root_entity: Entity,
         canvas_entity: Entity,
+        theme: &Theme,
+    ) -> Particle {
+        Ability::gen_skinned_particle(
+            pos,
+            direction,
+            level,
+            root_entity,
+            canvas_entity,
+            UouSkin::default(),
+            theme,
+        )
+    }
+    pub fn gen_skinned_particle(
+        pos: Vec2,
+        direction: Option<Vec2>,
+        level: Option<u8>,
+        root_entity: Entity,
+        canvas_entity: Entity,
+        skin: UouSkin,
+        theme: &Theme,
     ) -> Particle {
         Particle::new(
             Box::new(Ability {
                 tailings: CircularQueue::with_capacity(TAILING_SIZE),
                 tailing_counter: 0,
+                skin,
             }),
             pos,
             direction,
             level,
             root_entity,
             canvas_entity,
+            theme,
         )
     }
 }
@@ -44,8 +80,8 @@ impl ParticleAbility for Ability {
     fn radius(&self) -> f32 {
         RADIUS
     }
-    fn color(&self) -> Color {
-        COLOR
+    fn color(&self, theme: &Theme) -> Color {
+        self.skin.color.unwrap_or(theme.u)
     }
     fn min_level(&self) -> u8 {
         MIN_LEVEL
@@ -65,6 +101,9 @@ impl ParticleAbility for Ability {
     fn tailings(&self) -> Option<&CircularQueue<Vec2>> {
         Some(&self.tailings)
     }
+    fn clear_tailings(&mut self) {
+        self.tailings.clear();
+    }
     fn record_tailing(&mut self, pos: Vec2) {
         if self.tailing_counter == 0 {
             self.tailings.push(pos);
@@ -76,11 +115,13 @@ impl ParticleAbility for Ability {
     fn is_traveling(&self, _particle: &Particle) -> bool {
         false
     }
-    fn state_setup(&self, commands: &mut Commands, particle: &Particle) -> ParticleState {
-        setup_particle_running(commands, particle)
-    }
-    fn state_update(&self, commands: &mut Commands, particle: &Particle) {
-        update_particle_running(commands, particle);
+    fn state_setup(
+        &self,
+        commands: &mut Commands,
+        particle: &Particle,
+        _theme: &Theme,
+    ) -> ParticleState {
+        setup_particle_running(commands, particle, self.skin.shape)
     }
 }
 
@@ -90,6 +131,8 @@ pub fn build_particle_sprite(
     pos: Option<Vec2>,
     direction: Option<Vec2>,
     level: Option<u8>,
+    skin: UouSkin,
+    theme: &Theme,
 ) {
     let pos = match pos {
         Some(pos) => pos,
@@ -101,7 +144,7 @@ pub fn build_particle_sprite(
             SpriteBundle {
                 transform: Transform::from_xyz(pos.x, pos.y, 0.0),
                 sprite: Sprite {
-                    color: COLOR,
+                    color: skin.color.unwrap_or(theme.u),
                     ..default()
                 },
                 ..default()
@@ -113,7 +156,7 @@ pub fn build_particle_sprite(
                 .spawn(SpriteBundle {
                     transform: Transform::from_xyz(0.0, 0.0, 0.1),
                     sprite: Sprite {
-                        color: COLOR,
+                        color: skin.color.unwrap_or(theme.u),
                         ..default()
                     },
                     ..default()
@@ -121,76 +164,56 @@ pub fn build_particle_sprite(
                 .id();
         })
         .id();
-    let particle = Particle::create(
-        ParticleType::Uou,
+    let particle = Ability::gen_skinned_particle(
         pos,
         direction,
         level,
         root_entity,
         canvas_entity,
+        skin,
+        theme,
     );
     commands.entity(root_entity).insert(particle);
 }
 
-pub fn setup_particle_running(commands: &mut Commands, particle: &Particle) -> ParticleState {
+pub fn setup_particle_running(
+    commands: &mut Commands,
+    particle: &Particle,
+    shape: UouShape,
+) -> ParticleState {
     if let Some(mut entity_commands) = commands.get_entity(particle.root_entity()) {
         entity_commands.with_children(|parent| {
             parent
                 .spawn(SpriteBundle {
                     transform: Transform::from_xyz(0.0, 0.0, 0.2),
                     sprite: Sprite {
-                        color: COLOR,
+                        color: particle.color,
                         ..default()
                     },
                     ..default()
                 })
                 .with_children(|parent| {
-                    let shape = shapes::Circle {
-                        radius: RADIUS,
-                        center: Vec2::new(0.0, 0.0),
+                    let path = match shape {
+                        UouShape::Circle => GeometryBuilder::build_as(&shapes::Circle {
+                            radius: RADIUS,
+                            center: Vec2::new(0.0, 0.0),
+                        }),
+                        UouShape::Square => GeometryBuilder::build_as(&shapes::Rectangle {
+                            extents: Vec2::splat(RADIUS * 1.8),
+                            origin: RectangleOrigin::Center,
+                        }),
+                        UouShape::Diamond => GeometryBuilder::build_as(&shapes::RegularPolygon {
+                            sides: 4,
+                            feature: RegularPolygonFeature::Radius(RADIUS * 1.2),
+                            ..default()
+                        }),
                     };
                     parent.spawn((
-                        ShapeBundle {
-                            path: GeometryBuilder::build_as(&shape),
-                            ..default()
-                        },
-                        Fill::color(COLOR),
+                        ShapeBundle { path, ..default() },
+                        Fill::color(particle.color),
                     ));
                 });
         });
     }
     ParticleState::Running
 }
-
-pub fn update_particle_running(commands: &mut Commands, particle: &Particle) {
-    if particle.state != ParticleState::Running {
-        return;
-    }
-    if let Some(mut entity_commands) = commands.get_entity(particle.canvas_entity()) {
-        entity_commands.despawn_descendants();
-        if let Some(tailings) = particle.tailings() {
-            entity_commands.with_children(|parent| {
-                let mut next_pos = Vec2::default();
-                for (i, tailing) in tailings.iter().enumerate() {
-                    let mut path_builder = PathBuilder::new();
-                    path_builder.move_to(next_pos);
-                    next_pos = *tailing - particle.pos();
-                    path_builder.line_to(next_pos);
-                    parent.spawn((
-                        ShapeBundle {
-                            path: path_builder.build(),
-                            ..default()
-                        },
-                        Stroke {
-                            options: StrokeOptions::default()
-                                .with_end_cap(LineCap::Round)
-                                .with_start_cap(LineCap::Round)
-                                .with_line_width(RADIUS * (2.0 - i as f32 * 0.2)),
-                            color: COLOR.with_l(0.3 - i as f32 * 0.03),
-                        },
-                    ));
-                }
-            });
-        }
-    }
-}
@@ -0,0 +1,292 @@
+use crate::reactor::{field, motion::MotionPolicy, particle::*};
+use bevy_tweening::*;
+use std::time::Duration;
+
+const MIN_LEVEL: u8 = 1;
+const MAX_LEVEL: u8 = 1;
+const MIN_V: f32 = 0.2;
+const MAX_V: f32 = 0.5;
+const RADIUS: f32 = 10.0;
+const PULL_RADIUS: f32 = 120.0;
+const ALPHA_BEND: f32 = 0.08;
+const U_PULL: f32 = 0.5;
+const PASS_GAP: f32 = 24.0;
+
+pub struct Ability;
+
+impl Ability {
+    pub fn gen_particle(
+        pos: Vec2,
+        direction: Option<Vec2>,
+        level: Option<u8>,
+        root_entity: Entity,
+        canvas_entity: Entity,
+        theme: &Theme,
+    ) -> Particle {
+        Particle::new(
+            Box::new(Ability),
+            pos,
+            direction,
+            level,
+            root_entity,
+            canvas_entity,
+            theme,
+        )
+    }
+}
+
+impl ParticleAbility for Ability {
+    fn particle_type(&self) -> ParticleType {
+        ParticleType::Well
+    }
+    fn radius(&self) -> f32 {
+        RADIUS
+    }
+    fn color(&self, theme: &Theme) -> Color {
+        theme.well
+    }
+    fn min_level(&self) -> u8 {
+        MIN_LEVEL
+    }
+    fn max_level(&self) -> u8 {
+        MAX_LEVEL
+    }
+    fn min_v(&self) -> f32 {
+        MIN_V
+    }
+    fn max_v(&self) -> f32 {
+        MAX_V
+    }
+    fn is_traveling(&self, particle: &Particle) -> bool {
+        particle.state == ParticleState::Running
+    }
+    fn state_setup(
+        &self,
+        commands: &mut Commands,
+        particle: &Particle,
+        _theme: &Theme,
+    ) -> ParticleState {
+        setup_particle_starting(commands, particle)
+    }
+    fn redraw(
+        &self,
+        particle: &Particle,
+        shape: ParticleShape,
+        canvas: &mut ShapeCanvas,
+        motion: &MotionPolicy,
+        theme: &Theme,
+    ) {
+        match (shape, &particle.state) {
+            (ParticleShape::Body, ParticleState::Starting) => canvas.draw(
+                build_body_path(motion.steady_radius(particle.radius, RADIUS)),
+                particle.color,
+            ),
+            (ParticleShape::Body, ParticleState::Running) => {
+                canvas.draw(build_body_path(RADIUS), theme.well)
+            }
+            (ParticleShape::Core, ParticleState::Running) => {
+                canvas.draw(build_body_path(RADIUS * 0.45), theme.well)
+            }
+            (ParticleShape::Range, ParticleState::Running) => {
+                canvas.draw(build_body_path(PULL_RADIUS), theme.well.with_a(0.15))
+            }
+            _ => canvas.hide(),
+        }
+    }
+    fn state_starting_done(
+        &self,
+        _commands: &mut Commands,
+        _particle: &Particle,
+        _theme: &Theme,
+    ) -> ParticleState {
+        ParticleState::Running
+    }
+}
+
+pub fn build_particle_sprite(
+    commands: &mut Commands,
+    bundle: impl Bundle,
+    pos: Option<Vec2>,
+    direction: Option<Vec2>,
+    level: Option<u8>,
+    theme: &Theme,
+) {
+    let pos = match pos {
+        Some(pos) => pos,
+        None => field::gen_random_pos_in_field(RADIUS * 2.0),
+    };
+    let mut canvas_entity: Entity = Entity::PLACEHOLDER;
+    let root_entity = commands
+        .spawn((
+            SpriteBundle {
+                transform: Transform::from_xyz(pos.x, pos.y, 0.0),
+                sprite: Sprite {
+                    color: theme.well,
+                    ..default()
+                },
+                ..default()
+            },
+            bundle,
+        ))
+        .with_children(|parent| {
+            canvas_entity = parent
+                .spawn(SpriteBundle {
+                    transform: Transform::from_xyz(0.0, 0.0, 0.3),
+                    sprite: Sprite {
+                        color: theme.well,
+                        ..default()
+                    },
+                    ..default()
+                })
+                .with_children(|parent| {
+                    build_retained_shape(
+                        parent,
+                        ParticleShape::Range,
+                        0.1,
+                        Stroke::new(theme.well, SIDE_THICKNESS * 0.5),
+                    );
+                    build_retained_shape(
+                        parent,
+                        ParticleShape::Body,
+                        0.2,
+                        Stroke::new(theme.well, SIDE_THICKNESS),
+                    );
+                    build_retained_shape(parent, ParticleShape::Core, 0.3, Fill::color(theme.well));
+                })
+                .id();
+        })
+        .id();
+    let particle = Particle::create(
+        ParticleType::Well,
+        pos,
+        direction,
+        level,
+        root_entity,
+        canvas_entity,
+        theme,
+    );
+    commands.entity(root_entity).insert(particle);
+}
+
+pub fn setup_particle_starting(commands: &mut Commands, particle: &Particle) -> ParticleState {
+    if let Some(mut entity_commands) = commands.get_entity(particle.root_entity()) {
+        let tween = Tween::new(
+            EaseFunction::QuadraticIn,
+            Duration::from_millis(800),
+            ParticleAnimeLens {
+                start_radius: PULL_RADIUS,
+                start_color_alpha: 0.0,
+                end_radius: RADIUS,
+                end_color_alpha: 0.5,
+            },
+        )
+        .with_completed_event(STARTING_DONE_EVENT);
+        entity_commands.insert(Animator::new(tween));
+    }
+    ParticleState::Starting
+}
+
+fn build_body_path(radius: f32) -> Path {
+    let shape = shapes::Circle {
+        radius,
+        center: Vec2::default(),
+    };
+    GeometryBuilder::build_as(&shape)
+}
+
+// NOTE: alphas keep their speed, only their heading turns toward the well
+pub fn bend_v(particle: &mut Particle, well_pos: Vec2) {
+    let offset = well_pos - particle.pos();
+    let distance = offset.length();
+    if distance == 0.0 || distance > PULL_RADIUS {
+        return;
+    }
+    let speed = particle.v().length();
+    let strength = ALPHA_BEND * (1.0 - distance / PULL_RADIUS);
+    let direction = (particle.v().normalize_or_zero() + offset / distance * strength).normalize();
+    particle.set_v(direction * speed);
+}
+
+pub fn pull_pos(pos: Vec2, well_pos: Vec2) -> Vec2 {
+    let offset = well_pos - pos;
+    let distance = offset.length();
+    if distance == 0.0 || distance > PULL_RADIUS {
+        return pos;
+    }
+    pos + offset / distance * U_PULL * (1.0 - distance / PULL_RADIUS)
+}
+
+pub fn pass_through_pos(u: &Particle, well: &Particle) -> Vec2 {
+    let direction = (well.pos() - u.pos()).normalize_or_zero();
+    let direction = if direction == Vec2::ZERO {
+        Particle::gen_random_direction()
+    } else {
+        direction
+    };
+    well.pos() + direction * (u.radius + well.radius + PASS_GAP)
+}
+
+const COUNT_INIT_BIAS_COUNT: u32 = 30;
+const MAX_COUNT: usize = 3;
+
+pub fn fetch_target_count(total_alpha_count: u32) -> usize {
+    let mut count = 0;
+    for i in 1..=MAX_COUNT {
+        if total_alpha_count >= COUNT_INIT_BIAS_COUNT + 2_u32.pow(i as u32 + 5) {
+            count = i;
+        }
+    }
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::theme::ThemePreset;
+
+    fn gen_alpha(pos: Vec2, v: Vec2) -> Particle {
+        let theme = Theme::from_preset(ThemePreset::Default);
+        let mut particle = Particle::create(
+            ParticleType::Alpha,
+            pos,
+            None,
+            None,
+            Entity::PLACEHOLDER,
+            Entity::PLACEHOLDER,
+            &theme,
+        );
+        particle.set_v(v);
+        particle
+    }
+
+    #[test]
+    fn bends_alphas_toward_the_well_at_the_same_speed() {
+        let mut alpha = gen_alpha(Vec2::new(-60.0, 0.0), Vec2::new(0.0, 2.0));
+        bend_v(&mut alpha, Vec2::ZERO);
+        assert!(alpha.v().x > 0.0);
+        assert!((alpha.v().length() - 2.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn leaves_alphas_outside_the_pull_radius() {
+        let mut alpha = gen_alpha(Vec2::new(-PULL_RADIUS - 1.0, 0.0), Vec2::new(0.0, 2.0));
+        bend_v(&mut alpha, Vec2::ZERO);
+        assert_eq!(alpha.v(), Vec2::new(0.0, 2.0));
+    }
+
+    #[test]
+    fn pulls_u_harder_near_the_well() {
+        let near = pull_pos(Vec2::new(-30.0, 0.0), Vec2::ZERO);
+        let far = pull_pos(Vec2::new(-90.0, 0.0), Vec2::ZERO);
+        assert!(near.x > -30.0 && far.x > -90.0);
+        assert!(near.x + 30.0 > far.x + 90.0);
+        assert_eq!(near.y, 0.0);
+    }
+
+    #[test]
+    fn keeps_u_outside_the_pull_radius_or_on_the_well() {
+        let outside = Vec2::new(PULL_RADIUS + 1.0, 0.0);
+        assert_eq!(pull_pos(outside, Vec2::ZERO), outside);
+        assert_eq!(pull_pos(Vec2::ZERO, Vec2::ZERO), Vec2::ZERO);
+    }
+}
@@ -18,11 +18,14 @@ fn build_result_panel(
     asset_server: &Res<AssetServer>,
     status: &ResMut<reactor::status::ReactorStatus>,
     leaderboard: &Res<Persistent<app::leaderboard::Leaderboard>>,
+    ach_defs: &Res<app::achievement::AchievementDefs>,
+    theme: &app::theme::Theme,
+    locale: &app::locale::Locale,
 ) {
     parent.spawn(TextBundle::from_section(
-        "Game Over",
+        locale.tr("Game Over"),
         TextStyle {
-            font: asset_server.load(app::ui::FONT),
+            font: asset_server.load(locale.fetch_font(app::ui::FONT)),
             font_size: app::ui::FONT_SIZE * 3.0,
             color: Color::rgba(1.0, 0.0, 0.0, 0.8),
         },
@@ -62,14 +65,32 @@ fn build_result_panel(
                             ..default()
                         },))
                         .with_children(|parent| {
-                            build_result_entry(parent, asset_server, status, leaderboard, "score");
-                            build_result_entry(parent, asset_server, status, leaderboard, "time");
+                            build_result_entry(
+                                parent,
+                                asset_server,
+                                status,
+                                leaderboard,
+                                "score",
+                                theme,
+                                locale,
+                            );
+                            build_result_entry(
+                                parent,
+                                asset_server,
+                                status,
+                                leaderboard,
+                                "time",
+                                theme,
+                                locale,
+                            );
                             build_result_entry(
                                 parent,
                                 asset_server,
                                 status,
                                 leaderboard,
                                 "max_alpha_count",
+                                theme,
+                                locale,
                             );
                         });
                     parent
@@ -89,6 +110,8 @@ fn build_result_panel(
                                 status,
                                 leaderboard,
                                 "max_control_chain",
+                                theme,
+                                locale,
                             );
                             build_result_entry(
                                 parent,
@@ -96,6 +119,17 @@ fn build_result_panel(
                                 status,
                                 leaderboard,
                                 "max_hyper_chain",
+                                theme,
+                                locale,
+                            );
+                            build_result_entry(
+                                parent,
+                                asset_server,
+                                status,
+                                leaderboard,
+                                "max_graze_streak",
+                                theme,
+                                locale,
                             );
                         });
                     parent
@@ -110,11 +144,14 @@ fn build_result_panel(
                         },))
                         .with_children(|parent| {
                             for ach_code in status.done_achievements.iter() {
-                                reactor::field_ach::build_ach_icon(
-                                    parent,
-                                    asset_server,
-                                    ach_code.as_str(),
-                                );
+                                if let Some(ach_def) = ach_defs.find(ach_code) {
+                                    reactor::field_ach::build_ach_icon(
+                                        parent,
+                                        asset_server,
+                                        ach_def,
+                                        theme,
+                                    );
+                                }
                             }
                         });
                 });
@@ -127,6 +164,8 @@ fn build_result_entry(
     status: &ResMut<reactor::status::ReactorStatus>,
     leaderboard: &Res<Persistent<app::leaderboard::Leaderboard>>,
     field: &str,
+    theme: &app::theme::Theme,
+    locale: &app::locale::Locale,
 ) -> Entity {
     parent
         .spawn(NodeBundle {
@@ -157,6 +196,7 @@ fn build_result_entry(
                 "max_alpha_count" => asset_server.load("images/icons/circles-three-fill.png"),
                 "max_control_chain" => asset_server.load("images/icons/square.png"),
                 "max_hyper_chain" => asset_server.load("images/icons/hexagon.png"),
+                "max_graze_streak" => asset_server.load("images/icons/circle.png"),
                 _ => panic!("Invalid field"),
             };
             parent.spawn(ImageBundle {
@@ -183,9 +223,9 @@ fn build_result_entry(
                 .with_children(|parent| {
                     let rank: u8 = leaderboard.rank(field, status.fetch(field));
                     let rank_color = if rank <= 10 {
-                        app::ui::FG_COLOR
+                        theme.fg
                     } else {
-                        app::ui::SECONDARY_COLOR
+                        theme.secondary
                     };
                     let rank_text = match rank {
                         1 => String::from("1st"),
@@ -217,7 +257,7 @@ fn build_result_entry(
                                             TextStyle {
                                                 font: asset_server.load(app::ui::FONT_DIGIT),
                                                 font_size: RESULT_FS * 0.6,
-                                                color: app::ui::BG_COLOR,
+                                                color: theme.bg,
                                             },
                                         )
                                         .with_style(
@@ -233,11 +273,11 @@ fn build_result_entry(
                             if is_new {
                                 parent.spawn(
                                     TextBundle::from_section(
-                                        "NEW!!",
+                                        locale.tr("NEW!!"),
                                         TextStyle {
                                             font: asset_server.load(app::ui::FONT_DIGIT),
                                             font_size: RESULT_FS * 0.6,
-                                            color: app::ui::FG_COLOR,
+                                            color: theme.fg,
                                         },
                                     )
                                     .with_style(Style {
@@ -251,12 +291,14 @@ fn build_result_entry(
                         "time" | "score" => field,
                         "max_alpha_count" => "alpha_count",
                         "max_control_chain" | "max_hyper_chain" => "chain",
+                        "max_graze_streak" => "graze",
                         _ => panic!("Invalid field"),
                     };
                     let text_color = match field {
-                        "time" | "score" | "max_alpha_count" => app::ui::SECONDARY_COLOR,
-                        "max_control_chain" => reactor::particle::control::COLOR,
-                        "max_hyper_chain" => reactor::particle::hyper::COLOR,
+                        "time" | "score" | "max_alpha_count" => theme.secondary,
+                        "max_control_chain" => theme.control,
+                        "max_hyper_chain" => theme.hyper,
+                        "max_graze_streak" => theme.u,
                         _ => panic!("Invalid field"),
                     };
                     parent.spawn(TextBundle::from_section(
@@ -43,14 +43,15 @@ struct DemoParticle;
 fn state_setup(
     mut commands: Commands,
     mut key_binding: ResMut<app::key_binding::KeyBindingConfig>,
+    theme: Res<app::theme::Theme>,
 ) {
     key_binding.mode = app::key_binding::KeyBindingMode::Navgation;
-    trigger::build_particle_sprite(&mut commands, DemoParticle, None, None, None);
-    trigger::build_particle_sprite(&mut commands, DemoParticle, None, None, None);
-    trigger::build_particle_sprite(&mut commands, DemoParticle, None, None, None);
-    hyper::build_particle_sprite(&mut commands, DemoParticle, None, None, None);
-    hyper::build_particle_sprite(&mut commands, DemoParticle, None, None, None);
-    hyper::build_particle_sprite(&mut commands, DemoParticle, None, None, None);
+    trigger::build_particle_sprite(&mut commands, DemoParticle, None, None, None, &theme);
+    trigger::build_particle_sprite(&mut commands, DemoParticle, None, None, None, &theme);
+    trigger::build_particle_sprite(&mut commands, DemoParticle, None, None, None, &theme);
+    hyper::build_particle_sprite(&mut commands, DemoParticle, None, None, None, &theme);
+    hyper::build_particle_sprite(&mut commands, DemoParticle, None, None, None, &theme);
+    hyper::build_particle_sprite(&mut commands, DemoParticle, None, None, None, &theme);
 }
 
 fn state_exit(mut commands: Commands, particle_query: Query<Entity, With<DemoParticle>>) {
@@ -65,6 +66,7 @@ fn state_action(
     mut reactor_timer: ResMut<reactor::ReactorTimer>,
     time: Res<Time>,
     status: ResMut<status::ReactorStatus>,
+    theme: Res<app::theme::Theme>,
 ) {
     if reactor_timer.0.tick(time.delta()).just_finished() {
         let alpha_count = status.fetch("alpha_count");
@@ -78,28 +80,27 @@ fn state_action(
                 ParticleType::Alpha => {
                     particle.tick_countdown();
                 }
-                ParticleType::Hyper => {
-                    if particle.tick_countdown() == 0 {
-                        if alpha_count > 150 {
-                            let new_pos = field::gen_random_pos_in_field(particle.radius);
-                            (*particle).jump(new_pos);
-                            transform.translation.x = new_pos.x;
-                            transform.translation.y = new_pos.y;
-                            control::build_particle_sprite(
-                                &mut commands,
-                                DemoParticle,
-                                None,
-                                None,
-                                Some(particle.level()),
-                            );
-                        }
-                        if particle.level() == 1 {
-                            particle.update_level(5);
-                        } else {
-                            particle.update_level(-1);
-                        }
-                        particle.reset_countdown();
+                ParticleType::Hyper if particle.tick_countdown() == 0 => {
+                    if alpha_count > 150 {
+                        let new_pos = field::gen_random_pos_in_field(particle.radius);
+                        (*particle).warp(new_pos);
+                        transform.translation.x = new_pos.x;
+                        transform.translation.y = new_pos.y;
+                        control::build_particle_sprite(
+                            &mut commands,
+                            DemoParticle,
+                            None,
+                            None,
+                            Some(particle.level()),
+                            &theme,
+                        );
                     }
+                    if particle.level() == 1 {
+                        particle.update_level(5);
+                    } else {
+                        particle.update_level(-1);
+                    }
+                    particle.reset_countdown();
                 }
                 ParticleType::Trigger => {
                     transform.rotate_z(-time.delta_seconds() * 2.0);
@@ -116,6 +117,7 @@ fn state_action(
                             Some(particle.pos() + direction * particle.radius),
                             Some(direction),
                             Some(level),
+                            &theme,
                         );
                         if level > 2 {
                             insert_anime_effect(
@@ -126,6 +128,7 @@ fn state_action(
                                     start_pos: particle.pos(),
                                     end_pos: particle.pos(),
                                 },
+                                &theme,
                             );
                         }
                     }
@@ -139,24 +142,18 @@ fn state_action(
 fn handle_particle_reaction(
     mut commands: Commands,
     mut particle_query: Query<(Entity, &mut Particle, &mut Transform), With<Particle>>,
-    ae_query: Query<(Entity, &mut AnimeEffect), With<AnimeEffect>>,
     mut reactor_timer: ResMut<reactor::ReactorTimer>,
     mut painter_timer: ResMut<reactor::PainterTimer>,
     mut tween_completed_events: EventReader<TweenCompleted>,
     time: Res<Time>,
+    theme: Res<app::theme::Theme>,
 ) {
     if painter_timer.0.tick(time.delta()).just_finished() {
-        for (_, particle, _) in particle_query.iter() {
-            particle.state_update(&mut commands);
-        }
         for (_, mut particle, _) in particle_query.iter_mut() {
             if particle.state == ParticleState::Created {
-                particle.state_setup(&mut commands);
+                particle.state_setup(&mut commands, &theme);
             }
         }
-        for (_, ae) in ae_query.iter() {
-            update_anime_effect(&mut commands, ae);
-        }
     }
     if reactor_timer.0.tick(time.delta()).just_finished() {
         let hit_map = detect_hit(&mut particle_query);
@@ -166,7 +163,7 @@ fn handle_particle_reaction(
                 match p.particle_type() {
                     ParticleType::Alpha => match action {
                         HitAction::Kill => {
-                            alpha::setup_particle_ending(&mut commands, &mut p);
+                            alpha::setup_particle_ending(&mut commands, &mut p, &theme);
                         }
                         HitAction::Release(count) => {
                             p.reset_countdown();
@@ -180,6 +177,7 @@ fn handle_particle_reaction(
                                         Some(p.pos() + direction * p.radius * 3.0),
                                         Some(direction),
                                         Some(1),
+                                        &theme,
                                     );
                                 }
                             } else {
@@ -189,6 +187,7 @@ fn handle_particle_reaction(
                                     Some(p.pos()),
                                     None,
                                     None,
+                                    &theme,
                                 );
                             }
                             if *count > 3 {
@@ -200,6 +199,7 @@ fn handle_particle_reaction(
                                         start_pos: p.pos(),
                                         end_pos: p.pos(),
                                     },
+                                    &theme,
                                 );
                             }
                             entities_to_despawn.insert(e);
@@ -214,12 +214,12 @@ fn handle_particle_reaction(
                         HitAction::AlphaHit(count) => {
                             for _ in 1..=*count {
                                 if p.tick_countdown() == 0 {
-                                    control::setup_particle_ending(&mut commands, &mut p);
+                                    control::setup_particle_ending(&mut commands, &mut p, &theme);
                                 }
                             }
                         }
                         HitAction::Kill => {
-                            control::setup_particle_ending(&mut commands, &mut p);
+                            control::setup_particle_ending(&mut commands, &mut p, &theme);
                         }
                         _ => (),
                     },
@@ -231,7 +231,7 @@ fn handle_particle_reaction(
             if tween_event.user_data == STARTING_DONE_EVENT {
                 for (e, mut p, _) in particle_query.iter_mut() {
                     if e == tween_event.entity {
-                        p.state_starting_done(&mut commands);
+                        p.state_starting_done(&mut commands, &theme);
                     }
                 }
             }
@@ -9,12 +9,16 @@ impl Plugin for StatePlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(
             OnEnter(reactor::ReactorState::Ended),
-            (state_setup, app::audio::reduce_bgm_volume),
+            (
+                (app::unlock::check_unlocks, state_setup).chain(),
+                app::audio::reduce_bgm_volume,
+            ),
         )
         .add_systems(
             Update,
             (
                 reactor::field_ach::update_ach_fields,
+                reactor::field_ach::paint_ach_progress,
                 handle_ui_navigation.after(NavRequestSystem),
             )
                 .run_if(in_state(reactor::ReactorState::Ended)),
@@ -29,8 +33,12 @@ impl Plugin for StatePlugin {
 #[derive(Component)]
 struct StateRootUi;
 
+#[derive(Component)]
+struct UnlockOverlay;
+
 #[derive(Component)]
 enum ButtonAction {
+    CloseUnlocks,
     ReStart,
     Leaderboard,
     Achievement,
@@ -39,14 +47,27 @@ enum ButtonAction {
 
 const ENDED_BG_COLOR: Color = Color::rgba(0.0, 0.0, 0.0, 0.95);
 
+#[allow(clippy::too_many_arguments)]
 fn state_setup(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     status: ResMut<reactor::status::ReactorStatus>,
     leaderboard: Res<Persistent<app::leaderboard::Leaderboard>>,
     mut key_binding: ResMut<app::key_binding::KeyBindingConfig>,
+    unlock_store: Res<Persistent<app::unlock::UnlockStore>>,
+    ach_defs: Res<app::achievement::AchievementDefs>,
+    theme: Res<app::theme::Theme>,
+    locale: Res<app::locale::Locale>,
 ) {
     key_binding.mode = app::key_binding::KeyBindingMode::Navgation;
+    let unlocked_codes = unlock_store.pending_codes();
+    let build_focusable = |focusable: Focusable| {
+        if unlocked_codes.is_empty() {
+            focusable
+        } else {
+            focusable.blocked()
+        }
+    };
     let lb_record = status.export();
     let is_new_record = leaderboard.is_new_record(&lb_record);
     commands
@@ -86,6 +107,9 @@ fn state_setup(
                         &asset_server,
                         &status,
                         &leaderboard,
+                        &ach_defs,
+                        &theme,
+                        &locale,
                     );
                     if is_new_record {
                         parent
@@ -116,7 +140,7 @@ fn state_setup(
                                             TextStyle {
                                                 font: asset_server.load(app::ui::FONT_DIGIT),
                                                 font_size: app::ui::FONT_SIZE,
-                                                color: app::ui::FG_COLOR,
+                                                color: theme.fg,
                                             },
                                         ));
                                     });
@@ -139,7 +163,7 @@ fn state_setup(
                                 (
                                     ButtonAction::ReStart,
                                     app::interaction::IaButton,
-                                    Focusable::new().prioritized(),
+                                    build_focusable(Focusable::new().prioritized()),
                                 ),
                                 Style {
                                     padding: UiRect::all(app::ui::px_p(app::ui::BTN_PADDING)),
@@ -147,6 +171,8 @@ fn state_setup(
                                 },
                                 Some("Re-Start"),
                                 Some("arrow-counter-clockwise"),
+                                &theme,
+                                &locale,
                             );
                             app::ui::build_btn(
                                 parent,
@@ -154,7 +180,7 @@ fn state_setup(
                                 (
                                     ButtonAction::Leaderboard,
                                     app::interaction::IaButton,
-                                    Focusable::default(),
+                                    build_focusable(Focusable::default()),
                                 ),
                                 Style {
                                     padding: UiRect::all(app::ui::px_p(app::ui::BTN_PADDING)),
@@ -162,6 +188,8 @@ fn state_setup(
                                 },
                                 Some("Report"),
                                 Some("list-numbers"),
+                                &theme,
+                                &locale,
                             );
                             app::ui::build_btn(
                                 parent,
@@ -169,7 +197,7 @@ fn state_setup(
                                 (
                                     ButtonAction::Achievement,
                                     app::interaction::IaButton,
-                                    Focusable::default(),
+                                    build_focusable(Focusable::default()),
                                 ),
                                 Style {
                                     padding: UiRect::all(app::ui::px_p(app::ui::BTN_PADDING)),
@@ -177,6 +205,8 @@ fn state_setup(
                                 },
                                 Some("Marks"),
                                 Some("crosshair"),
+                                &theme,
+                                &locale,
                             );
                         });
                 });
@@ -186,7 +216,7 @@ fn state_setup(
                 (
                     ButtonAction::BackToMenu,
                     app::interaction::IaButton,
-                    Focusable::default(),
+                    build_focusable(Focusable::default()),
                 ),
                 Style {
                     position_type: PositionType::Absolute,
@@ -195,27 +225,167 @@ fn state_setup(
                     ..default()
                 },
                 "arrow-left-light",
+                &theme,
+                &locale,
             );
         });
+    if !unlocked_codes.is_empty() {
+        build_unlock_overlay(
+            &mut commands,
+            &asset_server,
+            &unlocked_codes,
+            &theme,
+            &locale,
+        );
+    }
+}
+
+const UNLOCK_PANEL_W: f32 = app::ui::FONT_SIZE * 14.0;
+
+fn build_unlock_overlay(
+    commands: &mut Commands,
+    asset_server: &Res<AssetServer>,
+    unlocked_codes: &[String],
+    theme: &app::theme::Theme,
+    locale: &app::locale::Locale,
+) {
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(0.0),
+                    bottom: Val::Px(0.0),
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    align_items: AlignItems::Center,
+                    justify_content: JustifyContent::Center,
+                    ..default()
+                },
+                background_color: theme.cover.into(),
+                z_index: ZIndex::Global(1),
+                ..default()
+            },
+            StateRootUi,
+            UnlockOverlay,
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn(NodeBundle {
+                    style: Style {
+                        width: Val::Px(UNLOCK_PANEL_W),
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        padding: UiRect::all(app::ui::px_p(6.0)),
+                        border: UiRect::all(app::ui::px_p(1.0)),
+                        row_gap: app::ui::px_p(4.0),
+                        ..default()
+                    },
+                    background_color: theme.bg.into(),
+                    border_color: theme.fg.into(),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    parent.spawn(TextBundle::from_section(
+                        locale.tr("Unlocked"),
+                        TextStyle {
+                            font: asset_server.load(locale.fetch_font(app::ui::FONT)),
+                            font_size: app::ui::FONT_SIZE * 1.5,
+                            color: theme.fg,
+                        },
+                    ));
+                    for code in unlocked_codes.iter() {
+                        let unlock_def = app::unlock::fetch_unlock_def(code);
+                        parent
+                            .spawn(NodeBundle {
+                                style: Style {
+                                    width: Val::Percent(100.0),
+                                    align_items: AlignItems::Center,
+                                    column_gap: app::ui::px_p(4.0),
+                                    ..default()
+                                },
+                                ..default()
+                            })
+                            .with_children(|parent| {
+                                page::unlock::build_item_preview(
+                                    parent,
+                                    asset_server,
+                                    unlock_def,
+                                    true,
+                                    theme,
+                                );
+                                parent.spawn(TextBundle::from_section(
+                                    format!(
+                                        "{} - {}",
+                                        locale.tr(app::unlock::fetch_slot_name(unlock_def.slot())),
+                                        locale.tr(unlock_def.name)
+                                    ),
+                                    TextStyle {
+                                        font: asset_server.load(locale.fetch_font(app::ui::FONT)),
+                                        font_size: app::ui::FONT_SIZE,
+                                        color: theme.fg,
+                                    },
+                                ));
+                            });
+                    }
+                    app::ui::build_btn(
+                        parent,
+                        asset_server,
+                        (
+                            ButtonAction::CloseUnlocks,
+                            app::interaction::IaButton,
+                            Focusable::new().prioritized(),
+                        ),
+                        Style {
+                            padding: UiRect::all(app::ui::px_p(app::ui::BTN_PADDING)),
+                            ..default()
+                        },
+                        Some("OK"),
+                        None,
+                        theme,
+                        locale,
+                    );
+                });
+        });
 }
 
 fn state_exit(to_despawn: Query<Entity, With<StateRootUi>>, commands: Commands) {
     app::ui::despawn_ui::<StateRootUi>(to_despawn, commands);
 }
 
+#[allow(clippy::too_many_arguments)]
 fn handle_ui_navigation(
     mut actions: Query<&mut ButtonAction>,
     mut events: EventReader<NavEvent>,
     mut page_state: ResMut<NextState<page::PageState>>,
     mut reactor_state: ResMut<NextState<reactor::ReactorState>>,
+    mut commands: Commands,
+    overlay_query: Query<Entity, With<UnlockOverlay>>,
+    mut focusables: Query<&mut Focusable>,
+    mut unlock_store: ResMut<Persistent<app::unlock::UnlockStore>>,
 ) {
+    let mut is_unlock_closed = false;
     events.nav_iter().activated_in_query_foreach_mut(
         &mut actions,
         |mut action| match &mut *action {
+            ButtonAction::CloseUnlocks => is_unlock_closed = true,
             ButtonAction::BackToMenu => page_state.set(page::PageState::Menu),
             ButtonAction::ReStart => reactor_state.set(reactor::ReactorState::Ready),
             ButtonAction::Leaderboard => page_state.set(page::PageState::Leaderboard),
             ButtonAction::Achievement => page_state.set(page::PageState::Achievement),
         },
     );
+    if is_unlock_closed {
+        for entity in overlay_query.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+        for mut focusable in focusables.iter_mut() {
+            focusable.unblock();
+        }
+        unlock_store
+            .update(|unlock_store| {
+                unlock_store.clear_pending();
+            })
+            .expect("failed to update unlocks");
+    }
 }
@@ -8,26 +8,47 @@ pub struct StatePlugin;
 
 impl Plugin for StatePlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(
-            OnEnter(reactor::ReactorState::Paused),
-            (state_setup, app::audio::reduce_bgm_volume),
-        )
-        .add_systems(
-            Update,
-            handle_ui_navigation
-                .after(NavRequestSystem)
-                .run_if(in_state(reactor::ReactorState::Paused)),
-        )
-        .add_systems(
-            OnExit(reactor::ReactorState::Paused),
-            (app::audio::roll_bgm_volume_back, state_exit),
-        );
+        app.init_resource::<PauseReason>()
+            .add_systems(
+                OnEnter(reactor::ReactorState::Paused),
+                (state_setup, app::audio::reduce_bgm_volume),
+            )
+            .add_systems(
+                Update,
+                handle_ui_navigation
+                    .after(NavRequestSystem)
+                    .run_if(in_state(reactor::ReactorState::Paused)),
+            )
+            .add_systems(
+                OnExit(reactor::ReactorState::Paused),
+                (app::audio::roll_bgm_volume_back, state_exit),
+            );
     }
 }
 
 #[derive(Component)]
 struct StateRootUi;
 
+#[derive(Resource, Clone, Copy, PartialEq, Default)]
+pub enum PauseReason {
+    #[default]
+    Manual,
+    FocusLost,
+    Minimized,
+    GamepadDisconnected,
+}
+
+impl PauseReason {
+    fn text(&self) -> Option<&str> {
+        match self {
+            PauseReason::Manual => None,
+            PauseReason::FocusLost => Some("Window lost focus"),
+            PauseReason::Minimized => Some("Window minimized"),
+            PauseReason::GamepadDisconnected => Some("Gamepad disconnected"),
+        }
+    }
+}
+
 #[derive(Component)]
 enum ButtonAction {
     Resume,
@@ -41,6 +62,9 @@ fn state_setup(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     mut key_binding: ResMut<app::key_binding::KeyBindingConfig>,
+    pause_reason: Res<PauseReason>,
+    theme: Res<app::theme::Theme>,
+    locale: Res<app::locale::Locale>,
 ) {
     key_binding.mode = app::key_binding::KeyBindingMode::Navgation;
     commands
@@ -57,7 +81,7 @@ fn state_setup(
                     justify_content: JustifyContent::Center,
                     ..default()
                 },
-                background_color: app::ui::COVER_COLOR.into(),
+                background_color: theme.cover.into(),
                 ..default()
             },
             StateRootUi,
@@ -65,11 +89,11 @@ fn state_setup(
         .with_children(|parent| {
             parent.spawn(
                 TextBundle::from_section(
-                    "Paused",
+                    locale.tr("Paused"),
                     TextStyle {
-                        font: asset_server.load(app::ui::FONT),
+                        font: asset_server.load(locale.fetch_font(app::ui::FONT)),
                         font_size: app::ui::FONT_SIZE * 2.0,
-                        color: app::ui::FG_COLOR,
+                        color: theme.fg,
                     },
                 )
                 .with_style(Style {
@@ -77,6 +101,22 @@ fn state_setup(
                     ..default()
                 }),
             );
+            if let Some(reason) = pause_reason.text() {
+                parent.spawn(
+                    TextBundle::from_section(
+                        locale.tr(reason),
+                        TextStyle {
+                            font: asset_server.load(locale.fetch_font(app::ui::FONT)),
+                            font_size: app::ui::FONT_SIZE,
+                            color: theme.secondary,
+                        },
+                    )
+                    .with_style(Style {
+                        margin: UiRect::bottom(app::ui::px_p(10.0)),
+                        ..default()
+                    }),
+                );
+            }
             parent
                 .spawn(NodeBundle {
                     style: Style {
@@ -98,6 +138,8 @@ fn state_setup(
                         ),
                         "Resume",
                         "play-light",
+                        &theme,
+                        &locale,
                     );
                     app::ui::build_menu_entry(
                         parent,
@@ -109,6 +151,8 @@ fn state_setup(
                         ),
                         "ReStart",
                         "arrow-counter-clockwise",
+                        &theme,
+                        &locale,
                     );
                     app::ui::build_menu_entry(
                         parent,
@@ -120,6 +164,8 @@ fn state_setup(
                         ),
                         "Abort",
                         "arrow-left-light",
+                        &theme,
+                        &locale,
                     );
                     #[cfg(not(target_arch = "wasm32"))]
                     {
@@ -133,14 +179,21 @@ fn state_setup(
                             ),
                             "Quit",
                             "sign-out-light",
+                            &theme,
+                            &locale,
                         );
                     }
                 });
         });
 }
 
-fn state_exit(to_despawn: Query<Entity, With<StateRootUi>>, commands: Commands) {
+fn state_exit(
+    to_despawn: Query<Entity, With<StateRootUi>>,
+    commands: Commands,
+    mut pause_reason: ResMut<PauseReason>,
+) {
     app::ui::despawn_ui::<StateRootUi>(to_despawn, commands);
+    *pause_reason = PauseReason::Manual;
 }
 
 fn handle_ui_navigation(
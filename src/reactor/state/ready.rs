@@ -1,5 +1,9 @@
-use crate::reactor::{self, field, field_ach, particle::*};
+use crate::{
+    app,
+    reactor::{self, field, field_ach, particle::*},
+};
 use bevy::prelude::*;
+use bevy_persistent::prelude::*;
 use std::f32::consts::PI;
 
 pub struct StatePlugin;
@@ -11,7 +15,7 @@ impl Plugin for StatePlugin {
             (
                 field::reset_reactor_fields,
                 field::reset_target_fields,
-                field_ach::reset_ach_fields,
+                field_ach::reset_ach_fields.after(field::reset_reactor_fields),
                 state_setup,
             ),
         );
@@ -25,6 +29,8 @@ fn state_setup(
     mut commands: Commands,
     particle_query: Query<Entity, With<reactor::RunningParticle>>,
     mut reactor_state: ResMut<NextState<reactor::ReactorState>>,
+    unlock_store: Res<Persistent<app::unlock::UnlockStore>>,
+    theme: Res<app::theme::Theme>,
 ) {
     for entity in &particle_query {
         commands.entity(entity).despawn_recursive();
@@ -35,6 +41,8 @@ fn state_setup(
         Some(Vec2::new(0.0, 0.0)),
         None,
         None,
+        unlock_store.uou_skin(),
+        &theme,
     );
     let start_angle = 0.0;
     for i in 0..PARTICLE_COUNT {
@@ -46,6 +54,7 @@ fn state_setup(
             Some(pos),
             Some(pos),
             None,
+            &theme,
         );
     }
     let start_angle = 60.0;
@@ -58,6 +67,7 @@ fn state_setup(
             Some(pos),
             Some(pos),
             None,
+            &theme,
         );
     }
     reactor_state.set(reactor::ReactorState::Running);
@@ -2,13 +2,17 @@ use crate::{
     app,
     reactor::{self, anime_effect::*, field, field_ach, hit::*, particle::*, status},
 };
-use bevy::{input, prelude::*};
+use bevy::{
+    input,
+    prelude::*,
+    window::{CursorGrabMode, WindowFocused, WindowResized},
+};
 #[cfg(not(target_arch = "wasm32"))]
 use bevy::{render::view::window::screenshot::ScreenshotManager, window::PrimaryWindow};
 use bevy_persistent::prelude::*;
 use bevy_tweening::*;
 use bevy_ui_navigation::{prelude::*, NavRequestSystem};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::f32::consts::PI;
 
 pub struct StatePlugin;
@@ -17,27 +21,34 @@ impl Plugin for StatePlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(
             OnEnter(reactor::ReactorState::Running),
-            (clear_anime_effect, state_setup),
+            (clear_anime_effect, state_setup, grab_cursor),
         )
         .add_systems(
             Update,
             (
                 detect_sensitivity_modification,
-                control_u_by_mouse,
-                control_u_by_keyboard,
-                control_u_by_gamepad,
+                control_u_by_mouse.run_if(reactor::camera::is_alive),
+                control_u_by_keyboard.run_if(reactor::camera::is_alive),
+                control_u_by_gamepad.run_if(reactor::camera::is_alive),
+                control_u_by_touch.run_if(reactor::camera::is_alive),
                 handle_pause_btn.after(NavRequestSystem),
+                auto_pause,
                 move_particle,
-                field::update_reactor_fields,
+                field::update_reactor_fields.run_if(reactor::camera::is_alive),
                 field::update_target_fields,
                 field_ach::update_ach_fields,
+                field_ach::paint_ach_progress,
                 handle_particle_reaction,
+                trigger_bomb,
                 component_animator_system::<Particle>,
                 component_animator_system::<AnimeEffect>,
             )
                 .run_if(in_state(reactor::ReactorState::Running)),
         )
-        .add_systems(OnExit(reactor::ReactorState::Running), state_exit);
+        .add_systems(
+            OnExit(reactor::ReactorState::Running),
+            (state_exit, release_cursor),
+        );
     }
 }
 
@@ -56,6 +67,8 @@ fn state_setup(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     mut key_binding: ResMut<app::key_binding::KeyBindingConfig>,
+    theme: Res<app::theme::Theme>,
+    locale: Res<app::locale::Locale>,
 ) {
     key_binding.mode = app::key_binding::KeyBindingMode::Gaming;
     commands
@@ -93,14 +106,20 @@ fn state_setup(
                 GameControlPanel,
             ));
             parent
-                .spawn((NodeBundle {
-                    style: Style {
-                        width: Val::Px(app::WINDOW_W),
-                        height: Val::Px(app::WINDOW_H),
+                .spawn((
+                    NodeBundle {
+                        style: Style {
+                            width: Val::Px(app::WINDOW_W),
+                            height: Val::Px(app::WINDOW_H),
+                            ..default()
+                        },
                         ..default()
                     },
-                    ..default()
-                },))
+                    app::ui::FitToField {
+                        width: Some(app::WINDOW_W),
+                        height: Some(app::WINDOW_H),
+                    },
+                ))
                 .with_children(|parent| {
                     app::ui::build_icon_btn(
                         parent,
@@ -117,6 +136,8 @@ fn state_setup(
                             ..default()
                         },
                         "pause-light",
+                        &theme,
+                        &locale,
                     );
                 });
         });
@@ -131,16 +152,101 @@ fn state_exit(
     dbg!("(running) status = {}", status);
 }
 
+fn grab_cursor(
+    mut window_query: Query<&mut Window>,
+    settings: Res<Persistent<app::settings::Settings>>,
+) {
+    if !settings.is_enabled("cursor_grab") {
+        return;
+    }
+    let mut window = window_query.single_mut();
+    // NOTE: macOS can't confine the cursor, so it is locked instead. A locked cursor
+    // stops moving, which only the relative mouse motion mode can live with.
+    window.cursor.grab_mode = if !cfg!(target_os = "macos") {
+        CursorGrabMode::Confined
+    } else if settings.is_enabled("follow_cursor") {
+        CursorGrabMode::None
+    } else {
+        CursorGrabMode::Locked
+    };
+    window.cursor.visible = false;
+}
+
+fn release_cursor(mut window_query: Query<&mut Window>) {
+    let mut window = window_query.single_mut();
+    window.cursor.grab_mode = CursorGrabMode::None;
+    window.cursor.visible = true;
+}
+
 fn move_particle(
     mut commands: Commands,
     mut particle_query: Query<(&mut Transform, &mut Particle), With<Particle>>,
     mut reactor_timer: ResMut<reactor::ReactorTimer>,
     time: Res<Time>,
     status: Res<status::ReactorStatus>,
+    theme: Res<app::theme::Theme>,
 ) {
     if reactor_timer.0.tick(time.delta()).just_finished() {
         let alpha_count = status.fetch("alpha_count");
+        let well_positions: Vec<Vec2> = particle_query
+            .iter()
+            .filter(|(_, p)| p.particle_type() == ParticleType::Well)
+            .filter(|(_, p)| p.state == ParticleState::Running)
+            .map(|(_, p)| p.pos())
+            .collect();
+        let well_count = particle_query
+            .iter()
+            .filter(|(_, p)| p.particle_type() == ParticleType::Well)
+            .count();
+        if well_count < well::fetch_target_count(alpha_count) {
+            well::build_particle_sprite(
+                &mut commands,
+                reactor::RunningParticle,
+                None,
+                None,
+                None,
+                &theme,
+            );
+        }
+        let u_pos = particle_query
+            .iter()
+            .find(|(_, p)| p.particle_type() == ParticleType::Uou)
+            .map(|(_, p)| p.pos())
+            .unwrap_or_default();
+        let hunter_count = particle_query
+            .iter()
+            .filter(|(_, p)| p.particle_type() == ParticleType::Hunter)
+            .count();
+        if hunter_count < hunter::fetch_target_count(status.fetch("time"))
+            && hunter::is_respawn_ready(status.time_since_hunter_kill())
+        {
+            let hunter_pos = hunter::gen_spawn_pos(u_pos);
+            hunter::build_particle_sprite(
+                &mut commands,
+                reactor::RunningParticle,
+                Some(hunter_pos),
+                Some(u_pos - hunter_pos),
+                None,
+                &theme,
+            );
+        }
         for (mut transform, mut particle) in particle_query.iter_mut() {
+            for well_pos in well_positions.iter() {
+                match particle.particle_type() {
+                    ParticleType::Alpha if particle.state == ParticleState::Running => {
+                        well::bend_v(particle.as_mut(), *well_pos);
+                    }
+                    ParticleType::Uou => {
+                        let field_rect = field::get_field_rect(particle.radius + 3.0);
+                        let new_pos = well::pull_pos(particle.pos(), *well_pos)
+                            .clamp(field_rect.min, field_rect.max);
+                        particle.jump(new_pos);
+                        transform.translation.x = new_pos.x;
+                        transform.translation.y = new_pos.y;
+                    }
+                    _ => (),
+                }
+            }
             if particle.is_traveling() {
                 let new_pos = (*particle).travel();
                 transform.translation.x = new_pos.x;
@@ -149,11 +255,15 @@ fn move_particle(
                     ParticleType::Alpha => {
                         particle.tick_countdown();
                     }
-                    ParticleType::Hyper => {
-                        if particle.level() > 1 && particle.tick_countdown() == 0 {
-                            particle.update_level(-1);
-                            particle.reset_countdown();
-                        }
+                    ParticleType::Hyper
+                        if particle.level() > 1 && particle.tick_countdown() == 0 =>
+                    {
+                        particle.update_level(-1);
+                        particle.reset_countdown();
+                    }
+                    ParticleType::Hunter if particle.tick_countdown() == 0 => {
+                        particle.reset_countdown();
+                        hunter::aim(particle.as_mut(), u_pos);
                     }
                     ParticleType::Trigger => {
                         transform.rotate_z(-time.delta_seconds() * 2.0);
@@ -170,6 +280,7 @@ fn move_particle(
                                 Some(particle.pos() + direction * particle.radius),
                                 Some(direction),
                                 Some(level),
+                                &theme,
                             );
                             if level > 2 {
                                 insert_anime_effect(
@@ -180,6 +291,7 @@ fn move_particle(
                                         start_pos: particle.pos(),
                                         end_pos: particle.pos(),
                                     },
+                                    &theme,
                                 );
                             }
                         }
@@ -204,14 +316,53 @@ fn handle_pause_btn(
     );
 }
 
+fn auto_pause(
+    mut focused_events: EventReader<WindowFocused>,
+    mut resized_events: EventReader<WindowResized>,
+    mut connection_events: EventReader<input::gamepad::GamepadConnectionEvent>,
+    settings: Res<Persistent<app::settings::Settings>>,
+    active_gamepad: Res<app::gamepad::ActiveGamepad>,
+    mut pause_reason: ResMut<reactor::state::paused::PauseReason>,
+    mut reactor_state: ResMut<NextState<reactor::ReactorState>>,
+) {
+    let mut reason = None;
+    if focused_events.read().any(|event| !event.focused) {
+        reason = Some(reactor::state::paused::PauseReason::FocusLost);
+    }
+    if resized_events
+        .read()
+        .any(|event| event.width == 0.0 || event.height == 0.0)
+    {
+        reason = Some(reactor::state::paused::PauseReason::Minimized);
+    }
+    if connection_events.read().any(|event| {
+        Some(event.gamepad) == active_gamepad.gamepad
+            && matches!(
+                event.connection,
+                input::gamepad::GamepadConnection::Disconnected
+            )
+    }) {
+        reason = Some(reactor::state::paused::PauseReason::GamepadDisconnected);
+    }
+    if !settings.is_enabled("auto_pause") {
+        return;
+    }
+    if let Some(reason) = reason {
+        *pause_reason = reason;
+        reactor_state.set(reactor::ReactorState::Paused);
+    }
+}
+
 const HYPER_HIT_BASE_SCORE: u32 = 100;
 const CONTROL_HIT_SCORE: u32 = 100;
+const GRAZE_BASE_SCORE: u32 = 10;
+const HUNTER_KILL_SCORE: u32 = 300;
+const MAX_GRAZE_MULTIPLIER: u32 = 10;
 
 #[allow(clippy::too_many_arguments)]
 fn handle_particle_reaction(
     mut commands: Commands,
     mut particle_query: Query<(Entity, &mut Particle, &mut Transform), With<Particle>>,
-    ae_query: Query<(Entity, &mut AnimeEffect), With<AnimeEffect>>,
     mut tween_completed_events: EventReader<TweenCompleted>,
     mut reactor_timer: ResMut<reactor::ReactorTimer>,
     mut painter_timer: ResMut<reactor::PainterTimer>,
@@ -220,21 +371,19 @@ fn handle_particle_reaction(
     settings: Res<Persistent<app::settings::Settings>>,
     audio_se_asset: Res<app::audio::AudioSeAsset>,
     mut status: ResMut<status::ReactorStatus>,
+    mut camera_feel: ResMut<reactor::camera::CameraFeel>,
+    mut grazing_alphas: Local<HashMap<Entity, bool>>,
+    dash: Res<reactor::dash::DashState>,
     #[cfg(not(target_arch = "wasm32"))] main_window: Query<Entity, With<PrimaryWindow>>,
     #[cfg(not(target_arch = "wasm32"))] mut screenshot_manager: ResMut<ScreenshotManager>,
+    theme: Res<app::theme::Theme>,
 ) {
     if painter_timer.0.tick(time.delta()).just_finished() {
-        for (_, particle, _) in particle_query.iter() {
-            particle.state_update(&mut commands);
-        }
         for (_, mut particle, _) in particle_query.iter_mut() {
             if particle.state == ParticleState::Created {
-                particle.state_setup(&mut commands);
+                particle.state_setup(&mut commands, &theme);
             }
         }
-        for (_, ae) in ae_query.iter() {
-            update_anime_effect(&mut commands, ae);
-        }
     }
     if reactor_timer.0.tick(time.delta()).just_finished() {
         let mut u_pos: Vec2 = Vec2::default();
@@ -243,7 +392,41 @@ fn handle_particle_reaction(
                 u_pos = Vec2::new(t.translation.x, t.translation.y);
             }
         }
-        status.update_stopping_time(u_pos);
+        let is_dying = camera_feel.is_dying();
+        if !is_dying {
+            status.update_stopping_time(u_pos);
+        }
+        // NOTE: a graze counts once the alpha leaves the ring without ever touching U
+        let grazing = detect_graze(&particle_query);
+        for (e, is_touching) in grazing.iter() {
+            *grazing_alphas.entry(*e).or_default() |= *is_touching;
+        }
+        let grazed_count = grazing_alphas
+            .iter()
+            .filter(|(e, is_touched)| {
+                !is_dying
+                    && !grazing.contains_key(*e)
+                    && !**is_touched
+                    && particle_query
+                        .get(**e)
+                        .is_ok_and(|(_, p, _)| p.state == ParticleState::Running)
+            })
+            .count();
+        grazing_alphas.retain(|e, _| grazing.contains_key(e));
+        for _ in 0..grazed_count {
+            let streak = status.record_graze();
+            status.increase("score", GRAZE_BASE_SCORE * streak.min(MAX_GRAZE_MULTIPLIER));
+            insert_anime_effect(
+                &mut commands,
+                AnimeEffectParam {
+                    kind: AnimeEffectKind::Graze,
+                    shape: AnimeEffectShape::Circle,
+                    start_pos: u_pos,
+                    end_pos: u_pos,
+                },
+                &theme,
+            );
+        }
         let hit_map = detect_hit(&mut particle_query);
         let mut entities_to_despawn: HashSet<Entity> = HashSet::new();
         for (e, mut p, mut t) in particle_query.iter_mut() {
@@ -251,7 +434,7 @@ fn handle_particle_reaction(
                 match p.particle_type() {
                     ParticleType::Alpha => match action {
                         HitAction::Kill => {
-                            alpha::setup_particle_ending(&mut commands, &mut p);
+                            alpha::setup_particle_ending(&mut commands, &mut p, &theme);
                             app::audio::play_se(
                                 app::audio::AudioSe::Pop,
                                 &mut commands,
@@ -271,6 +454,7 @@ fn handle_particle_reaction(
                                         Some(p.pos() + direction * p.radius * 3.0),
                                         Some(direction),
                                         Some(1),
+                                        &theme,
                                     );
                                 }
                             } else {
@@ -281,6 +465,7 @@ fn handle_particle_reaction(
                                     Some(p.pos() + direction * p.radius * 3.0),
                                     None,
                                     None,
+                                    &theme,
                                 );
                             }
                             if *count > 3 {
@@ -292,6 +477,7 @@ fn handle_particle_reaction(
                                         start_pos: p.pos(),
                                         end_pos: p.pos(),
                                     },
+                                    &theme,
                                 );
                             }
                             app::audio::play_se(
@@ -300,6 +486,7 @@ fn handle_particle_reaction(
                                 &audio_se_asset,
                                 &settings,
                             );
+                            camera_feel.release(&settings, *count);
                             entities_to_despawn.insert(e);
                         }
                         HitAction::MoveOnly => {
@@ -312,14 +499,15 @@ fn handle_particle_reaction(
                         HitAction::AlphaHit(count) => {
                             for _ in 1..=*count {
                                 if p.tick_countdown() == 0 {
-                                    control::setup_particle_ending(&mut commands, &mut p);
+                                    control::setup_particle_ending(&mut commands, &mut p, &theme);
                                 }
                             }
                         }
                         HitAction::Kill => {
-                            control::setup_particle_ending(&mut commands, &mut p);
+                            control::setup_particle_ending(&mut commands, &mut p, &theme);
                         }
-                        HitAction::UouHit => {
+                        HitAction::UouHit if !is_dying => {
+                            let hit_pos = p.pos();
                             insert_anime_effect(
                                 &mut commands,
                                 AnimeEffectParam {
@@ -328,6 +516,7 @@ fn handle_particle_reaction(
                                     start_pos: p.pos(),
                                     end_pos: p.pos(),
                                 },
+                                &theme,
                             );
                             if let Some(pos) = status.prev_chain_pos(status::StatusChain::Control) {
                                 insert_anime_effect(
@@ -338,6 +527,7 @@ fn handle_particle_reaction(
                                         start_pos: pos,
                                         end_pos: p.pos(),
                                     },
+                                    &theme,
                                 );
                             };
                             let current_level = p.level();
@@ -348,12 +538,13 @@ fn handle_particle_reaction(
                                 Some(new_c_pos),
                                 Some(new_c_pos - u_pos),
                                 Some(current_level + 1),
+                                &theme,
                             );
                             status.increase("total_control_count", 1);
                             p.update_level(1);
                             status.compare_and_update_max_field("control_level", p.level() as u32);
                             let ori_c_pos = field::gen_random_pos_in_field(p.radius);
-                            p.jump(ori_c_pos);
+                            p.warp(ori_c_pos);
                             t.translation.x = ori_c_pos.x;
                             t.translation.y = ori_c_pos.y;
                             p.assign_random_v(Some(ori_c_pos - u_pos));
@@ -364,13 +555,33 @@ fn handle_particle_reaction(
                                 &settings,
                             );
                             status.increase("score", CONTROL_HIT_SCORE);
+                            insert_anime_effect(
+                                &mut commands,
+                                AnimeEffectParam {
+                                    kind: AnimeEffectKind::Score {
+                                        score: CONTROL_HIT_SCORE,
+                                        level: p.level(),
+                                    },
+                                    shape: AnimeEffectShape::Square,
+                                    start_pos: hit_pos,
+                                    end_pos: hit_pos,
+                                },
+                                &theme,
+                            );
                             status.update_chain(status::StatusChain::Control, u_pos);
+                            insert_chain_link(
+                                &mut commands,
+                                &status,
+                                AnimeEffectShape::Square,
+                                &theme,
+                            );
                             p.state = hyper::setup_particle_starting(&mut commands, &p);
                         }
                         _ => (),
                     },
-                    ParticleType::Hyper => {
+                    ParticleType::Hyper if !is_dying => {
                         if let HitAction::UouHit = action {
+                            let hit_pos = p.pos();
                             insert_anime_effect(
                                 &mut commands,
                                 AnimeEffectParam {
@@ -379,6 +590,7 @@ fn handle_particle_reaction(
                                     start_pos: p.pos(),
                                     end_pos: p.pos(),
                                 },
+                                &theme,
                             );
                             if let Some(pos) = status.prev_chain_pos(status::StatusChain::Hyper) {
                                 insert_anime_effect(
@@ -389,6 +601,7 @@ fn handle_particle_reaction(
                                         start_pos: pos,
                                         end_pos: p.pos(),
                                     },
+                                    &theme,
                                 );
                             };
                             let new_c_pos = field::gen_random_pos_in_field(p.radius);
@@ -398,13 +611,14 @@ fn handle_particle_reaction(
                                 Some(new_c_pos),
                                 Some(new_c_pos - u_pos),
                                 Some(p.level()),
+                                &theme,
                             );
                             status.increase("total_control_count", 1);
                             status.increase("total_hyper_count", 1);
                             p.update_level(1);
                             status.compare_and_update_max_field("hyper_level", p.level() as u32);
                             let h_pos = field::gen_random_pos_in_field(p.radius);
-                            p.jump(h_pos);
+                            p.warp(h_pos);
                             t.translation.x = h_pos.x;
                             t.translation.y = h_pos.y;
                             p.assign_random_v(Some(h_pos - u_pos));
@@ -414,13 +628,73 @@ fn handle_particle_reaction(
                                 &audio_se_asset,
                                 &settings,
                             );
-                            status.increase("score", HYPER_HIT_BASE_SCORE * p.level() as u32);
+                            let hit_score = HYPER_HIT_BASE_SCORE * p.level() as u32;
+                            status.increase("score", hit_score);
+                            insert_anime_effect(
+                                &mut commands,
+                                AnimeEffectParam {
+                                    kind: AnimeEffectKind::Score {
+                                        score: hit_score,
+                                        level: p.level(),
+                                    },
+                                    shape: AnimeEffectShape::Hexagon,
+                                    start_pos: hit_pos,
+                                    end_pos: hit_pos,
+                                },
+                                &theme,
+                            );
                             status.update_chain(status::StatusChain::Hyper, u_pos);
+                            insert_chain_link(
+                                &mut commands,
+                                &status,
+                                AnimeEffectShape::Hexagon,
+                                &theme,
+                            );
                             p.state = hyper::setup_particle_starting(&mut commands, &p);
                         }
                     }
-                    ParticleType::Uou => {
+                    ParticleType::Hunter => {
                         if let HitAction::Kill = action {
+                            let hit_pos = p.pos();
+                            hunter::setup_particle_ending(&mut commands, &mut p, &theme);
+                            insert_anime_effect(
+                                &mut commands,
+                                AnimeEffectParam {
+                                    kind: AnimeEffectKind::Explosion,
+                                    shape: AnimeEffectShape::Dart,
+                                    start_pos: hit_pos,
+                                    end_pos: hit_pos,
+                                },
+                                &theme,
+                            );
+                            status.increase("score", HUNTER_KILL_SCORE);
+                            status.record_hunter_kill();
+                            insert_anime_effect(
+                                &mut commands,
+                                AnimeEffectParam {
+                                    kind: AnimeEffectKind::Score {
+                                        score: HUNTER_KILL_SCORE,
+                                        level: 1,
+                                    },
+                                    shape: AnimeEffectShape::Dart,
+                                    start_pos: hit_pos,
+                                    end_pos: hit_pos,
+                                },
+                                &theme,
+                            );
+                            app::audio::play_se(
+                                app::audio::AudioSe::Pop,
+                                &mut commands,
+                                &audio_se_asset,
+                                &settings,
+                            );
+                        }
+                    }
+                    ParticleType::Uou => match action {
+                        HitAction::Kill => {
+                            if camera_feel.is_dying() || dash.is_invulnerable() {
+                                continue;
+                            }
                             #[cfg(not(target_arch = "wasm32"))]
                             app::screenshot::shot_current(
                                 &main_window,
@@ -433,9 +707,35 @@ fn handle_particle_reaction(
                                 &audio_se_asset,
                                 &settings,
                             );
-                            reactor_state.set(reactor::ReactorState::Submit);
+                            if !camera_feel.start_death(&settings, u_pos) {
+                                reactor_state.set(reactor::ReactorState::Submit);
+                            }
                         }
-                    }
+                        HitAction::PassThrough(pos) => {
+                            let field_rect = field::get_field_rect(p.radius + 3.0);
+                            let new_pos = pos.clamp(field_rect.min, field_rect.max);
+                            insert_anime_effect(
+                                &mut commands,
+                                AnimeEffectParam {
+                                    kind: AnimeEffectKind::Bullet,
+                                    shape: AnimeEffectShape::Ring,
+                                    start_pos: p.pos(),
+                                    end_pos: new_pos,
+                                },
+                                &theme,
+                            );
+                            p.warp(new_pos);
+                            t.translation.x = new_pos.x;
+                            t.translation.y = new_pos.y;
+                            app::audio::play_se(
+                                app::audio::AudioSe::Warning,
+                                &mut commands,
+                                &audio_se_asset,
+                                &settings,
+                            );
+                        }
+                        _ => (),
+                    },
                     _ => (),
                 }
             }
@@ -444,7 +744,7 @@ fn handle_particle_reaction(
             if tween_event.user_data == STARTING_DONE_EVENT {
                 for (e, mut p, _) in particle_query.iter_mut() {
                     if e == tween_event.entity {
-                        p.state_starting_done(&mut commands);
+                        p.state_starting_done(&mut commands, &theme);
                     }
                 }
             }
@@ -462,13 +762,35 @@ fn handle_particle_reaction(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn control_u_by_mouse(
     mut panel_query: Query<&Interaction, (With<Interaction>, With<GameControlPanel>)>,
     mut u_particle_query: Query<(&mut Particle, &mut Transform), With<reactor::ControlParticle>>,
     mut mouse_motion_events: EventReader<input::mouse::MouseMotion>,
+    window_query: Query<&Window>,
+    camera_query: Query<(&Camera, &GlobalTransform)>,
+    time: Res<Time>,
     settings: Res<Persistent<app::settings::Settings>>,
     status: Res<reactor::status::ReactorStatus>,
 ) {
+    if settings.is_enabled("follow_cursor") {
+        mouse_motion_events.clear();
+        let (camera, camera_transform) = camera_query.single();
+        if let Some(target) = window_query
+            .single()
+            .cursor_position()
+            .and_then(|cursor| camera.viewport_to_world_2d(camera_transform, cursor))
+        {
+            follow_u(
+                target,
+                time.delta_seconds(),
+                &mut u_particle_query,
+                &status,
+                &settings,
+            );
+        }
+        return;
+    }
     for interaction in &mut panel_query {
         if *interaction == Interaction::Pressed {
             let events = mouse_motion_events.read().collect::<Vec<_>>();
@@ -479,79 +801,55 @@ fn control_u_by_mouse(
     }
 }
 
+fn control_u_by_touch(
+    touch_control: Res<app::touch::TouchControl>,
+    button_query: Query<&Interaction, With<Button>>,
+    mut u_particle_query: Query<(&mut Particle, &mut Transform), With<reactor::ControlParticle>>,
+    settings: Res<Persistent<app::settings::Settings>>,
+    status: Res<reactor::status::ReactorStatus>,
+) {
+    // NOTE: a touch pressing a button is meant for the UI, not for dragging U.
+    if button_query
+        .iter()
+        .any(|interaction| *interaction == Interaction::Pressed)
+    {
+        return;
+    }
+    let delta = touch_control.drag_delta();
+    if delta != Vec2::ZERO {
+        move_u(delta, &mut u_particle_query, &status, &settings);
+    }
+}
+
 const KEYBOARD_DELTA_BIAS: f32 = 1.5;
 
 fn control_u_by_keyboard(
     keyboard_input: Res<Input<KeyCode>>,
+    gamepad_input: Res<Input<GamepadButton>>,
+    active_gamepad: Res<app::gamepad::ActiveGamepad>,
     mut u_particle_query: Query<(&mut Particle, &mut Transform), With<reactor::ControlParticle>>,
     settings: Res<Persistent<app::settings::Settings>>,
     status: Res<reactor::status::ReactorStatus>,
 ) {
-    let mut delta: Vec2 = Vec2::default();
-    if keyboard_input.pressed(KeyCode::W)
-        || keyboard_input.pressed(KeyCode::Up)
-        || keyboard_input.pressed(KeyCode::K)
-    {
-        delta.y = -KEYBOARD_DELTA_BIAS;
-    }
-    if keyboard_input.pressed(KeyCode::S)
-        || keyboard_input.pressed(KeyCode::Down)
-        || keyboard_input.pressed(KeyCode::J)
-    {
-        delta.y = KEYBOARD_DELTA_BIAS;
-    }
-    if keyboard_input.pressed(KeyCode::A)
-        || keyboard_input.pressed(KeyCode::Left)
-        || keyboard_input.pressed(KeyCode::H)
-    {
-        delta.x = -KEYBOARD_DELTA_BIAS;
-    }
-    if keyboard_input.pressed(KeyCode::D)
-        || keyboard_input.pressed(KeyCode::Right)
-        || keyboard_input.pressed(KeyCode::L)
-    {
-        delta.x = KEYBOARD_DELTA_BIAS;
-    }
+    let delta =
+        settings
+            .fetch_input_map()
+            .move_delta(&keyboard_input, &gamepad_input, &active_gamepad)
+            * KEYBOARD_DELTA_BIAS;
     move_u(delta, &mut u_particle_query, &status, &settings);
 }
 
 const GAEMPAD_DELTA_BIAS: f32 = 2.0;
-const GAEMPAD_MIN_THRESHOLD: f32 = 0.25;
-
 fn control_u_by_gamepad(
-    mut events: EventReader<input::gamepad::GamepadEvent>,
+    active_gamepad: Res<app::gamepad::ActiveGamepad>,
+    axes: Res<Axis<GamepadAxis>>,
     mut u_particle_query: Query<(&mut Particle, &mut Transform), With<reactor::ControlParticle>>,
-    mut last_delta: Local<Vec2>,
     settings: Res<Persistent<app::settings::Settings>>,
     status: Res<reactor::status::ReactorStatus>,
 ) {
-    for event in events.read() {
-        dbg!(event);
-        if let input::gamepad::GamepadEvent::Axis(axis_event) = event {
-            let mut delta: Vec2 = Vec2::default();
-            if axis_event.axis_type == input::gamepad::GamepadAxisType::LeftStickX
-                || axis_event.axis_type == input::gamepad::GamepadAxisType::RightStickX
-            {
-                delta.x = GAEMPAD_DELTA_BIAS * axis_event.value;
-                if axis_event.value.abs() > GAEMPAD_MIN_THRESHOLD {
-                    last_delta.x = delta.x;
-                } else {
-                    last_delta.x = 0.0;
-                }
-            }
-            if axis_event.axis_type == input::gamepad::GamepadAxisType::LeftStickY
-                || axis_event.axis_type == input::gamepad::GamepadAxisType::RightStickY
-            {
-                delta.y = GAEMPAD_DELTA_BIAS * axis_event.value * -1.0;
-                if axis_event.value.abs() > GAEMPAD_MIN_THRESHOLD {
-                    last_delta.y = delta.y;
-                } else {
-                    last_delta.y = 0.0;
-                }
-            }
-        }
-    }
-    move_u(*last_delta, &mut u_particle_query, &status, &settings);
+    let stick = app::gamepad::read_stick(&active_gamepad, &axes, &settings.fetch_gamepad_tuning());
+    let delta = Vec2::new(stick.x, -stick.y) * GAEMPAD_DELTA_BIAS;
+    move_u(delta, &mut u_particle_query, &status, &settings);
 }
 
 fn move_u(
@@ -561,22 +859,50 @@ fn move_u(
     settings: &Res<Persistent<app::settings::Settings>>,
 ) {
     let (mut u_particle, mut u_transform) = u_particle_query.get_single_mut().unwrap();
-    let new_pos = calculate_u_new_pos(
-        u_particle.pos(),
-        delta,
-        if status.in_modified_sensitivity {
-            settings.get_value("sensitivity_modified")
-        } else {
-            settings.get_value("sensitivity")
-        },
-    );
+    let new_pos = calculate_u_new_pos(u_particle.pos(), delta, fetch_sensitivity(status, settings));
+    u_particle.jump(new_pos);
+    u_transform.translation.x = new_pos.x;
+    u_transform.translation.y = new_pos.y;
+}
+
+const FOLLOW_BASE_SPEED: f32 = 120.0;
+
+fn follow_u(
+    target: Vec2,
+    delta_seconds: f32,
+    u_particle_query: &mut Query<(&mut Particle, &mut Transform), With<reactor::ControlParticle>>,
+    status: &Res<reactor::status::ReactorStatus>,
+    settings: &Res<Persistent<app::settings::Settings>>,
+) {
+    let (mut u_particle, mut u_transform) = u_particle_query.get_single_mut().unwrap();
+    let max_step = FOLLOW_BASE_SPEED
+        * calculate_delta_ratio(fetch_sensitivity(status, settings))
+        * delta_seconds;
+    let step = (target - u_particle.pos()).clamp_length_max(max_step);
+    let field_rect = field::get_field_rect(uou::RADIUS + 3.0);
+    let new_pos = (u_particle.pos() + step).clamp(field_rect.min, field_rect.max);
     u_particle.jump(new_pos);
     u_transform.translation.x = new_pos.x;
     u_transform.translation.y = new_pos.y;
 }
 
+fn fetch_sensitivity(
+    status: &Res<reactor::status::ReactorStatus>,
+    settings: &Res<Persistent<app::settings::Settings>>,
+) -> u8 {
+    if status.in_modified_sensitivity {
+        settings.get_value("sensitivity_modified")
+    } else {
+        settings.get_value("sensitivity")
+    }
+}
+
+fn calculate_delta_ratio(sensitivity: u8) -> f32 {
+    0.5 + sensitivity as f32 / 100.0 * 5.0
+}
+
 fn calculate_u_new_pos(current: Vec2, delta: Vec2, sensitivity: u8) -> Vec2 {
-    let delta_ratio = 0.5 + sensitivity as f32 / 100.0 * 5.0;
+    let delta_ratio = calculate_delta_ratio(sensitivity);
     let field_rect = field::get_field_rect(uou::RADIUS + 3.0);
     let new_x = (current.x + delta.x * delta_ratio).clamp(field_rect.min.x, field_rect.max.x);
     let new_y = (current.y - delta.y * delta_ratio).clamp(field_rect.min.y, field_rect.max.y);
@@ -586,23 +912,115 @@ fn calculate_u_new_pos(current: Vec2, delta: Vec2, sensitivity: u8) -> Vec2 {
 fn detect_sensitivity_modification(
     keyboard_input: Res<Input<KeyCode>>,
     mut button_changed_events: EventReader<input::gamepad::GamepadButtonChangedEvent>,
+    active_gamepad: Res<app::gamepad::ActiveGamepad>,
+    settings: Res<Persistent<app::settings::Settings>>,
+    touch_control: Res<app::touch::TouchControl>,
+    mut is_touch_slow: Local<bool>,
     mut status: ResMut<reactor::status::ReactorStatus>,
 ) {
+    if touch_control.is_slow() != *is_touch_slow {
+        *is_touch_slow = touch_control.is_slow();
+        status.in_modified_sensitivity = *is_touch_slow;
+    }
+    let input_map = settings.fetch_input_map();
+    let slow_buttons = input_map.buttons(app::key_binding::GameAction::Slow);
     for btn_event in button_changed_events.read() {
-        if btn_event.button_type == input::gamepad::GamepadButtonType::RightTrigger
-            || btn_event.button_type == input::gamepad::GamepadButtonType::LeftTrigger
+        if Some(btn_event.gamepad) == active_gamepad.gamepad
+            && slow_buttons.contains(&btn_event.button_type)
         {
             status.in_modified_sensitivity = btn_event.value == 1.0;
         }
     }
-    if keyboard_input.just_pressed(KeyCode::ShiftLeft)
-        || keyboard_input.just_pressed(KeyCode::ShiftRight)
-    {
+    let slow_keys = input_map.keys(app::key_binding::GameAction::Slow);
+    if keyboard_input.any_just_pressed(slow_keys.clone()) {
         status.in_modified_sensitivity = true;
     }
-    if keyboard_input.just_released(KeyCode::ShiftLeft)
-        || keyboard_input.just_released(KeyCode::ShiftRight)
-    {
+    if keyboard_input.any_just_released(slow_keys) {
         status.in_modified_sensitivity = false;
     }
 }
+
+const BOMB_RADIUS: f32 = 160.0;
+
+#[allow(clippy::too_many_arguments)]
+fn trigger_bomb(
+    mut commands: Commands,
+    mut particle_query: Query<&mut Particle>,
+    keyboard_input: Res<Input<KeyCode>>,
+    gamepad_input: Res<Input<GamepadButton>>,
+    active_gamepad: Res<app::gamepad::ActiveGamepad>,
+    settings: Res<Persistent<app::settings::Settings>>,
+    audio_se_asset: Res<app::audio::AudioSeAsset>,
+    mut status: ResMut<status::ReactorStatus>,
+    mut camera_feel: ResMut<reactor::camera::CameraFeel>,
+    theme: Res<app::theme::Theme>,
+) {
+    let is_triggered = settings.fetch_input_map().is_just_pressed(
+        app::key_binding::GameAction::Bomb,
+        &keyboard_input,
+        &gamepad_input,
+        &active_gamepad,
+    );
+    if !is_triggered || camera_feel.is_dying() {
+        return;
+    }
+    let Some(u_pos) = particle_query
+        .iter()
+        .find(|p| p.particle_type() == ParticleType::Uou)
+        .map(|p| p.pos())
+    else {
+        return;
+    };
+    if !status.spend_bomb() {
+        return;
+    }
+    let mut count = 0;
+    for mut p in particle_query.iter_mut() {
+        if p.particle_type() == ParticleType::Alpha
+            && p.state == ParticleState::Running
+            && p.pos().distance(u_pos) < BOMB_RADIUS
+        {
+            alpha::setup_particle_ending(&mut commands, &mut p, &theme);
+            count += 1;
+        }
+    }
+    insert_anime_effect(
+        &mut commands,
+        AnimeEffectParam {
+            kind: AnimeEffectKind::Explosion,
+            shape: AnimeEffectShape::Circle,
+            start_pos: u_pos,
+            end_pos: u_pos,
+        },
+        &theme,
+    );
+    app::audio::play_se(
+        app::audio::AudioSe::Boom,
+        &mut commands,
+        &audio_se_asset,
+        &settings,
+    );
+    camera_feel.release(&settings, count);
+}
+
+fn insert_chain_link(
+    commands: &mut Commands,
+    status: &status::ReactorStatus,
+    shape: AnimeEffectShape,
+    theme: &app::theme::Theme,
+) {
+    let trail = status.chain_trail();
+    if trail.len() < 2 {
+        return;
+    }
+    insert_anime_effect(
+        commands,
+        AnimeEffectParam {
+            kind: AnimeEffectKind::ChainLink(trail.to_vec()),
+            shape,
+            start_pos: trail[0],
+            end_pos: trail[trail.len() - 1],
+        },
+        theme,
+    );
+}
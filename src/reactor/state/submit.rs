@@ -9,13 +9,19 @@ impl Plugin for StatePlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(
             OnEnter(reactor::ReactorState::Submit),
-            (state_setup, app::audio::reduce_bgm_volume),
+            (
+                state_setup,
+                reactor::field_ach::store_ach_counters,
+                reactor::field_ach::store_ach_progress,
+                app::audio::reduce_bgm_volume,
+            ),
         )
         .add_systems(
             Update,
             (
                 delay_ui_display,
                 reactor::field_ach::update_ach_fields,
+                reactor::field_ach::paint_ach_progress,
                 handle_keybord_input,
                 handle_ui_navigation.after(NavRequestSystem),
             )
@@ -42,6 +48,7 @@ enum ButtonAction {
 
 const ENDED_BG_COLOR: Color = Color::rgba(0.0, 0.0, 0.0, 0.95);
 
+#[allow(clippy::too_many_arguments)]
 fn state_setup(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
@@ -50,6 +57,9 @@ fn state_setup(
     settings: Res<Persistent<app::settings::Settings>>,
     mut key_binding: ResMut<app::key_binding::KeyBindingConfig>,
     mut anime_timer: ResMut<reactor::AnimeTimer>,
+    ach_defs: Res<app::achievement::AchievementDefs>,
+    theme: Res<app::theme::Theme>,
+    locale: Res<app::locale::Locale>,
 ) {
     anime_timer.0.reset();
     key_binding.mode = app::key_binding::KeyBindingMode::Keyboard;
@@ -93,6 +103,9 @@ fn state_setup(
                         &asset_server,
                         &status,
                         &leaderboard,
+                        &ach_defs,
+                        &theme,
+                        &locale,
                     );
                     parent
                         .spawn(NodeBundle {
@@ -120,7 +133,7 @@ fn state_setup(
                                         border: UiRect::all(app::ui::px_p(1.0)),
                                         ..default()
                                     },
-                                    border_color: app::ui::FG_COLOR.into(),
+                                    border_color: theme.fg.into(),
                                     ..default()
                                 })
                                 .with_children(|parent| {
@@ -130,14 +143,14 @@ fn state_setup(
                                             TextStyle {
                                                 font: asset_server.load(app::ui::FONT_DIGIT),
                                                 font_size: app::ui::FONT_SIZE,
-                                                color: app::ui::FG_COLOR,
+                                                color: theme.fg,
                                             },
                                         ),
                                         PlayerNameInput,
                                     ));
                                 });
                         });
-                    build_keyboard(parent, &asset_server);
+                    build_keyboard(parent, &asset_server, &theme);
                     app::ui::build_btn(
                         parent,
                         &asset_server,
@@ -152,6 +165,8 @@ fn state_setup(
                         },
                         Some("Submit"),
                         Some("download-simple"),
+                        &theme,
+                        &locale,
                     );
                 });
         });
@@ -247,7 +262,11 @@ const KB_FS: f32 = app::ui::FONT_SIZE * 0.6;
 const KB_KEY_SIZE: f32 = 40.0;
 const KB_ICON_SIZE: f32 = 20.0;
 
-fn build_keyboard(parent: &mut ChildBuilder, asset_server: &Res<AssetServer>) -> Entity {
+fn build_keyboard(
+    parent: &mut ChildBuilder,
+    asset_server: &Res<AssetServer>,
+    theme: &app::theme::Theme,
+) -> Entity {
     parent
         .spawn(NodeBundle {
             style: Style {
@@ -273,7 +292,7 @@ fn build_keyboard(parent: &mut ChildBuilder, asset_server: &Res<AssetServer>) ->
                     })
                     .with_children(|parent| {
                         for kb_key in kb_row {
-                            build_key_btn(parent, asset_server, kb_key);
+                            build_key_btn(parent, asset_server, kb_key, theme);
                         }
                     });
             }
@@ -281,7 +300,12 @@ fn build_keyboard(parent: &mut ChildBuilder, asset_server: &Res<AssetServer>) ->
         .id()
 }
 
-fn build_key_btn(parent: &mut ChildBuilder, asset_server: &Res<AssetServer>, key: &str) -> Entity {
+fn build_key_btn(
+    parent: &mut ChildBuilder,
+    asset_server: &Res<AssetServer>,
+    key: &str,
+    theme: &app::theme::Theme,
+) -> Entity {
     parent
         .spawn((
             ButtonBundle {
@@ -292,7 +316,7 @@ fn build_key_btn(parent: &mut ChildBuilder, asset_server: &Res<AssetServer>, key
                     align_items: AlignItems::Center,
                     ..default()
                 },
-                background_color: app::ui::BTN_BG.into(),
+                background_color: theme.btn_bg.into(),
                 ..default()
             },
             (
@@ -321,7 +345,7 @@ fn build_key_btn(parent: &mut ChildBuilder, asset_server: &Res<AssetServer>, key
                     TextStyle {
                         font: asset_server.load(app::ui::FONT_DIGIT),
                         font_size: KB_FS,
-                        color: app::ui::FG_COLOR,
+                        color: theme.fg,
                     },
                 ));
             }
@@ -1,7 +1,47 @@
 use crate::app::leaderboard::LeaderboardRecord;
 use bevy::prelude::*;
+use bevy::utils::HashMap;
 use chrono::Local;
 
+pub const FETCHABLE_FIELDS: [&str; 22] = [
+    "time",
+    "score",
+    "alpha_count",
+    "chain_length",
+    "max_alpha_count",
+    "max_control_chain",
+    "max_hyper_chain",
+    "total_control_count",
+    "total_hyper_count",
+    "max_control_count",
+    "max_full_level_control_count",
+    "max_control_level",
+    "max_hyper_level",
+    "max_stopping_time",
+    "current_stopping_time",
+    "total_stopping_time",
+    "current_max_hyper_level",
+    "current_full_level_control_count",
+    "graze_count",
+    "max_graze_streak",
+    "dash_count",
+    "bombs_used",
+];
+
+pub const LIFETIME_FIELDS: [&str; 7] = [
+    "games_played",
+    "time",
+    "score",
+    "total_control_count",
+    "total_hyper_count",
+    "total_stopping_time",
+    "dash_count",
+];
+
+const CHAIN_TRAIL_SIZE: usize = 6;
+const GRAZE_STREAK_WINDOW: u32 = 200;
+const BOMB_CHARGE_FULL: u32 = 30;
+
 #[derive(PartialEq, Default, Debug)]
 pub enum StatusChain {
     #[default]
@@ -15,10 +55,14 @@ pub struct ReactorStatus {
     pub player_name: String,
     pub highlight_uid: String,
     pub done_achievements: Vec<String>,
+    pub done_tiers: HashMap<String, usize>,
+    pub ach_progress: HashMap<String, u32>,
     pub in_modified_sensitivity: bool,
+    pub lifetime_counters: HashMap<String, u32>,
     current_chain: StatusChain,
     chain_length: u32,
     prev_chain_pos: Option<Vec2>,
+    chain_trail: Vec<Vec2>,
     time: u32,
     score: u32,
     alpha_count: u32,
@@ -36,6 +80,14 @@ pub struct ReactorStatus {
     max_stopping_time: u32,
     current_max_hyper_level: u32,
     current_full_level_control_count: u32,
+    graze_count: u32,
+    graze_streak: u32,
+    max_graze_streak: u32,
+    last_graze_time: Option<u32>,
+    dash_count: u32,
+    bomb_charge: u32,
+    bombs_used: u32,
+    last_hunter_kill_time: Option<u32>,
     u_pos: Vec2,
     started_at: String,
     ended_at: String,
@@ -59,6 +111,10 @@ impl ReactorStatus {
                 }
                 StatusChain::None => (),
             }
+            if self.chain_trail.len() >= CHAIN_TRAIL_SIZE {
+                self.chain_trail.remove(0);
+            }
+            self.chain_trail.push(position);
         } else {
             match chain {
                 StatusChain::Control => {
@@ -74,8 +130,23 @@ impl ReactorStatus {
                 }
             }
             self.current_chain = chain;
+            self.chain_trail = vec![position];
         }
         self.prev_chain_pos = Some(position);
+        self.bomb_charge = (self.bomb_charge + self.chain_length).min(BOMB_CHARGE_FULL);
+    }
+
+    pub fn bomb_charge_ratio(&self) -> f32 {
+        self.bomb_charge as f32 / BOMB_CHARGE_FULL as f32
+    }
+
+    pub fn spend_bomb(&mut self) -> bool {
+        if self.bomb_charge < BOMB_CHARGE_FULL {
+            return false;
+        }
+        self.bomb_charge = 0;
+        self.bombs_used += 1;
+        true
     }
 
     pub fn export(&self) -> LeaderboardRecord {
@@ -94,6 +165,8 @@ impl ReactorStatus {
             max_hyper_level: self.max_hyper_level,
             total_stopping_time: self.total_stopping_time,
             max_stopping_time: self.max_stopping_time,
+            max_graze_streak: self.max_graze_streak,
+            bombs_used: self.bombs_used,
             started_at: self.started_at.clone(),
             ended_at: self.ended_at.clone(),
         }
@@ -118,6 +191,15 @@ impl ReactorStatus {
         self.mark_timeline("started");
     }
 
+    pub fn fetch_done_tier(&self, code: &str) -> usize {
+        self.done_tiers.get(code).copied().unwrap_or(0)
+    }
+
+    pub fn update_ach_progress(&mut self, code: &str, value: u32) {
+        let best = self.ach_progress.entry(String::from(code)).or_insert(0);
+        *best = (*best).max(value);
+    }
+
     pub fn fetch(&self, field: &str) -> u32 {
         match field {
             "time" => self.time,
@@ -138,10 +220,29 @@ impl ReactorStatus {
             "total_stopping_time" => self.total_stopping_time,
             "current_max_hyper_level" => self.current_max_hyper_level,
             "current_full_level_control_count" => self.current_full_level_control_count,
+            "graze_count" => self.graze_count,
+            "max_graze_streak" => self.max_graze_streak,
+            "dash_count" => self.dash_count,
+            "bombs_used" => self.bombs_used,
             _ => panic!("Invalid field"),
         }
     }
 
+    pub fn fetch_lifetime(&self, field: &str) -> u32 {
+        let base = self.lifetime_counters.get(field).copied().unwrap_or(0);
+        let current = match field {
+            "games_played" => 1,
+            "time"
+            | "score"
+            | "total_control_count"
+            | "total_hyper_count"
+            | "total_stopping_time"
+            | "dash_count" => self.fetch(field),
+            _ => panic!("Invalid lifetime field"),
+        };
+        base.saturating_add(current)
+    }
+
     pub fn update_stopping_time(&mut self, new_u_pos: Vec2) {
         if self.current_stopping_time > self.max_stopping_time {
             self.max_stopping_time = self.current_stopping_time;
@@ -167,6 +268,30 @@ impl ReactorStatus {
         self.prev_chain_pos.as_ref().copied()
     }
 
+    pub fn chain_trail(&self) -> &[Vec2] {
+        &self.chain_trail
+    }
+
+    // NOTE: grazes close enough in time to each other build up a streak
+    pub fn record_graze(&mut self) -> u32 {
+        let in_streak = self
+            .last_graze_time
+            .is_some_and(|last| self.time - last <= GRAZE_STREAK_WINDOW);
+        self.graze_streak = if in_streak { self.graze_streak + 1 } else { 1 };
+        self.graze_count += 1;
+        self.last_graze_time = Some(self.time);
+        self.compare_and_update_max_field("graze_streak", self.graze_streak);
+        self.graze_streak
+    }
+
+    pub fn record_hunter_kill(&mut self) {
+        self.last_hunter_kill_time = Some(self.time);
+    }
+
+    pub fn time_since_hunter_kill(&self) -> Option<u32> {
+        self.last_hunter_kill_time.map(|last| self.time - last)
+    }
+
     pub fn increase(&mut self, field: &str, amount: u32) -> u32 {
         match field {
             "time" => {
@@ -189,6 +314,10 @@ impl ReactorStatus {
                 self.total_hyper_count += amount;
                 self.total_hyper_count
             }
+            "dash_count" => {
+                self.dash_count += amount;
+                self.dash_count
+            }
             _ => panic!("Invalid field"),
         }
     }
@@ -259,6 +388,12 @@ impl ReactorStatus {
                     is_updated = true;
                 }
             }
+            "graze_streak" => {
+                if value > self.max_graze_streak {
+                    self.max_graze_streak = value;
+                    is_updated = true;
+                }
+            }
             _ => panic!("Invalid field"),
         }
         is_updated
@@ -0,0 +1,177 @@
+use crate::{
+    app,
+    reactor::particle::{alpha, control, hyper, uou, Particle, ParticleState, ParticleType},
+};
+use bevy::prelude::*;
+use bevy_persistent::prelude::*;
+use bevy_prototype_lyon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+const TAIL_Z: f32 = -1.0;
+const TAIL_ALPHA: f32 = 0.5;
+
+// NOTE: (particle type, tail length, head width), one strip per tail index is
+// shared by every particle of the type, so the entity count never grows with the field
+const TAIL_KINDS: [(ParticleType, usize, f32); 4] = [
+    (
+        ParticleType::Alpha,
+        alpha::TAILING_SIZE,
+        alpha::RADIUS * 2.0,
+    ),
+    (ParticleType::Uou, uou::TAILING_SIZE, uou::RADIUS * 2.0),
+    (ParticleType::Control, control::TAILING_SIZE, 8.0),
+    (ParticleType::Hyper, hyper::TAILING_SIZE, 8.0),
+];
+
+#[derive(Clone, Copy, PartialEq, Default, Serialize, Deserialize, Debug)]
+pub enum TrailQuality {
+    Off,
+    Low,
+    #[default]
+    High,
+}
+
+impl TrailQuality {
+    pub fn name(&self) -> &str {
+        match self {
+            TrailQuality::Off => "Off",
+            TrailQuality::Low => "Low",
+            TrailQuality::High => "High",
+        }
+    }
+    pub fn next(&self) -> Self {
+        match self {
+            TrailQuality::Off => TrailQuality::Low,
+            TrailQuality::Low => TrailQuality::High,
+            TrailQuality::High => TrailQuality::Off,
+        }
+    }
+    // NOTE: points shared by all particles of a type, tails get shorter as the field fills up
+    fn point_budget(&self) -> usize {
+        match self {
+            TrailQuality::Off => 0,
+            TrailQuality::Low => 400,
+            TrailQuality::High => 3000,
+        }
+    }
+}
+
+// NOTE: keeps the last drawn segments, so a strip is only rebuilt when its points move
+#[derive(Component)]
+struct TailStrip {
+    kind: usize,
+    index: usize,
+    segments: Vec<(Vec2, Vec2)>,
+    color: Color,
+}
+
+pub struct TailPlugin;
+
+impl Plugin for TailPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, build_tail_strips)
+            .add_systems(Update, rebuild_tail_strips);
+    }
+}
+
+fn build_tail_strips(mut commands: Commands) {
+    for (kind, (_, size, head_width)) in TAIL_KINDS.iter().enumerate() {
+        for index in 0..*size {
+            let width = head_width * (1.0 - index as f32 / *size as f32 * 0.9);
+            commands.spawn((
+                ShapeBundle {
+                    spatial: SpatialBundle {
+                        transform: Transform::from_xyz(0.0, 0.0, TAIL_Z),
+                        visibility: Visibility::Hidden,
+                        ..default()
+                    },
+                    ..default()
+                },
+                Stroke {
+                    options: StrokeOptions::default()
+                        .with_end_cap(LineCap::Round)
+                        .with_start_cap(LineCap::Round)
+                        .with_line_width(width),
+                    color: Color::NONE,
+                },
+                TailStrip {
+                    kind,
+                    index,
+                    segments: Vec::new(),
+                    color: Color::NONE,
+                },
+            ));
+        }
+    }
+}
+
+fn tail_kind(particle_type: ParticleType) -> Option<usize> {
+    TAIL_KINDS
+        .iter()
+        .position(|(tail_type, _, _)| *tail_type == particle_type)
+}
+
+fn rebuild_tail_strips(
+    settings: Res<Persistent<app::settings::Settings>>,
+    particle_query: Query<Ref<Particle>>,
+    mut removed_particles: RemovedComponents<Particle>,
+    mut strip_query: Query<(&mut TailStrip, &mut Path, &mut Stroke, &mut Visibility)>,
+) {
+    let is_removed = removed_particles.read().count() > 0;
+    if !settings.is_changed()
+        && !is_removed
+        && !particle_query.iter().any(|particle| particle.is_changed())
+    {
+        return;
+    }
+    let tailed_particles: Vec<(usize, &Particle)> = particle_query
+        .iter()
+        .filter(|particle| particle.state == ParticleState::Running)
+        .filter_map(|particle| {
+            tail_kind(particle.particle_type()).map(|kind| (kind, particle.into_inner()))
+        })
+        .collect();
+    let budget = settings.fetch_trail_quality().point_budget();
+    let mut segments: Vec<Vec<Vec<(Vec2, Vec2)>>> = TAIL_KINDS
+        .iter()
+        .map(|(_, size, _)| vec![Vec::new(); *size])
+        .collect();
+    let mut colors: Vec<Option<Color>> = vec![None; TAIL_KINDS.len()];
+    for kind in 0..TAIL_KINDS.len() {
+        let count = tailed_particles.iter().filter(|(k, _)| *k == kind).count();
+        let length = TAIL_KINDS[kind].1.min(budget / count.max(1));
+        for (_, particle) in tailed_particles.iter().filter(|(k, _)| *k == kind) {
+            let Some(tailings) = particle.tailings() else {
+                continue;
+            };
+            let mut prev_pos = particle.pos();
+            for (index, tailing) in tailings.iter().take(length).enumerate() {
+                segments[kind][index].push((prev_pos, *tailing));
+                prev_pos = *tailing;
+            }
+            colors[kind].get_or_insert(particle.color);
+        }
+    }
+    for (mut strip, mut path, mut stroke, mut visibility) in strip_query.iter_mut() {
+        let strip_segments = &segments[strip.kind][strip.index];
+        let Some(color) = colors[strip.kind].filter(|_| !strip_segments.is_empty()) else {
+            strip.segments.clear();
+            visibility.set_if_neq(Visibility::Hidden);
+            continue;
+        };
+        visibility.set_if_neq(Visibility::Inherited);
+        if strip.segments == *strip_segments && strip.color == color {
+            continue;
+        }
+        let mut path_builder = PathBuilder::new();
+        for (start_pos, end_pos) in strip_segments.iter() {
+            path_builder.move_to(*start_pos);
+            path_builder.line_to(*end_pos);
+        }
+        *path = path_builder.build();
+        let fade = 1.0 - strip.index as f32 / TAIL_KINDS[strip.kind].1 as f32;
+        stroke.color = color.with_a(color.a() * TAIL_ALPHA * fade);
+        strip.segments.clone_from(strip_segments);
+        strip.color = color;
+    }
+}